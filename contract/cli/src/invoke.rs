@@ -0,0 +1,122 @@
+//! Contract invocation through the `stellar` CLI
+//!
+//! Every operator command maps to one or more contract calls. Each call is run
+//! as `stellar contract invoke`, which handles RPC, signing and XDR encoding;
+//! this module only builds the argument lists and captures the JSON result.
+
+use std::process::Command;
+
+/// Network, signer and contract shared by every call
+#[derive(Clone, Debug)]
+pub struct Target {
+    pub contract_id: String,
+    pub source: String,
+    pub network: String,
+}
+
+/// A single contract call and its named arguments
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Invocation {
+    pub function: String,
+    pub args: Vec<(String, String)>,
+    /// Simulate only; used for getters so no transaction is submitted
+    pub read_only: bool,
+}
+
+impl Invocation {
+    pub fn new(function: &str) -> Self {
+        Invocation {
+            function: function.to_string(),
+            args: Vec::new(),
+            read_only: false,
+        }
+    }
+
+    pub fn read(function: &str) -> Self {
+        Invocation {
+            read_only: true,
+            ..Invocation::new(function)
+        }
+    }
+
+    pub fn arg(mut self, name: &str, value: impl ToString) -> Self {
+        self.args.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Full `stellar` argument list for this call against `target`
+    pub fn command_args(&self, target: &Target) -> Vec<String> {
+        let mut args = vec![
+            "contract".to_string(),
+            "invoke".to_string(),
+            "--id".to_string(),
+            target.contract_id.clone(),
+            "--source".to_string(),
+            target.source.clone(),
+            "--network".to_string(),
+            target.network.clone(),
+        ];
+        if self.read_only {
+            args.push("--send=no".to_string());
+        }
+        args.push("--".to_string());
+        args.push(self.function.clone());
+        for (name, value) in &self.args {
+            args.push(format!("--{name}"));
+            args.push(value.clone());
+        }
+        args
+    }
+
+    /// Run the call, returning the contract's result as printed by the CLI
+    pub fn run(&self, target: &Target) -> Result<String, String> {
+        let output = Command::new("stellar")
+            .args(self.command_args(target))
+            .output()
+            .map_err(|e| format!("failed to run `stellar`: {e}"))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "{} failed: {}",
+                self.function,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target() -> Target {
+        Target {
+            contract_id: "CABC".to_string(),
+            source: "operator".to_string(),
+            network: "testnet".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_command_args() {
+        let args = Invocation::new("publish_event")
+            .arg("organizer", "GORG")
+            .arg("event_id", 7)
+            .command_args(&target());
+        assert_eq!(
+            args,
+            [
+                "contract", "invoke", "--id", "CABC", "--source", "operator", "--network",
+                "testnet", "--", "publish_event", "--organizer", "GORG", "--event_id", "7",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_only_calls_are_not_sent() {
+        let args = Invocation::read("get_event_stats").arg("event_id", 1).command_args(&target());
+        assert!(args.contains(&"--send=no".to_string()));
+        assert!(!Invocation::new("cancel_event").command_args(&target()).contains(&"--send=no".to_string()));
+    }
+}
@@ -0,0 +1,289 @@
+//! `lumentix` — operator CLI for the Lumentix contract
+//!
+//! Wraps the contract's entrypoints in task-level commands (create and
+//! publish events, set platform fees, run settlement batches, dump stats) so
+//! operators no longer hand-write `stellar contract invoke` calls. Requires
+//! the `stellar` CLI on `PATH` with the `--source` identity configured.
+
+mod invoke;
+
+use std::collections::HashMap;
+use std::env;
+use std::process::ExitCode;
+use invoke::{Invocation, Target};
+
+/// Tickets per `issue_prorata_refunds` call; matches the contract's `MAX_BATCH_SIZE`
+const REFUND_BATCH_SIZE: usize = 50;
+
+const USAGE: &str = "\
+usage: lumentix [--contract ID] [--source IDENTITY] [--network NAME] <command> [options]
+
+Global options fall back to LUMENTIX_CONTRACT_ID, LUMENTIX_SOURCE and
+LUMENTIX_NETWORK; the network defaults to testnet.
+
+commands:
+  create-event     --organizer ADDR --name S --summary S --location S
+                   --start TS --end TS --price N --capacity N [--draft]
+  publish          --organizer ADDR --event ID
+  set-fees         --admin ADDR [--bps N --effective TS] [--flat N]
+  settle           --organizer ADDR --events ID,ID,...
+  prorata-refunds  --organizer ADDR --event ID --tickets ID,ID,...
+  stats            [--event ID]";
+
+/// Parsed `--name value` options and bare `--flag`s
+#[derive(Debug, Default)]
+struct Options {
+    values: HashMap<String, String>,
+    flags: Vec<String>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut iter = args.iter().peekable();
+        while let Some(arg) = iter.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| format!("unexpected argument `{arg}`"))?;
+            match iter.peek() {
+                Some(value) if !value.starts_with("--") => {
+                    options.values.insert(name.to_string(), iter.next().unwrap().clone());
+                }
+                _ => options.flags.push(name.to_string()),
+            }
+        }
+        Ok(options)
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    fn require(&self, name: &str) -> Result<&str, String> {
+        self.get(name).ok_or_else(|| format!("missing --{name}"))
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+}
+
+/// Parse a comma-separated list of IDs
+fn parse_ids(list: &str) -> Result<Vec<u64>, String> {
+    list.split(',')
+        .filter(|id| !id.trim().is_empty())
+        .map(|id| id.trim().parse().map_err(|_| format!("invalid id `{id}`")))
+        .collect()
+}
+
+/// Split leading global options from the command and its options
+fn split_globals(args: &[String]) -> Result<(Target, &[String]), String> {
+    let mut contract_id = env::var("LUMENTIX_CONTRACT_ID").ok();
+    let mut source = env::var("LUMENTIX_SOURCE").ok();
+    let mut network = env::var("LUMENTIX_NETWORK").unwrap_or_else(|_| "testnet".to_string());
+
+    let mut rest = args;
+    while let [name, value, tail @ ..] = rest {
+        match name.as_str() {
+            "--contract" => contract_id = Some(value.clone()),
+            "--source" => source = Some(value.clone()),
+            "--network" => network = value.clone(),
+            _ => break,
+        }
+        rest = tail;
+    }
+
+    let target = Target {
+        contract_id: contract_id.ok_or("missing --contract or LUMENTIX_CONTRACT_ID")?,
+        source: source.ok_or("missing --source or LUMENTIX_SOURCE")?,
+        network,
+    };
+    Ok((target, rest))
+}
+
+fn create_event(target: &Target, options: &Options) -> Result<(), String> {
+    let function = if options.flag("draft") { "create_draft_event" } else { "create_event" };
+    let event_id = Invocation::new(function)
+        .arg("organizer", options.require("organizer")?)
+        .arg("name", options.require("name")?)
+        .arg("summary", options.require("summary")?)
+        .arg("location", options.require("location")?)
+        .arg("start_time", options.require("start")?)
+        .arg("end_time", options.require("end")?)
+        .arg("ticket_price", options.require("price")?)
+        .arg("max_tickets", options.require("capacity")?)
+        .run(target)?;
+    println!("created event {event_id}");
+    Ok(())
+}
+
+fn publish(target: &Target, options: &Options) -> Result<(), String> {
+    let event_id = options.require("event")?;
+    Invocation::new("publish_event")
+        .arg("organizer", options.require("organizer")?)
+        .arg("event_id", event_id)
+        .run(target)?;
+    println!("published event {event_id}");
+    Ok(())
+}
+
+/// Update the platform fee fields of the current config, leaving the rest as is
+fn set_fees(target: &Target, options: &Options) -> Result<(), String> {
+    let admin = options.require("admin")?;
+
+    // The bps rate only changes with notice, through a scheduled fee change
+    if let Some(bps) = options.get("bps") {
+        let bps: u32 = bps.parse().map_err(|_| "invalid --bps")?;
+        let effective_at = options.require("effective")?;
+        Invocation::new("schedule_fee_change")
+            .arg("admin", admin)
+            .arg("new_bps", bps)
+            .arg("effective_at", effective_at)
+            .run(target)?;
+        println!("platform fee change to {bps} bps scheduled for {effective_at}");
+    }
+    if let Some(flat) = options.get("flat") {
+        let config = Invocation::read("get_config").run(target)?;
+        let mut config: serde_json::Value =
+            serde_json::from_str(&config).map_err(|e| format!("unexpected config output: {e}"))?;
+        // i128 values are passed to the CLI as strings
+        config["platform_fee_flat"] = flat.into();
+
+        Invocation::new("set_config")
+            .arg("admin", admin)
+            .arg("config", config)
+            .run(target)?;
+        println!("flat platform fee updated");
+    }
+    Ok(())
+}
+
+/// Complete each event and release its escrow, continuing past failures
+fn settle(target: &Target, options: &Options) -> Result<(), String> {
+    let organizer = options.require("organizer")?;
+    let mut failed = 0;
+    for event_id in parse_ids(options.require("events")?)? {
+        let settled = Invocation::new("complete_event")
+            .arg("organizer", organizer)
+            .arg("event_id", event_id)
+            .run(target)
+            .and_then(|_| {
+                Invocation::new("release_escrow")
+                    .arg("organizer", organizer)
+                    .arg("event_id", event_id)
+                    .run(target)
+            });
+        match settled {
+            Ok(amount) => println!("event {event_id}: released {amount}"),
+            Err(e) => {
+                failed += 1;
+                eprintln!("event {event_id}: {e}");
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} event(s) not settled"));
+    }
+    Ok(())
+}
+
+/// Pay pro-rata refunds of a curtailed event in contract-sized batches
+fn prorata_refunds(target: &Target, options: &Options) -> Result<(), String> {
+    let organizer = options.require("organizer")?;
+    let event_id = options.require("event")?;
+    let tickets = parse_ids(options.require("tickets")?)?;
+
+    for (index, batch) in tickets.chunks(REFUND_BATCH_SIZE).enumerate() {
+        let ids = serde_json::to_string(batch).unwrap();
+        let total = Invocation::new("issue_prorata_refunds")
+            .arg("organizer", organizer)
+            .arg("event_id", event_id)
+            .arg("ticket_ids", ids)
+            .run(target)?;
+        println!("batch {}: {} tickets, refunded {total}", index + 1, batch.len());
+    }
+    Ok(())
+}
+
+/// Print an event and its stats, or the contract-wide totals
+fn stats(target: &Target, options: &Options) -> Result<(), String> {
+    match options.get("event") {
+        Some(event_id) => {
+            println!("{}", Invocation::read("get_event").arg("event_id", event_id).run(target)?);
+            println!("{}", Invocation::read("get_event_stats").arg("event_id", event_id).run(target)?);
+        }
+        None => {
+            println!("{}", Invocation::read("get_reconciliation").run(target)?);
+            println!("{}", Invocation::read("get_platform_balance").run(target)?);
+        }
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (target, rest) = split_globals(args)?;
+    let (command, rest) = rest.split_first().ok_or(USAGE)?;
+    let options = Options::parse(rest)?;
+
+    match command.as_str() {
+        "create-event" => create_event(&target, &options),
+        "publish" => publish(&target, &options),
+        "set-fees" => set_fees(&target, &options),
+        "settle" => settle(&target, &options),
+        "prorata-refunds" => prorata_refunds(&target, &options),
+        "stats" => stats(&target, &options),
+        _ => Err(format!("unknown command `{command}`\n\n{USAGE}")),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = Options::parse(&strings(&["--event", "3", "--draft", "--name", "Gala"])).unwrap();
+        assert_eq!(options.get("event"), Some("3"));
+        assert_eq!(options.get("name"), Some("Gala"));
+        assert!(options.flag("draft"));
+        assert_eq!(options.require("organizer"), Err("missing --organizer".to_string()));
+        assert!(Options::parse(&strings(&["stray"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        assert_eq!(parse_ids("1, 2,3,"), Ok(vec![1, 2, 3]));
+        assert!(parse_ids("1,x").is_err());
+    }
+
+    #[test]
+    fn test_split_globals() {
+        let args = strings(&["--contract", "CABC", "--source", "ops", "--network", "mainnet", "stats"]);
+        let (target, rest) = split_globals(&args).unwrap();
+        assert_eq!(target.contract_id, "CABC");
+        assert_eq!(target.source, "ops");
+        assert_eq!(target.network, "mainnet");
+        assert_eq!(rest, ["stats"]);
+    }
+}
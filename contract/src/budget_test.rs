@@ -0,0 +1,90 @@
+//! Resource budget regression benchmarks
+//!
+//! Measures CPU instructions and memory for the contract's hot paths with
+//! `env.budget()` and fails when a path grows past its threshold. Thresholds
+//! sit roughly 25% above the measured cost; when a change legitimately makes a
+//! path more expensive, re-measure with `--nocapture` and raise the limit in
+//! the same commit.
+
+extern crate std;
+
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::Env;
+use crate::testutils::{self, FIXTURE_BUYER_BALANCE, FIXTURE_TICKET_PRICE};
+use crate::validation::MAX_BATCH_SIZE;
+use crate::CancellationReason;
+
+struct Limit {
+    cpu: u64,
+    mem: u64,
+}
+
+const PURCHASE: Limit = Limit { cpu: 1_810_000, mem: 330_000 };
+const REFUND: Limit = Limit { cpu: 1_700_000, mem: 335_000 };
+const PRORATA_PAGE: Limit = Limit { cpu: 70_000_000, mem: 22_000_000 };
+const TICKET_PAGE: Limit = Limit { cpu: 1_825_000, mem: 185_000 };
+
+/// Run `f` against a fresh default budget and check it against `limit`
+fn measure(env: &Env, name: &str, limit: Limit, f: impl FnOnce()) {
+    env.budget().reset_default();
+    f();
+    let cpu = env.budget().cpu_instruction_cost();
+    let mem = env.budget().memory_bytes_cost();
+    std::println!("{name}: cpu={cpu} mem={mem}");
+    
+    assert!(cpu <= limit.cpu, "{name} cpu regressed: {cpu} > {}", limit.cpu);
+    assert!(mem <= limit.mem, "{name} memory regressed: {mem} > {}", limit.mem);
+}
+
+#[test]
+fn bench_purchase_ticket() {
+    let env = Env::default();
+    let (_, client) = testutils::setup_contract(&env);
+    let fixture = testutils::setup_event_with_sales(&env, &client, 1);
+    let asset = client.get_event_payment_asset(&fixture.event_id);
+    let buyer = testutils::funded_account(&env, &fixture.token, FIXTURE_BUYER_BALANCE);
+    
+    measure(&env, "purchase_ticket", PURCHASE, || {
+        client.purchase_ticket(&buyer, &fixture.event_id, &FIXTURE_TICKET_PRICE, &None, &asset, &None);
+    });
+}
+
+#[test]
+fn bench_refund_ticket() {
+    let env = Env::default();
+    let (_, client) = testutils::setup_contract(&env);
+    let fixture = testutils::setup_event_with_sales(&env, &client, 1);
+    client.cancel_event(&fixture.organizer, &fixture.event_id, &CancellationReason::Other, &None);
+    let ticket_id = fixture.ticket_ids.get(0).unwrap();
+    let buyer = fixture.buyers.get(0).unwrap();
+    
+    measure(&env, "refund_ticket", REFUND, || {
+        client.refund_ticket(&ticket_id, &buyer);
+    });
+}
+
+#[test]
+fn bench_prorata_refund_page() {
+    let env = Env::default();
+    let (_, client) = testutils::setup_contract(&env);
+    env.budget().reset_unlimited();
+    let fixture = testutils::setup_event_with_sales(&env, &client, MAX_BATCH_SIZE);
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.curtail_event(&fixture.organizer, &fixture.event_id, &5_000);
+    
+    measure(&env, "issue_prorata_refunds", PRORATA_PAGE, || {
+        client.issue_prorata_refunds(&fixture.organizer, &fixture.event_id, &fixture.ticket_ids);
+    });
+}
+
+#[test]
+fn bench_get_tickets_page() {
+    let env = Env::default();
+    let (_, client) = testutils::setup_contract(&env);
+    env.budget().reset_unlimited();
+    let fixture = testutils::setup_event_with_sales(&env, &client, MAX_BATCH_SIZE);
+    
+    measure(&env, "get_tickets", TICKET_PAGE, || {
+        client.get_tickets(&fixture.ticket_ids);
+    });
+}
@@ -1,4 +1,16 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol};
+
+use crate::types::{CancellationReason, EventStatus};
+
+// Topics are `(domain, action, subject)`, with the subject an event ID
+// wherever one applies, so indexers can subscribe to a single domain, action
+// or event. Payloads carry IDs and small values; follow-up reads fetch the
+// records themselves.
+const EVENT: Symbol = symbol_short!("event");
+const TICKET: Symbol = symbol_short!("ticket");
+const ESCROW: Symbol = symbol_short!("escrow");
+const ORGANIZER: Symbol = symbol_short!("organizer");
+const PLATFORM: Symbol = symbol_short!("platform");
 
 //a type for tranfer of event
 pub struct TransferEvent;
@@ -8,4 +20,157 @@ impl TransferEvent {
         env.events()
             .publish((symbol_short!("transfer"),), (ticket_id, from, to));
     }
-}
\ No newline at end of file
+}
+
+/// An event was created or moved to a new status
+pub fn status_changed(env: &Env, event_id: u64, status: EventStatus) {
+    env.events()
+        .publish((EVENT, symbol_short!("status"), event_id), status);
+}
+
+/// The last ticket of an event sold
+pub fn sold_out(env: &Env, event_id: u64, tickets_sold: u32) {
+    env.events()
+        .publish((EVENT, symbol_short!("sold_out"), event_id), tickets_sold);
+}
+
+/// An event's bonus capacity unlocked, raising its capacity to `max_tickets`
+pub fn bonus_unlocked(env: &Env, event_id: u64, max_tickets: u32) {
+    env.events()
+        .publish((EVENT, symbol_short!("bonus"), event_id), max_tickets);
+}
+
+/// The admin featured an event, or removed it from the featured list with an
+/// `until` of 0
+pub fn featured(env: &Env, event_id: u64, rank: u32, until: u64) {
+    env.events()
+        .publish((EVENT, symbol_short!("featured"), event_id), (rank, until));
+}
+
+/// A draft event was published and opened for sales, for services notifying
+/// its followers
+pub fn sales_opened(env: &Env, event_id: u64, followers: u32) {
+    env.events()
+        .publish((EVENT, symbol_short!("opened"), event_id), followers);
+}
+
+/// An organizer posted an announcement to an event's ticket holders
+pub fn announcement_posted(env: &Env, event_id: u64, number: u32, content_hash: BytesN<32>) {
+    env.events()
+        .publish((EVENT, symbol_short!("announce"), event_id), (number, content_hash));
+}
+
+/// An organizer registered a gate device for an event, or revoked it with an
+/// `expires_at` of 0
+pub fn gate_device(env: &Env, event_id: u64, device: Address, expires_at: u64) {
+    env.events()
+        .publish((EVENT, symbol_short!("device"), event_id), (device, expires_at));
+}
+
+/// An organizer published a new off-chain description for an event
+pub fn description_updated(env: &Env, event_id: u64, description_hash: BytesN<32>) {
+    env.events()
+        .publish((EVENT, symbol_short!("descr"), event_id), description_hash);
+}
+
+/// Waitlist registration opened for a sold-out event
+pub fn waitlist_opened(env: &Env, event_id: u64) {
+    env.events()
+        .publish((EVENT, symbol_short!("wl_open"), event_id), ());
+}
+
+/// An event was cancelled by its organizer
+pub fn event_cancelled(env: &Env, event_id: u64, reason: CancellationReason) {
+    env.events()
+        .publish((EVENT, symbol_short!("cancel"), event_id), reason);
+}
+
+/// A ticket was sold
+pub fn ticket_purchased(env: &Env, event_id: u64, ticket_id: u64) {
+    env.events()
+        .publish((TICKET, symbol_short!("purchased"), event_id), ticket_id);
+}
+
+/// A ticket sold on a legacy system was imported
+pub fn ticket_imported(env: &Env, event_id: u64, ticket_id: u64) {
+    env.events()
+        .publish((TICKET, symbol_short!("imported"), event_id), ticket_id);
+}
+
+/// A ticket was refunded in full
+pub fn ticket_refunded(env: &Env, event_id: u64, ticket_id: u64) {
+    env.events()
+        .publish((TICKET, symbol_short!("refunded"), event_id), ticket_id);
+}
+
+/// A ticket changed hands through an accepted offer or swap
+pub fn ticket_transferred(env: &Env, event_id: u64, ticket_id: u64) {
+    env.events()
+        .publish((TICKET, symbol_short!("transfer"), event_id), ticket_id);
+}
+
+/// A ticket was burned for redemption outside the contract
+pub fn ticket_redeemed(env: &Env, event_id: u64, ticket_id: u64, redemption_hash: BytesN<32>) {
+    env.events()
+        .publish((TICKET, symbol_short!("redeemed"), event_id), (ticket_id, redemption_hash));
+}
+
+/// A ticket was admitted at the gate
+pub fn ticket_checked_in(env: &Env, event_id: u64, ticket_id: u64) {
+    env.events()
+        .publish((TICKET, symbol_short!("checkin"), event_id), ticket_id);
+}
+
+/// A will-call ticket was picked up
+pub fn ticket_picked_up(env: &Env, event_id: u64, ticket_id: u64, claimer: Address) {
+    env.events()
+        .publish((TICKET, symbol_short!("pickup"), event_id), (ticket_id, claimer));
+}
+
+/// A ticket was lent to a borrower until `until`
+pub fn ticket_lent(env: &Env, event_id: u64, ticket_id: u64, borrower: Address, until: u64) {
+    env.events()
+        .publish((TICKET, symbol_short!("lent"), event_id), (ticket_id, borrower, until));
+}
+
+/// The admin refunded a ticket on the organizer's behalf
+pub fn admin_refunded(env: &Env, event_id: u64, ticket_id: u64, admin: Address) {
+    env.events()
+        .publish((TICKET, symbol_short!("adm_rfnd"), event_id), (ticket_id, admin));
+}
+
+/// Late refund requests spiked, freezing an event's payouts pending review
+pub fn payouts_frozen(env: &Env, event_id: u64, requests: u32) {
+    env.events()
+        .publish((ESCROW, symbol_short!("frozen"), event_id), requests);
+}
+
+/// An event's escrow was redeemed from its yield vault
+pub fn yield_redeemed(env: &Env, event_id: u64, earned: i128) {
+    env.events()
+        .publish((ESCROW, symbol_short!("yield"), event_id), earned);
+}
+
+/// The admin proposed recovering an event's orphaned escrow
+pub fn recovery_proposed(env: &Env, event_id: u64, to: Address, amount: i128, unlock_at: u64) {
+    env.events()
+        .publish((ESCROW, symbol_short!("rec_prop"), event_id), (to, amount, unlock_at));
+}
+
+/// An event's orphaned escrow was recovered
+pub fn funds_recovered(env: &Env, event_id: u64, to: Address, amount: i128) {
+    env.events()
+        .publish((ESCROW, symbol_short!("recovered"), event_id), (to, amount));
+}
+
+/// An organizer handed control of all their events to a new address
+pub fn organizer_rotated(env: &Env, old: Address, new: Address, event_count: u32) {
+    env.events()
+        .publish((ORGANIZER, symbol_short!("rotated"), old), (new, event_count));
+}
+
+/// A platform fee change was announced
+pub fn fee_change_scheduled(env: &Env, fee_bps: u32, effective_at: u64) {
+    env.events()
+        .publish((PLATFORM, symbol_short!("fee_sched")), (fee_bps, effective_at));
+}
@@ -0,0 +1,60 @@
+//! On-chain invariant checks for audits and debugging
+//!
+//! Compiled only with the `invariants` feature, so production builds do not
+//! carry the extra entrypoint.
+
+use soroban_sdk::{contractimpl, Env};
+use crate::error::LumentixError;
+use crate::types::Reconciliation;
+use crate::{storage, LumentixContract, LumentixContractClient};
+
+/// Check the money conservation law and the fee reserve against a set of totals
+pub fn check(totals: &Reconciliation) -> Result<(), LumentixError> {
+    if totals.escrow + totals.platform_balance + totals.offers_held
+        != totals.deposits - totals.withdrawals - totals.refunds
+    {
+        return Err(LumentixError::InvariantViolation);
+    }
+    
+    if totals.escrow < 0
+        || totals.offers_held < 0
+        || totals.reserved_fees < 0
+        || totals.platform_balance < totals.reserved_fees
+    {
+        return Err(LumentixError::InvariantViolation);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl LumentixContract {
+    /// Verify that the contract's money totals reconcile
+    pub fn verify_invariants(env: Env) -> Result<(), LumentixError> {
+        check(&storage::get_reconciliation(&env))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_detects_imbalance() {
+        let totals = Reconciliation {
+            deposits: 1_100,
+            withdrawals: 300,
+            refunds: 100,
+            escrow: 500,
+            platform_balance: 100,
+            reserved_fees: 50,
+            offers_held: 100,
+        };
+        assert!(check(&totals).is_ok());
+        
+        let leaked = Reconciliation { escrow: 499, ..totals.clone() };
+        assert_eq!(check(&leaked), Err(LumentixError::InvariantViolation));
+        
+        let underfunded = Reconciliation { reserved_fees: 101, ..totals };
+        assert_eq!(check(&underfunded), Err(LumentixError::InvariantViolation));
+    }
+}
@@ -101,259 +101,520 @@ mod tests {
 #![no_std]
 
 mod error;
+mod events;
+#[cfg(feature = "invariants")]
+mod invariants;
+mod membership;
+mod money;
+mod payments;
 mod storage;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
 mod types;
 mod validation;
 
+#[cfg(test)]
+mod budget_test;
+#[cfg(test)]
+mod snapshot_test;
+#[cfg(test)]
+mod state_machine_test;
 #[cfg(test)]
 mod test;
 
 pub use contract::TicketContract;
 pub use events::TransferEvent;
 pub use models::Ticket;
-pub use error::LumentixError;
+pub use error::{LumentixError, TicketError};
+pub use membership::Membership;
+pub use payments::YieldVault;
 pub use types::*;
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, panic_with_error, token, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, String,
+    Symbol, TryFromVal, Val, Vec,
+};
+
+contractmeta!(key = "name", val = "Lumentix");
+contractmeta!(key = "desc", val = "Event ticketing with escrow protection");
+// Keep in sync with the package version in Cargo.toml
+contractmeta!(key = "binver", val = "0.1.0");
+
+/// High bit set on salted event IDs so they never collide with sequential IDs
+const SALTED_EVENT_ID_FLAG: u64 = 1 << 63;
+
+/// Length of an event day for multi-use tickets
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Delay between proposing and executing a stuck-funds recovery
+const RECOVERY_TIMELOCK_SECS: u64 = 7 * SECONDS_PER_DAY;
+
+/// How long before an event its standby pool is resolved and standby sales
+/// close
+const STANDBY_RESOLUTION_SECS: u64 = SECONDS_PER_DAY;
+
+/// Tier every ticket is currently sold in
+const BASE_TIER: u32 = 0;
+
+/// Contract build version, taken from the crate manifest
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[contract]
 pub struct LumentixContract;
 
 #[contractimpl]
 impl LumentixContract {
-    /// Initialize the contract with admin address
-    pub fn initialize(env: Env, admin: Address) -> Result<(), LumentixError> {
+    /// Initialize the contract with its admin and full initial configuration
+    ///
+    /// `fee_destinations` may be empty to send platform fees to the admin.
+    /// `payment_asset`, if given, becomes the payment asset of every event
+    /// created afterwards. Repeating the exact same initialization succeeds
+    /// without changes, so deploy scripts can retry; any other call on an
+    /// initialized contract fails with `AlreadyInitialized`.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        config: PlatformConfig,
+        fee_destinations: Vec<FeeDestination>,
+        payment_asset: Option<PaymentAsset>,
+    ) -> Result<(), LumentixError> {
+        admin.require_auth();
+        
         validation::validate_address(&admin)?;
         
         if storage::is_initialized(&env) {
+            let unchanged = storage::get_admin(&env) == admin
+                && storage::get_config(&env) == config
+                && storage::get_fee_destinations(&env).unwrap_or(Vec::new(&env)) == fee_destinations
+                && storage::get_default_payment_asset(&env) == payment_asset;
+            if unchanged {
+                return Ok(());
+            }
             return Err(LumentixError::AlreadyInitialized);
         }
         
+        validation::validate_config(&config)?;
+        if !fee_destinations.is_empty() {
+            validation::validate_fee_destinations(&fee_destinations)?;
+        }
+        if let Some(asset) = &payment_asset {
+            check_payment_asset(&env, asset)?;
+        }
+        
+        storage::set_config(&env, &config);
+        if !fee_destinations.is_empty() {
+            storage::set_fee_destinations(&env, &fee_destinations);
+        }
+        if let Some(asset) = &payment_asset {
+            storage::set_default_payment_asset(&env, asset);
+        }
         storage::set_admin(&env, &admin);
+        storage::set_initialized_at(&env, env.ledger().timestamp());
         storage::set_initialized(&env);
         
         Ok(())
     }
 
-    /// Create a new event
-    pub fn create_event(
+    /// Update platform-wide limits (admin only)
+    ///
+    /// `platform_fee_bps` must stay at its current value; fee changes are
+    /// announced ahead with `schedule_fee_change`.
+    pub fn set_config(
         env: Env,
-        organizer: Address,
-        name: String,
-        description: String,
-        location: String,
-        start_time: u64,
-        end_time: u64,
-        ticket_price: i128,
-        max_tickets: u32,
-    ) -> Result<u64, LumentixError> {
-        organizer.require_auth();
+        admin: Address,
+        config: PlatformConfig,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        validation::validate_config(&config)?;
         
-        if !storage::is_initialized(&env) {
-            return Err(LumentixError::NotInitialized);
+        if config.platform_fee_bps != storage::get_config(&env).platform_fee_bps {
+            return Err(LumentixError::InvalidConfig);
         }
         
-        // Input validation
-        validation::validate_address(&organizer)?;
-        validation::validate_positive_amount(ticket_price)?;
-        validation::validate_positive_capacity(max_tickets)?;
-        validation::validate_time_range(start_time, end_time)?;
-        validation::validate_string_not_empty(&name)?;
-        
-        let event_id = storage::get_next_event_id(&env);
-        
-        let event = Event {
-            id: event_id,
-            organizer: organizer.clone(),
-            name,
-            description,
-            location,
-            start_time,
-            end_time,
-            ticket_price,
-            max_tickets,
-            tickets_sold: 0,
-            status: EventStatus::Active,
-        };
-        
-        storage::set_event(&env, event_id, &event);
-        storage::increment_event_id(&env);
+        storage::set_config(&env, &config);
         
-        Ok(event_id)
+        Ok(())
     }
 
-    /// Purchase a ticket for an event
-    pub fn purchase_ticket(
+    /// Announce a change of `platform_fee_bps` that takes effect at
+    /// `effective_at` (admin only)
+    ///
+    /// The change must be announced at least `fee_change_notice_secs` ahead
+    /// and stay within `max_fee_bps`. Scheduling again replaces a change
+    /// that has not taken effect yet.
+    pub fn schedule_fee_change(
         env: Env,
-        buyer: Address,
-        event_id: u64,
-        payment_amount: i128,
-    ) -> Result<u64, LumentixError> {
-        buyer.require_auth();
+        admin: Address,
+        new_bps: u32,
+        effective_at: u64,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
         
-        if !storage::is_initialized(&env) {
-            return Err(LumentixError::NotInitialized);
+        let config = storage::get_config(&env);
+        if new_bps > config.max_fee_bps {
+            return Err(LumentixError::InvalidConfig);
         }
         
-        validation::validate_address(&buyer)?;
-        validation::validate_positive_amount(payment_amount)?;
+        let now = env.ledger().timestamp();
+        if effective_at < now.saturating_add(config.fee_change_notice_secs) {
+            return Err(LumentixError::InvalidTimeRange);
+        }
         
-        let mut event = storage::get_event(&env, event_id)?;
+        storage::set_fee_change(&env, &FeeChange {
+            fee_bps: new_bps,
+            scheduled_at: now,
+            effective_at,
+        });
+        events::fee_change_scheduled(&env, new_bps, effective_at);
         
-        // Validate event status
-        if event.status != EventStatus::Active {
+        Ok(())
+    }
+
+    /// Withdraw a scheduled fee change that has not taken effect yet (admin only)
+    pub fn cancel_fee_change(env: Env, admin: Address) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        if storage::get_pending_fee_change(&env).is_none() {
             return Err(LumentixError::InvalidStatusTransition);
         }
+        storage::remove_fee_change(&env);
         
-        // Check capacity
-        if event.tickets_sold >= event.max_tickets {
-            return Err(LumentixError::EventSoldOut);
-        }
+        Ok(())
+    }
+
+    /// Get the announced fee change, until it takes effect
+    pub fn get_pending_fee_change(env: Env) -> Option<FeeChange> {
+        storage::get_pending_fee_change(&env)
+    }
+
+    /// Set the minimum ticket price (in stroops) accepted by `create_event` (admin only)
+    pub fn set_min_ticket_price(
+        env: Env,
+        admin: Address,
+        min_price: i128,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
         
-        // Validate payment amount
-        if payment_amount < event.ticket_price {
-            return Err(LumentixError::InsufficientFunds);
+        let mut config = storage::get_config(&env);
+        config.min_ticket_price = min_price;
+        validation::validate_config(&config)?;
+        
+        storage::set_config(&env, &config);
+        
+        Ok(())
+    }
+
+    /// Get platform-wide limits
+    pub fn get_config(env: Env) -> Result<PlatformConfig, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
         }
         
-        let ticket_id = storage::get_next_ticket_id(&env);
+        Ok(storage::get_config(&env))
+    }
+
+    /// Set where withdrawn platform fees are paid and each destination's share (admin only)
+    pub fn set_fee_destinations(
+        env: Env,
+        admin: Address,
+        destinations: Vec<FeeDestination>,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        validation::validate_fee_destinations(&destinations)?;
         
-        let ticket = Ticket {
-            id: ticket_id,
-            event_id,
-            owner: buyer.clone(),
-            purchase_time: env.ledger().timestamp(),
-            used: false,
-            refunded: false,
-        };
+        storage::set_fee_destinations(&env, &destinations);
         
-        storage::set_ticket(&env, ticket_id, &ticket);
-        storage::increment_ticket_id(&env);
+        Ok(())
+    }
+
+    /// Get the payment asset new events are created with, if one was set at
+    /// initialization
+    pub fn get_default_payment_asset(env: Env) -> Option<PaymentAsset> {
+        storage::get_default_payment_asset(&env)
+    }
+
+    /// Get the platform fee destinations, defaulting to the admin alone
+    pub fn get_fee_destinations(env: Env) -> Result<Vec<FeeDestination>, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
         
-        // Update event
-        event.tickets_sold += 1;
-        storage::set_event(&env, event_id, &event);
+        Ok(fee_destinations(&env))
+    }
+
+    /// Exempt an event or organizer from the platform fee, or lift the exemption (admin only)
+    pub fn set_fee_exempt(
+        env: Env,
+        admin: Address,
+        target: FeeExemptTarget,
+        exempt: bool,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
         
-        // Store payment in escrow
-        storage::add_escrow(&env, event_id, payment_amount);
+        storage::set_fee_exempt(&env, &target, exempt);
         
-        Ok(ticket_id)
+        Ok(())
     }
 
-    /// Use a ticket (mark as used)
-    pub fn use_ticket(
+    /// Check whether an event or organizer is exempt from the platform fee
+    pub fn is_fee_exempt(env: Env, target: FeeExemptTarget) -> bool {
+        storage::is_fee_exempt(&env, &target)
+    }
+
+    /// Set the volume-based fee schedule (admin only)
+    ///
+    /// Tiers must be ordered by strictly increasing `min_volume`. An empty
+    /// schedule charges every organizer the base `platform_fee_bps`.
+    pub fn set_fee_tiers(
         env: Env,
-        ticket_id: u64,
-        validator: Address,
+        admin: Address,
+        tiers: Vec<FeeTier>,
     ) -> Result<(), LumentixError> {
-        validator.require_auth();
+        require_admin(&env, &admin)?;
+        validation::validate_fee_tiers(&storage::get_config(&env), &tiers)?;
+        
+        storage::set_fee_tiers(&env, &tiers);
         
+        Ok(())
+    }
+
+    /// Get the volume-based fee schedule
+    pub fn get_fee_tiers(env: Env) -> Vec<FeeTier> {
+        storage::get_fee_tiers(&env)
+    }
+
+    /// Get an organizer's lifetime settled volume (escrow released to them)
+    pub fn get_organizer_volume(env: Env, organizer: Address) -> i128 {
+        storage::get_organizer_volume(&env, &organizer)
+    }
+
+    /// Get the fee rate an organizer's new sales are currently charged, in basis points
+    pub fn get_organizer_fee_bps(env: Env, organizer: Address) -> u32 {
+        fee_bps_for(&env, &organizer)
+    }
+
+    /// Preview the platform fee a ticket purchase for an event would be charged now
+    pub fn preview_fee(env: Env, event_id: u64) -> Result<FeeQuote, LumentixError> {
         if !storage::is_initialized(&env) {
             return Err(LumentixError::NotInitialized);
         }
         
-        validation::validate_address(&validator)?;
+        let event = storage::get_event(&env, event_id)?;
         
-        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        fee_quote(&env, &event)
+    }
+
+    /// Get the contract-wide money totals used to reconcile balances
+    pub fn get_reconciliation(env: Env) -> Reconciliation {
+        storage::get_reconciliation(&env)
+    }
+
+    /// Get the platform fees collected and not yet withdrawn, across all assets
+    pub fn get_platform_balance(env: Env) -> i128 {
+        storage::get_platform_balance(&env)
+    }
+
+    /// Get the platform fees held in one token (`None` for accounting-only
+    /// events), which is what `withdraw_platform_fees` draws on
+    pub fn get_platform_asset_balance(env: Env, token: Option<Address>) -> i128 {
+        storage::get_asset_balance(&env, &token)
+    }
+
+    /// Withdraw platform fees, split across the fee destinations (admin only)
+    ///
+    /// Fees are withdrawn per asset, in `token` (`None` for fees of
+    /// accounting-only events). Fees of events that have not settled yet stay
+    /// reserved for refunds; the rest can be withdrawn in several
+    /// installments. Each destination gets its share rounded down; the
+    /// rounding remainder goes to the first one.
+    pub fn withdraw_platform_fees(
+        env: Env,
+        admin: Address,
+        token: Option<Address>,
+        amount: i128,
+    ) -> Result<Vec<FeePayout>, LumentixError> {
+        require_admin(&env, &admin)?;
+        validation::validate_positive_amount(amount)?;
         
-        if ticket.used {
-            return Err(LumentixError::TicketAlreadyUsed);
+        // Fees that may still have to be refunded cannot be withdrawn
+        let available = storage::get_asset_balance(&env, &token) - storage::get_asset_reserved(&env, &token);
+        if amount > available {
+            return Err(LumentixError::InsufficientPlatformBalance);
         }
         
-        if ticket.refunded {
-            return Err(LumentixError::RefundNotAllowed);
+        storage::deduct_platform_balance(&env, &token, amount)?;
+        storage::record_withdrawal(&env, amount);
+        
+        let mut payouts = Vec::new(&env);
+        let mut remainder = amount;
+        for destination in fee_destinations(&env).iter() {
+            let share = money::bps_of(amount, destination.share_bps)?;
+            remainder -= share;
+            payouts.push_back(FeePayout {
+                recipient: destination.recipient,
+                amount: share,
+            });
         }
         
-        let event = storage::get_event(&env, ticket.event_id)?;
+        let mut first = payouts.get(0).unwrap();
+        first.amount += remainder;
+        payouts.set(0, first);
         
-        // Only organizer can validate tickets
-        if validator != event.organizer {
-            return Err(LumentixError::Unauthorized);
+        for payout in payouts.iter() {
+            payments::pay_out_token(&env, &token, &payout.recipient, payout.amount);
         }
         
-        ticket.used = true;
-        storage::set_ticket(&env, ticket_id, &ticket);
-        
-        Ok(())
+        Ok(payouts)
     }
 
-    /// Cancel an event
-    pub fn cancel_event(
+    /// Propose recovering a balance that no normal flow can reach (admin
+    /// only), returning when the recovery can be executed
+    ///
+    /// Only escrow of events whose record no longer exists qualifies. The
+    /// proposal is emitted and can be executed with `recover_stuck_funds`
+    /// after `RECOVERY_TIMELOCK_SECS`; proposing again restarts the timelock.
+    pub fn propose_recovery(
         env: Env,
-        organizer: Address,
-        event_id: u64,
-    ) -> Result<(), LumentixError> {
-        organizer.require_auth();
+        admin: Address,
+        bucket: RecoveryBucket,
+        to: Address,
+    ) -> Result<u64, LumentixError> {
+        require_admin(&env, &admin)?;
         
-        if !storage::is_initialized(&env) {
-            return Err(LumentixError::NotInitialized);
-        }
+        let RecoveryBucket::OrphanedEscrow(event_id) = bucket;
+        let amount = orphaned_escrow(&env, event_id)?;
+        let unlock_at = env.ledger().timestamp() + RECOVERY_TIMELOCK_SECS;
         
-        validation::validate_address(&organizer)?;
+        storage::set_recovery(&env, event_id, &RecoveryRequest {
+            bucket,
+            to: to.clone(),
+            amount,
+            unlock_at,
+            token: storage::event_token(&env, event_id),
+        });
+        events::recovery_proposed(&env, event_id, to, amount, unlock_at);
         
-        let mut event = storage::get_event(&env, event_id)?;
+        Ok(unlock_at)
+    }
+
+    /// Execute a proposed stuck-funds recovery once its timelock has passed
+    /// (admin only), returning the amount recovered
+    pub fn recover_stuck_funds(
+        env: Env,
+        admin: Address,
+        bucket: RecoveryBucket,
+        to: Address,
+    ) -> Result<i128, LumentixError> {
+        require_admin(&env, &admin)?;
         
-        if event.organizer != organizer {
-            return Err(LumentixError::Unauthorized);
-        }
+        let RecoveryBucket::OrphanedEscrow(event_id) = bucket;
+        let request = storage::get_recovery(&env, event_id)
+            .filter(|request| request.to == to)
+            .ok_or(LumentixError::RefundRequestNotFound)?;
         
-        if event.status != EventStatus::Active {
-            return Err(LumentixError::InvalidStatusTransition);
+        if env.ledger().timestamp() < request.unlock_at {
+            return Err(LumentixError::DraftRetentionNotElapsed);
         }
         
-        event.status = EventStatus::Cancelled;
-        storage::set_event(&env, event_id, &event);
+        let amount = orphaned_escrow(&env, event_id)?;
         
-        Ok(())
+        storage::clear_escrow(&env, event_id);
+        storage::remove_recovery(&env, event_id);
+        storage::record_withdrawal(&env, amount);
+        events::funds_recovered(&env, event_id, to.clone(), amount);
+        payments::pay_out_token(&env, &request.token, &to, amount);
+        
+        Ok(amount)
     }
 
-    /// Request refund for a ticket (only if event is cancelled)
-    pub fn refund_ticket(
+    /// Get the pending recovery proposal for a bucket, if any
+    pub fn get_recovery_request(env: Env, bucket: RecoveryBucket) -> Option<RecoveryRequest> {
+        let RecoveryBucket::OrphanedEscrow(event_id) = bucket;
+        storage::get_recovery(&env, event_id)
+    }
+
+    /// Mark an organizer as verified, raising their active event quota (admin only)
+    pub fn set_organizer_verified(
         env: Env,
-        ticket_id: u64,
-        buyer: Address,
+        admin: Address,
+        organizer: Address,
+        verified: bool,
     ) -> Result<(), LumentixError> {
-        buyer.require_auth();
-        
-        if !storage::is_initialized(&env) {
-            return Err(LumentixError::NotInitialized);
-        }
-        
-        validation::validate_address(&buyer)?;
+        require_admin(&env, &admin)?;
         
-        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        storage::set_verified_organizer(&env, &organizer, verified);
         
-        if ticket.owner != buyer {
-            return Err(LumentixError::Unauthorized);
-        }
+        Ok(())
+    }
+
+    /// Check whether an organizer is verified
+    pub fn is_organizer_verified(env: Env, organizer: Address) -> bool {
+        storage::is_verified_organizer(&env, &organizer)
+    }
+
+    /// Feature an event at `rank` until `until_ts` (admin only)
+    ///
+    /// Re-featuring an event replaces its placement; an `until_ts` that has
+    /// already passed removes it from the list. Events sharing a rank keep
+    /// the order they were featured in.
+    pub fn set_featured(
+        env: Env,
+        admin: Address,
+        event_id: u64,
+        rank: u32,
+        until_ts: u64,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
         
-        if ticket.used {
-            return Err(LumentixError::TicketAlreadyUsed);
-        }
+        storage::get_event(&env, event_id)?;
         
-        if ticket.refunded {
-            return Err(LumentixError::RefundNotAllowed);
+        let now = env.ledger().timestamp();
+        let mut featured = Vec::new(&env);
+        for placement in storage::get_featured(&env).iter() {
+            if placement.event_id != event_id && placement.until > now {
+                featured.push_back(placement);
+            }
         }
         
-        let event = storage::get_event(&env, ticket.event_id)?;
-        
-        if event.status != EventStatus::Cancelled {
-            return Err(LumentixError::EventNotCancelled);
+        if until_ts > now {
+            if featured.len() >= validation::MAX_FEATURED_EVENTS {
+                return Err(LumentixError::CapacityExceeded);
+            }
+            let position = featured.iter()
+                .position(|placement| placement.rank > rank)
+                .map_or(featured.len(), |index| index as u32);
+            featured.insert(position, FeaturedEvent { event_id, rank, until: until_ts });
+            events::featured(&env, event_id, rank, until_ts);
+        } else {
+            events::featured(&env, event_id, rank, 0);
         }
-        
-        ticket.refunded = true;
-        storage::set_ticket(&env, ticket_id, &ticket);
-        
-        // Deduct from escrow
-        storage::deduct_escrow(&env, event.id, event.ticket_price)?;
+        storage::set_featured(&env, &featured);
         
         Ok(())
     }
 
-    /// Release escrow funds to organizer (after event completion)
-    pub fn release_escrow(
+    /// List the featured event placements that have not lapsed, by rank
+    pub fn list_featured_events(env: Env) -> Vec<FeaturedEvent> {
+        let now = env.ledger().timestamp();
+        let mut featured = Vec::new(&env);
+        for placement in storage::get_featured(&env).iter() {
+            if placement.until > now {
+                featured.push_back(placement);
+            }
+        }
+        featured
+    }
+
+    /// Occupy a promotion slot for an event for `duration` seconds
+    ///
+    /// The slot costs `promotion_fee_per_day` for every started day, paid in
+    /// the event's asset into the platform balance. Returns the fee.
+    pub fn buy_promotion(
         env: Env,
         organizer: Address,
         event_id: u64,
+        slot: u32,
+        duration: u64,
     ) -> Result<i128, LumentixError> {
         organizer.require_auth();
         
@@ -361,34 +622,82 @@ impl LumentixContract {
             return Err(LumentixError::NotInitialized);
         }
         
-        validation::validate_address(&organizer)?;
-        
         let event = storage::get_event(&env, event_id)?;
         
         if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
         
-        if event.status != EventStatus::Completed {
-            return Err(LumentixError::InvalidStatusTransition);
+        if event.status != EventStatus::Active {
+            return Err(LumentixError::EventNotOnSale);
         }
         
-        let escrow_amount = storage::get_escrow(&env, event_id)?;
+        let config = storage::get_config(&env);
+        if slot >= config.promotion_slots {
+            return Err(LumentixError::InvalidConfig);
+        }
         
-        if escrow_amount == 0 {
-            return Err(LumentixError::EscrowAlreadyReleased);
+        if duration == 0 {
+            return Err(LumentixError::InvalidTimeRange);
         }
         
-        storage::clear_escrow(&env, event_id);
+        let now = env.ledger().timestamp();
+        if storage::get_promotion(&env, slot).is_some_and(|promotion| promotion.until > now) {
+            return Err(LumentixError::SponsorshipTaken);
+        }
         
-        Ok(escrow_amount)
+        let fee = config
+            .promotion_fee_per_day
+            .checked_mul(duration.div_ceil(SECONDS_PER_DAY) as i128)
+            .ok_or(LumentixError::InvalidTimeRange)?;
+        let until = now.checked_add(duration).ok_or(LumentixError::InvalidTimeRange)?;
+        storage::set_promotion(&env, &Promotion {
+            slot,
+            event_id,
+            organizer: organizer.clone(),
+            starts_at: now,
+            until,
+            paid: fee,
+        });
+        
+        // Accounting-only events move no tokens, so there is nothing to credit
+        if let Some(token) = storage::event_token(&env, event_id) {
+            storage::record_deposit(&env, fee);
+            storage::add_platform_balance(&env, &Some(token), fee);
+            payments::collect(&env, event_id, &organizer, fee);
+        }
+        
+        Ok(fee)
     }
 
-    /// Complete an event (after end time)
-    pub fn complete_event(
+    /// List the promotion slots currently occupied, by slot
+    pub fn list_promotions(env: Env) -> Vec<Promotion> {
+        let now = env.ledger().timestamp();
+        let mut promotions = Vec::new(&env);
+        for slot in 0..storage::get_config(&env).promotion_slots {
+            if let Some(promotion) = storage::get_promotion(&env, slot) {
+                if promotion.until > now {
+                    promotions.push_back(promotion);
+                }
+            }
+        }
+        promotions
+    }
+
+    /// Get the number of active events owned by an organizer
+    pub fn get_organizer_active_events(env: Env, organizer: Address) -> u32 {
+        storage::get_organizer_active_events(&env, &organizer)
+    }
+
+    /// Save the settings an organizer's new events are created with
+    ///
+    /// Each default is validated like its per-event setter and applied to
+    /// every event the organizer creates afterwards; events already created
+    /// are unaffected. An empty list clears the profile.
+    pub fn set_organizer_defaults(
         env: Env,
         organizer: Address,
-        event_id: u64,
+        defaults: Vec<OrganizerDefault>,
     ) -> Result<(), LumentixError> {
         organizer.require_auth();
         
@@ -396,36 +705,3623 @@ impl LumentixContract {
             return Err(LumentixError::NotInitialized);
         }
         
-        validation::validate_address(&organizer)?;
+        let config = storage::get_config(&env);
+        for default in defaults.iter() {
+            validation::validate_organizer_default(&config, &default)?;
+        }
+        storage::set_organizer_defaults(&env, &organizer, &defaults);
         
-        let mut event = storage::get_event(&env, event_id)?;
+        Ok(())
+    }
+
+    /// Get the settings an organizer's new events are created with
+    pub fn get_organizer_defaults(env: Env, organizer: Address) -> Vec<OrganizerDefault> {
+        storage::get_organizer_defaults(&env, &organizer)
+    }
+
+    /// Hand control of every event an organizer owns to a new address
+    ///
+    /// Both keys must authorize. Escrow stays keyed by event, so balances,
+    /// deposits and pending payouts follow the events to `new`; the
+    /// organizer's active-event count, settled volume, verification, rating,
+    /// defaults and fee exemption move with them. `new` must not already
+    /// control any events. Returns the number of events moved.
+    pub fn rotate_organizer_key(env: Env, old: Address, new: Address) -> Result<u32, LumentixError> {
+        old.require_auth();
+        new.require_auth();
         
-        if event.organizer != organizer {
-            return Err(LumentixError::Unauthorized);
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
         }
         
-        if event.status != EventStatus::Active {
-            return Err(LumentixError::InvalidStatusTransition);
+        if old == new || !storage::get_organizer_events(&env, &new).is_empty() {
+            return Err(LumentixError::InvalidAddress);
         }
         
-        let current_time = env.ledger().timestamp();
-        if current_time < event.end_time {
-            return Err(LumentixError::InvalidStatusTransition);
+        let event_ids = storage::get_organizer_events(&env, &old);
+        for event_id in event_ids.iter() {
+            let mut event = storage::get_event(&env, event_id)?;
+            event.organizer = new.clone();
+            storage::set_event(&env, event_id, &event);
         }
+        storage::move_organizer(&env, &old, &new);
         
-        event.status = EventStatus::Completed;
-        storage::set_event(&env, event_id, &event);
-        
-        Ok(())
+        events::organizer_rotated(&env, old, new, event_ids.len());
+        Ok(event_ids.len())
     }
 
-    /// Get event details
+    /// List the IDs of the stored events an organizer controls, in ID order
+    ///
+    /// Returns up to `limit` IDs after the `after` cursor together with the
+    /// cursor of the next page, if any; `limit` may not exceed
+    /// `MAX_BATCH_SIZE`.
+    pub fn get_organizer_events(
+        env: Env,
+        organizer: Address,
+        after: Option<u64>,
+        limit: u32,
+    ) -> Result<(Vec<u64>, Option<u64>), LumentixError> {
+        page(&storage::get_organizer_events(&env, &organizer), after, limit, |id| *id)
+    }
+
+    /// Create a new event, open for sales immediately
+    pub fn create_event(
+        env: Env,
+        organizer: Address,
+        name: String,
+        summary: String,
+        location: String,
+        start_time: u64,
+        end_time: u64,
+        ticket_price: i128,
+        max_tickets: u32,
+    ) -> Result<u64, LumentixError> {
+        organizer.require_auth();
+        
+        let event = Event {
+            id: 0,
+            organizer,
+            name,
+            summary,
+            description_hash: OptionalHash::None,
+            location,
+            start_time,
+            end_time,
+            ticket_price,
+            max_tickets,
+            tickets_sold: 0,
+            max_uses: 1,
+            allow_reentry: false,
+            status: EventStatus::Active,
+            created_at: env.ledger().timestamp(),
+        };
+        
+        create_event_record(&env, event, None)
+    }
+
+    /// Create a new event whose ID is derived from the organizer and a salt
+    ///
+    /// The same organizer and salt always yield the same ID (see
+    /// `compute_event_id`), so links can be prepared in advance and retries
+    /// cannot create duplicates.
+    pub fn create_event_with_salt(
+        env: Env,
+        organizer: Address,
+        salt: BytesN<32>,
+        name: String,
+        summary: String,
+        location: String,
+        start_time: u64,
+        end_time: u64,
+        ticket_price: i128,
+        max_tickets: u32,
+    ) -> Result<u64, LumentixError> {
+        organizer.require_auth();
+        
+        let event = Event {
+            id: 0,
+            organizer,
+            name,
+            summary,
+            description_hash: OptionalHash::None,
+            location,
+            start_time,
+            end_time,
+            ticket_price,
+            max_tickets,
+            tickets_sold: 0,
+            max_uses: 1,
+            allow_reentry: false,
+            status: EventStatus::Active,
+            created_at: env.ledger().timestamp(),
+        };
+        
+        create_event_record(&env, event, Some(salt))
+    }
+
+    /// Compute the event ID `create_event_with_salt` assigns for an organizer and salt
+    pub fn compute_event_id(env: Env, organizer: Address, salt: BytesN<32>) -> u64 {
+        derive_event_id(&env, &organizer, &salt)
+    }
+
+    /// Compute the check-in commitment for a ticket's QR payload
+    ///
+    /// Same function as `lumentix_types::compute_checkin_hash`, so apps and
+    /// gate scanners can check their commitments against the contract's.
+    pub fn compute_checkin_hash(env: Env, ticket_id: u64, owner: Address, nonce: u64) -> BytesN<32> {
+        lumentix_types::compute_checkin_hash(&env, ticket_id, &owner, nonce)
+    }
+
+    /// Create a new event as an unpublished draft
+    pub fn create_draft_event(
+        env: Env,
+        organizer: Address,
+        name: String,
+        summary: String,
+        location: String,
+        start_time: u64,
+        end_time: u64,
+        ticket_price: i128,
+        max_tickets: u32,
+    ) -> Result<u64, LumentixError> {
+        organizer.require_auth();
+        
+        let event = Event {
+            id: 0,
+            organizer,
+            name,
+            summary,
+            description_hash: OptionalHash::None,
+            location,
+            start_time,
+            end_time,
+            ticket_price,
+            max_tickets,
+            tickets_sold: 0,
+            max_uses: 1,
+            allow_reentry: false,
+            status: EventStatus::Draft,
+            created_at: env.ledger().timestamp(),
+        };
+        
+        create_event_record(&env, event, None)
+    }
+
+    /// Publish a draft event, opening it for sales
+    pub fn publish_event(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        publish(&env, &organizer, event_id)
+    }
+
+    /// Point an event at its full description, stored off-chain
+    ///
+    /// Only the `sha256` of the content is kept, optionally with a new
+    /// summary, so clients can check the text they fetched against it.
+    pub fn set_description_hash(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        description_hash: BytesN<32>,
+        summary: Option<String>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if matches!(event.status, EventStatus::Cancelled | EventStatus::Completed) {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        if let Some(summary) = summary {
+            validation::validate_summary(&summary)?;
+            event.summary = summary;
+        }
+        event.description_hash = OptionalHash::Some(description_hash.clone());
+        storage::set_event(&env, event_id, &event);
+        events::description_updated(&env, event_id, description_hash);
+        
+        Ok(())
+    }
+
+    /// Move several of an organizer's events to `status` in one call
+    ///
+    /// Each transition is validated on its own, exactly as `publish_event`
+    /// (`Active`) or `complete_event` (`Completed`) would; a failing event is
+    /// reported in its result and does not stop the rest of the batch.
+    /// Cancellation needs a per-event reason, so `Cancelled` (like `Draft`)
+    /// is reported as `InvalidStatusTransition` - use `cancel_event`.
+    pub fn update_events_status(
+        env: Env,
+        organizer: Address,
+        event_ids: Vec<u64>,
+        status: EventStatus,
+    ) -> Result<Vec<StatusUpdateResult>, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(event_ids.len())?;
+        
+        let mut results = Vec::new(&env);
+        for event_id in event_ids.iter() {
+            let outcome = match status {
+                EventStatus::Active => publish(&env, &organizer, event_id),
+                EventStatus::Completed => complete(&env, &organizer, event_id),
+                EventStatus::Draft | EventStatus::Cancelled => {
+                    Err(LumentixError::InvalidStatusTransition)
+                }
+            };
+            results.push_back(StatusUpdateResult {
+                event_id,
+                error_code: outcome.err().map_or(0, |error| error as u32),
+            });
+        }
+        
+        Ok(results)
+    }
+
+    /// Remove a never-published draft older than the retention threshold (admin only)
+    pub fn purge_draft(
+        env: Env,
+        admin: Address,
+        event_id: u64,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.status != EventStatus::Draft {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let config = storage::get_config(&env);
+        let current_time = env.ledger().timestamp();
+        if current_time < event.created_at.saturating_add(config.draft_retention_secs) {
+            return Err(LumentixError::DraftRetentionNotElapsed);
+        }
+        
+        storage::remove_event(&env, event_id);
+        storage::decrement_organizer_active_events(&env, &event.organizer);
+        
+        Ok(())
+    }
+
+    /// Purchase a ticket for an event
+    ///
+    /// `terms_hash` is the hash of the refund/terms policy the buyer accepted;
+    /// it is covered by the buyer's authorization and recorded against the
+    /// ticket as proof of acceptance. Once the organizer has registered a
+    /// policy it must match the current version, which the ticket is bound to.
+    ///
+    /// `payment_asset` is the asset the buyer is paying with; it must match
+    /// the event's configured payment asset, token and decimals alike.
+    ///
+    /// `memo` is an optional buyer reference, such as a purchase order
+    /// number, recorded against the ticket for payment reconciliation.
+    pub fn purchase_ticket(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        payment_amount: i128,
+        terms_hash: Option<BytesN<32>>,
+        payment_asset: Option<PaymentAsset>,
+        memo: Option<BytesN<32>>,
+    ) -> Result<u64, LumentixError> {
+        let request = PurchaseRequest {
+            terms_hash: terms_hash.into(),
+            payment_asset: payment_asset.into(),
+            add_ons: Vec::new(&env),
+            fields: Map::new(&env),
+            memo: memo.into(),
+        };
+        sell_ticket(&env, buyer, event_id, payment_amount, request)
+    }
+
+    /// Purchase a ticket with everything its event may ask of the buyer:
+    /// accepted terms, payment asset, add-ons, custom fields and memo
+    ///
+    /// Each part of `request` is checked as in `purchase_ticket`,
+    /// `purchase_ticket_with_add_ons` and `purchase_ticket_with_fields`.
+    pub fn purchase_ticket_with_request(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        payment_amount: i128,
+        request: PurchaseRequest,
+    ) -> Result<u64, LumentixError> {
+        sell_ticket(&env, buyer, event_id, payment_amount, request)
+    }
+
+    /// Purchase a ticket together with add-on items of its event
+    ///
+    /// `add_ons` lists add-on IDs, repeated for quantities above one.
+    /// `payment_amount` must cover the ticket price plus every add-on.
+    pub fn purchase_ticket_with_add_ons(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        payment_amount: i128,
+        terms_hash: Option<BytesN<32>>,
+        payment_asset: Option<PaymentAsset>,
+        add_ons: Vec<u32>,
+        memo: Option<BytesN<32>>,
+    ) -> Result<u64, LumentixError> {
+        let request = PurchaseRequest {
+            terms_hash: terms_hash.into(),
+            payment_asset: payment_asset.into(),
+            add_ons,
+            fields: Map::new(&env),
+            memo: memo.into(),
+        };
+        sell_ticket(&env, buyer, event_id, payment_amount, request)
+    }
+
+    /// Purchase a ticket, supplying the custom fields its event collects
+    ///
+    /// `fields` maps each of the event's `get_ticket_fields` to the hash of
+    /// the buyer's answer; the answers themselves stay off-chain.
+    pub fn purchase_ticket_with_fields(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        payment_amount: i128,
+        terms_hash: Option<BytesN<32>>,
+        payment_asset: Option<PaymentAsset>,
+        fields: Map<Symbol, BytesN<32>>,
+        memo: Option<BytesN<32>>,
+    ) -> Result<u64, LumentixError> {
+        let request = PurchaseRequest {
+            terms_hash: terms_hash.into(),
+            payment_asset: payment_asset.into(),
+            add_ons: Vec::new(&env),
+            fields,
+            memo: memo.into(),
+        };
+        sell_ticket(&env, buyer, event_id, payment_amount, request)
+    }
+
+    /// Define the custom fields, such as dietary needs or shirt size, that
+    /// buyers of an event's tickets must supply as hashes
+    ///
+    /// Can only be changed before any ticket is sold. Repeated names are
+    /// stored once; at most `MAX_TICKET_FIELDS` are accepted.
+    pub fn set_ticket_fields(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        fields: Vec<Symbol>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::TicketsAlreadySold);
+        }
+        
+        let mut distinct = Vec::new(&env);
+        for field in fields.iter() {
+            if !distinct.contains(&field) {
+                distinct.push_back(field);
+            }
+        }
+        if distinct.len() > validation::MAX_TICKET_FIELDS {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        storage::set_ticket_fields(&env, event_id, &distinct);
+        
+        Ok(())
+    }
+
+    /// Get the custom fields an event's buyers must supply
+    pub fn get_ticket_fields(env: Env, event_id: u64) -> Vec<Symbol> {
+        storage::get_ticket_fields(&env, event_id)
+    }
+
+    /// Get the hashed custom field values supplied with a ticket
+    pub fn get_ticket_field_values(env: Env, ticket_id: u64) -> Map<Symbol, BytesN<32>> {
+        storage::get_field_values(&env, ticket_id)
+    }
+
+    /// Give holders of a ticket to `required_event_id` `discount_bps` off
+    /// this event's tickets; a `discount_bps` of 0 removes the discount
+    ///
+    /// Setting a discount for the same partner event again replaces it. A
+    /// buyer qualifying for several discounts gets the largest.
+    pub fn set_bundle_discount(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        required_event_id: u64,
+        discount_bps: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if discount_bps > 10_000 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if required_event_id == event_id || !storage::has_event(&env, required_event_id) {
+            return Err(LumentixError::EventNotFound);
+        }
+        
+        let mut discounts = storage::get_bundle_discounts(&env, event_id);
+        if let Some(index) = discounts.iter().position(|discount| discount.required_event_id == required_event_id) {
+            discounts.remove(index as u32);
+        }
+        if discount_bps > 0 {
+            if discounts.len() >= validation::MAX_BUNDLE_DISCOUNTS {
+                return Err(LumentixError::CapacityExceeded);
+            }
+            discounts.push_back(BundleDiscount {
+                required_event_id,
+                discount_bps,
+            });
+        }
+        storage::set_bundle_discounts(&env, event_id, &discounts);
+        
+        Ok(())
+    }
+
+    /// Get an event's bundle discounts
+    pub fn get_bundle_discounts(env: Env, event_id: u64) -> Vec<BundleDiscount> {
+        storage::get_bundle_discounts(&env, event_id)
+    }
+
+    /// Price an event's tickets for members of an external membership
+    /// contract, by membership tier; `None` removes member pricing
+    ///
+    /// Member prices must be positive, for at most `MAX_MEMBER_TIERS` tiers.
+    /// A member pays the lower of their tier's price and any bundle
+    /// discounted price.
+    pub fn set_member_pricing(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        pricing: Option<MemberPricing>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        match pricing {
+            Some(pricing) => {
+                if pricing.prices.len() > validation::MAX_MEMBER_TIERS {
+                    return Err(LumentixError::CapacityExceeded);
+                }
+                for price in pricing.prices.values().iter() {
+                    validation::validate_positive_amount(price)?;
+                }
+                storage::set_member_pricing(&env, event_id, &pricing);
+            }
+            None => storage::remove_member_pricing(&env, event_id),
+        }
+        
+        Ok(())
+    }
+
+    /// Get an event's member pricing, if any
+    pub fn get_member_pricing(env: Env, event_id: u64) -> Option<MemberPricing> {
+        storage::get_member_pricing(&env, event_id)
+    }
+
+    /// Get the number of an event's tickets an account currently holds
+    pub fn get_holdings(env: Env, account: Address, event_id: u64) -> u32 {
+        storage::get_holdings(&env, &account, event_id)
+    }
+
+    /// Approve an event to import up to `approved` tickets sold on a legacy
+    /// system (admin only)
+    ///
+    /// `approved` is the event's total allowance and may not drop below what
+    /// it has already imported.
+    pub fn approve_import(
+        env: Env,
+        admin: Address,
+        event_id: u64,
+        approved: u32,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        storage::get_event(&env, event_id)?;
+        
+        let mut allowance = storage::get_import_allowance(&env, event_id).unwrap_or_default();
+        if approved < allowance.imported {
+            return Err(LumentixError::InvalidCapacity);
+        }
+        allowance.approved = approved;
+        storage::set_import_allowance(&env, event_id, &allowance);
+        
+        Ok(())
+    }
+
+    /// Get an event's legacy ticket import allowance, if one was approved
+    pub fn get_import_allowance(env: Env, event_id: u64) -> Option<ImportAllowance> {
+        storage::get_import_allowance(&env, event_id)
+    }
+
+    /// Mint tickets sold on a legacy system to their owners, returning
+    /// their IDs
+    ///
+    /// No payment moves: imported tickets take seats but carry no escrow,
+    /// so they are flagged as imported and cannot be refunded on-chain.
+    /// Each entry is an owner and tier; at most `MAX_BATCH_SIZE` per call,
+    /// within the allowance the admin approved.
+    pub fn import_tickets(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        tickets: Vec<(Address, u32)>,
+    ) -> Result<Vec<u64>, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(tickets.len())?;
+        
+        let mut event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let mut allowance = storage::get_import_allowance(&env, event_id).ok_or(LumentixError::Unauthorized)?;
+        if allowance.imported + tickets.len() > allowance.approved {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        if event.tickets_sold + tickets.len() > event.max_tickets {
+            return Err(LumentixError::EventSoldOut);
+        }
+        
+        let mut ticket_ids = Vec::new(&env);
+        for (owner, tier) in tickets.iter() {
+            validation::validate_address(&owner)?;
+            
+            let ticket_id = storage::get_next_ticket_id(&env);
+            let ticket = Ticket {
+                id: ticket_id,
+                event_id,
+                owner: owner.clone(),
+                tier,
+                purchase_time: env.ledger().timestamp(),
+                uses: 0,
+                last_used_at: 0,
+                status: issued_status(&env, event_id),
+                inside: false,
+                fee_bps: 0,
+                fee_paid: 0,
+            };
+            storage::set_ticket(&env, ticket_id, &ticket);
+            storage::set_imported(&env, ticket_id);
+            storage::increment_ticket_id(&env);
+            record_history(&env, &owner, HistoryAction::Purchased, &ticket, 0);
+            storage::add_holding(&env, &owner, event_id);
+            events::ticket_imported(&env, event_id, ticket_id);
+            ticket_ids.push_back(ticket_id);
+        }
+        
+        allowance.imported += tickets.len();
+        storage::set_import_allowance(&env, event_id, &allowance);
+        event.tickets_sold += tickets.len();
+        storage::set_event(&env, event_id, &event);
+        storage::record_imports(&env, event_id, tickets.len());
+        if event.status == EventStatus::Active && event.tickets_sold == event.max_tickets {
+            events::sold_out(&env, event_id, event.tickets_sold);
+            open_waitlist(&env, event_id);
+        }
+        
+        Ok(ticket_ids)
+    }
+
+    /// Check whether a ticket was imported from a legacy system rather than
+    /// sold through the contract
+    pub fn is_imported_ticket(env: Env, ticket_id: u64) -> bool {
+        storage::is_imported(&env, ticket_id)
+    }
+
+    /// Simulate buying `quantity` tickets without changing any state
+    ///
+    /// Runs the same checks as `purchase_ticket_with_request`, with every
+    /// ticket bought with `request` (or nothing, if `None`), and returns the
+    /// ticket IDs and cost the purchase would have right now, or the error
+    /// it would fail with, so wallets can preflight a purchase before asking
+    /// the buyer to sign.
+    pub fn simulate_purchase(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        quantity: u32,
+        request: Option<PurchaseRequest>,
+    ) -> Result<PurchaseSimulation, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&buyer)?;
+        validation::validate_positive_capacity(quantity)?;
+        validation::validate_batch_size(quantity)?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        check_sale(&env, &event, &buyer, BASE_TIER, quantity)?;
+        
+        let request = request.unwrap_or(PurchaseRequest {
+            terms_hash: OptionalHash::None,
+            payment_asset: OptionalAsset::None,
+            add_ons: Vec::new(&env),
+            fields: Map::new(&env),
+            memo: OptionalHash::None,
+        });
+        check_purchase_request(&env, event_id, &request)?;
+        let (_, add_ons_total) = reserve_add_ons(&env, event_id, &request.add_ons, quantity)?;
+        
+        let first_id = storage::get_next_ticket_id(&env);
+        let mut ticket_ids = Vec::new(&env);
+        for offset in 0..quantity as u64 {
+            ticket_ids.push_back(first_id + offset);
+        }
+        
+        let quote = fee_quote(&env, &event)?;
+        
+        Ok(PurchaseSimulation {
+            ticket_ids,
+            total_cost: (price_for(&env, &event, &buyer, &quote)? + add_ons_total) * quantity as i128,
+            fee: quote.fee * quantity as i128,
+        })
+    }
+
+    /// Offer an add-on item (parking, merchandise, meals) for an event,
+    /// returning its ID
+    pub fn add_add_on(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        name: String,
+        price: i128,
+        inventory: u32,
+    ) -> Result<u32, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_string_not_empty(&name)?;
+        validation::validate_positive_amount(price)?;
+        validation::validate_positive_capacity(inventory)?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let mut add_ons = storage::get_add_ons(&env, event_id);
+        if add_ons.len() >= validation::MAX_ADD_ONS_PER_EVENT {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        let add_on_id = add_ons.len();
+        add_ons.push_back(AddOn {
+            id: add_on_id,
+            name,
+            price,
+            inventory,
+            sold: 0,
+        });
+        storage::set_add_ons(&env, event_id, &add_ons);
+        
+        Ok(add_on_id)
+    }
+
+    /// Get an event's add-on items with their remaining inventory
+    pub fn get_add_ons(env: Env, event_id: u64) -> Vec<AddOn> {
+        storage::get_add_ons(&env, event_id)
+    }
+
+    /// Get the add-ons bought with a ticket, if any
+    pub fn get_ticket_add_ons(env: Env, ticket_id: u64) -> Option<TicketAddOns> {
+        storage::get_ticket_add_ons(&env, ticket_id)
+    }
+
+    /// Schedule `count` more tickets of an event to go on sale at
+    /// `at_timestamp`
+    ///
+    /// Once an event has waves, only the capacity of waves already released
+    /// can be sold; the rest of `max_tickets` stays unsellable.
+    pub fn release_wave(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        count: u32,
+        at_timestamp: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_positive_capacity(count)?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let mut waves = storage::get_capacity_waves(&env, event_id);
+        if waves.len() >= validation::MAX_CAPACITY_WAVES {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        let scheduled: u32 = waves.iter().map(|wave| wave.count).sum();
+        if scheduled + count > event.max_tickets {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        waves.push_back(CapacityWave {
+            count,
+            release_at: at_timestamp,
+        });
+        storage::set_capacity_waves(&env, event_id, &waves);
+        
+        Ok(())
+    }
+
+    /// Get an event's scheduled capacity waves
+    pub fn get_capacity_waves(env: Env, event_id: u64) -> Vec<CapacityWave> {
+        storage::get_capacity_waves(&env, event_id)
+    }
+
+    /// Close an event's sales `seconds_before_start` seconds before its
+    /// start time; zero removes the cutoff so sales run until the end time
+    pub fn set_sales_cutoff(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        seconds_before_start: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        if seconds_before_start == 0 {
+            storage::remove_sales_cutoff(&env, event_id);
+        } else {
+            storage::set_sales_cutoff(&env, event_id, seconds_before_start);
+        }
+        
+        Ok(())
+    }
+
+    /// Get how many seconds before its start an event's sales close, if set
+    pub fn get_sales_cutoff(env: Env, event_id: u64) -> Option<u64> {
+        storage::get_sales_cutoff(&env, event_id)
+    }
+
+    /// Hold back `count` extra tickets (e.g. standing room) until the event
+    /// reaches `unlock`; a zero count removes a rule that has not unlocked yet
+    ///
+    /// The threshold is checked after each sale. Once reached, `count` is
+    /// added to `max_tickets` for good.
+    pub fn set_bonus_capacity(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        count: u32,
+        unlock: BonusUnlock,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        if storage::get_bonus_capacity(&env, event_id).is_some_and(|bonus| bonus.unlocked) {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        if count == 0 {
+            storage::remove_bonus_capacity(&env, event_id);
+            return Ok(());
+        }
+        
+        match unlock {
+            BonusUnlock::Revenue(amount) => validation::validate_positive_amount(amount)?,
+            BonusUnlock::SellThroughBps(bps) if bps == 0 || bps > 10_000 => {
+                return Err(LumentixError::InvalidConfig);
+            }
+            BonusUnlock::SellThroughBps(_) => {}
+        }
+        
+        let config = storage::get_config(&env);
+        if event.max_tickets as u64 + count as u64 > config.max_capacity_per_event as u64 {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        storage::set_bonus_capacity(&env, event_id, &BonusCapacity {
+            count,
+            unlock,
+            unlocked: false,
+        });
+        
+        Ok(())
+    }
+
+    /// Get an event's bonus capacity rule, if set
+    pub fn get_bonus_capacity(env: Env, event_id: u64) -> Option<BonusCapacity> {
+        storage::get_bonus_capacity(&env, event_id)
+    }
+
+    /// Give one ticket tier its own sales window, e.g. VIP closing early or
+    /// door tickets opening on the day; `None` removes the override
+    ///
+    /// The window only narrows sales: the event's own close time (end time
+    /// or sales cutoff) still applies.
+    pub fn set_tier_sales_window(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        tier: u32,
+        window: Option<SalesWindow>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        if tier >= storage::get_config(&env).max_tiers {
+            return Err(LumentixError::InvalidConfig);
+        }
+        
+        let mut windows = storage::get_tier_sales_windows(&env, event_id);
+        match window {
+            Some(window) => {
+                validation::validate_time_range(window.opens_at, window.closes_at)?;
+                windows.set(tier, window);
+            }
+            None => {
+                windows.remove(tier);
+            }
+        }
+        storage::set_tier_sales_windows(&env, event_id, &windows);
+        
+        Ok(())
+    }
+
+    /// Get a ticket tier's sales window override, if set
+    pub fn get_tier_sales_window(env: Env, event_id: u64, tier: u32) -> Option<SalesWindow> {
+        storage::get_tier_sales_windows(&env, event_id).get(tier)
+    }
+
+    /// Get how many of an event's tickets have been released for sale so far
+    pub fn get_released_capacity(env: Env, event_id: u64) -> Result<u32, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        Ok(released_capacity(&env, &event))
+    }
+
+    /// Use a ticket (mark as used), returning the attendee details
+    ///
+    /// `secret` is the one scanned from the attendee's QR code; it must
+    /// match the ticket's check-in commitment when its holder set one.
+    pub fn use_ticket(
+        env: Env,
+        ticket_id: u64,
+        validator: Address,
+        secret: Bytes,
+    ) -> Result<CheckInResult, LumentixError> {
+        validator.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&validator)?;
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        // Only the organizer or one of the event's gate devices can check in
+        check_gate_validator(&env, &validator, &event)?;
+        
+        admit_or_fail(&env, ticket, &event, &secret)
+    }
+
+    /// Check in a ticket with a pass its holder signed in advance
+    ///
+    /// The holder (the borrower during a loan) authorizes the arguments
+    /// `(ticket_id, expiry, nonce)` offline; the validator submits them
+    /// later, up to `expiry`. Each nonce admits once per holder, so a pass
+    /// cannot be replayed. The pass carries the holder's QR secret as well.
+    pub fn check_in_signed(
+        env: Env,
+        validator: Address,
+        ticket_id: u64,
+        expiry: u64,
+        nonce: u64,
+        secret: Bytes,
+    ) -> Result<CheckInResult, LumentixError> {
+        validator.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        // Only the organizer or one of the event's gate devices can check in
+        check_gate_validator(&env, &validator, &event)?;
+        
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, TicketError::PassExpired);
+        }
+        
+        let holder = storage::get_active_loan(&env, ticket_id).map_or(ticket.owner.clone(), |loan| loan.borrower);
+        holder.require_auth_for_args((ticket_id, expiry, nonce).into_val(&env));
+        
+        if storage::is_checkin_nonce_used(&env, &holder, nonce) {
+            panic_with_error!(&env, TicketError::NonceUsed);
+        }
+        storage::use_checkin_nonce(&env, &holder, nonce);
+        
+        admit_or_fail(&env, ticket, &event, &secret)
+    }
+
+    /// Check whether a ticket holder has already spent a signed check-in nonce
+    pub fn is_checkin_nonce_used(env: Env, holder: Address, nonce: u64) -> bool {
+        storage::is_checkin_nonce_used(&env, &holder, nonce)
+    }
+
+    /// Record that an admitted attendee has left the venue
+    ///
+    /// On events that allow re-entry the ticket can then be scanned again
+    /// until the event ends without consuming another use.
+    pub fn check_out(
+        env: Env,
+        ticket_id: u64,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        validator.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        // Only organizer can validate tickets
+        if validator != event.organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if !ticket.inside {
+            return Err(LumentixError::TicketNotCheckedIn);
+        }
+        
+        ticket.inside = false;
+        storage::set_ticket(&env, ticket_id, &ticket);
+        storage::record_exit(&env, ticket.event_id);
+        
+        Ok(())
+    }
+
+    /// Allow or forbid re-entry after check-out for an event
+    pub fn set_reentry_allowed(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        allowed: bool,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        event.allow_reentry = allowed;
+        storage::set_event(&env, event_id, &event);
+        
+        Ok(())
+    }
+
+    /// Allow or forbid an event's tickets to change hands through resale
+    /// offers and swaps
+    ///
+    /// Tickets are transferable by default. Open offers and swaps stay in
+    /// place but cannot settle while transfers are off.
+    pub fn set_transferable(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        transferable: bool,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        storage::set_transferable(&env, event_id, transferable);
+        
+        Ok(())
+    }
+
+    /// Whether an event's tickets may be resold or swapped
+    pub fn is_transferable(env: Env, event_id: u64) -> bool {
+        storage::is_transferable(&env, event_id)
+    }
+
+    /// Scan a ticket at the gate of a specific event
+    ///
+    /// Unlike `use_ticket`, ticket-level failures (unknown ticket, wrong
+    /// event, already used, refunded, wrong secret) are returned as a
+    /// `Rejected` outcome rather than an error, so the attempt is recorded in
+    /// the event's failed check-in log instead of being rolled back.
+    pub fn check_in(
+        env: Env,
+        validator: Address,
+        event_id: u64,
+        ticket_id: u64,
+        secret: Bytes,
+    ) -> Result<CheckInOutcome, LumentixError> {
+        validator.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        // Only the organizer or one of the event's gate devices can check in
+        check_gate_validator(&env, &validator, &event)?;
+        
+        let rejection = match storage::get_ticket(&env, ticket_id) {
+            Err(_) => CheckInRejection::NotFound,
+            Ok(ticket) if ticket.event_id != event_id => CheckInRejection::WrongEvent,
+            Ok(ticket) => match check_admissible(&env, &ticket, &event) {
+                Err(rejection) => rejection,
+                Ok(()) if !secret_matches(&env, ticket_id, &secret) => CheckInRejection::WrongSecret,
+                Ok(()) => return admit_ticket(&env, ticket, &event).map(CheckInOutcome::Admitted),
+            },
+        };
+        
+        storage::log_failed_check_in(
+            &env,
+            event_id,
+            &FailedCheckIn {
+                ticket_id,
+                verifier: validator,
+                reason: rejection.clone(),
+                attempted_at: env.ledger().timestamp(),
+            },
+        );
+        
+        Ok(CheckInOutcome::Rejected(rejection))
+    }
+
+    /// Get the most recent failed check-in attempts for an event, oldest first
+    pub fn get_failed_check_ins(env: Env, event_id: u64) -> Result<Vec<FailedCheckIn>, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        storage::get_event(&env, event_id)?;
+        Ok(storage::get_failed_check_ins(&env, event_id))
+    }
+
+    /// Register a gate scanner for an event, or renew its registration
+    ///
+    /// The device can check tickets in at this event until `expires_at` and
+    /// do nothing else, so a lost scanner is revoked without rotating the
+    /// organizer's key.
+    pub fn register_gate_device(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        device: Address,
+        expires_at: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&device)?;
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if expires_at <= env.ledger().timestamp() {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        
+        let mut devices = storage::get_gate_devices(&env, event_id);
+        let registration = GateDevice { device: device.clone(), expires_at };
+        match devices.iter().position(|gate| gate.device == device) {
+            Some(index) => devices.set(index as u32, registration),
+            None if devices.len() >= validation::MAX_GATE_DEVICES_PER_EVENT => {
+                return Err(LumentixError::CapacityExceeded);
+            }
+            None => devices.push_back(registration),
+        }
+        storage::set_gate_devices(&env, event_id, &devices);
+        events::gate_device(&env, event_id, device, expires_at);
+        
+        Ok(())
+    }
+
+    /// Revoke a gate scanner's registration for an event immediately
+    pub fn revoke_gate_device(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        device: Address,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        let mut devices = storage::get_gate_devices(&env, event_id);
+        if let Some(index) = devices.iter().position(|gate| gate.device == device) {
+            devices.remove(index as u32);
+            storage::set_gate_devices(&env, event_id, &devices);
+            events::gate_device(&env, event_id, device, 0);
+        }
+        
+        Ok(())
+    }
+
+    /// Get an event's gate devices, including ones whose registration lapsed
+    pub fn get_gate_devices(env: Env, event_id: u64) -> Result<Vec<GateDevice>, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        storage::get_event(&env, event_id)?;
+        Ok(storage::get_gate_devices(&env, event_id))
+    }
+
+    /// Replace a ticket's check-in secret commitment before the event starts
+    ///
+    /// The commitment is `sha256(secret)` where the secret is encoded in the
+    /// attendee's QR code. Rotating it invalidates any previously leaked code.
+    pub fn rotate_checkin_hash(
+        env: Env,
+        owner: Address,
+        ticket_id: u64,
+        new_hash: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        owner.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let loan = storage::get_active_loan(&env, ticket_id);
+        
+        // A lent ticket's commitment is set by its borrower
+        let holder = loan.as_ref().map_or(&ticket.owner, |loan| &loan.borrower);
+        if *holder != owner {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        check_refundable(&ticket)?;
+        
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if env.ledger().timestamp() >= event.start_time {
+            return Err(LumentixError::EventAlreadyStarted);
+        }
+        
+        match loan {
+            Some(mut loan) => {
+                loan.checkin_hash = OptionalHash::Some(new_hash);
+                storage::set_loan(&env, ticket_id, &loan);
+            }
+            None => storage::set_checkin_hash(&env, ticket_id, &new_hash),
+        }
+        
+        Ok(())
+    }
+
+    /// Get a ticket's current check-in secret commitment, if one is set
+    ///
+    /// During a loan this is the borrower's commitment; the owner's applies
+    /// again once the loan ends.
+    pub fn get_checkin_hash(env: Env, ticket_id: u64) -> Option<BytesN<32>> {
+        checkin_hash(&env, ticket_id)
+    }
+
+    /// Lend a ticket to `borrower` until `until`, when it reverts to the
+    /// owner without further calls
+    ///
+    /// The borrower sets the check-in commitment for the loan; the owner
+    /// cannot refund, resell or swap the ticket until it ends.
+    pub fn lend_ticket(
+        env: Env,
+        owner: Address,
+        ticket_id: u64,
+        borrower: Address,
+        until: u64,
+    ) -> Result<(), LumentixError> {
+        owner.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&borrower)?;
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != owner || borrower == owner {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if ticket.status != TicketStatus::Valid || storage::get_active_loan(&env, ticket_id).is_some() {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let now = env.ledger().timestamp();
+        if until <= now {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        
+        storage::set_loan(&env, ticket_id, &TicketLoan {
+            borrower: borrower.clone(),
+            lent_at: now,
+            until,
+            checkin_hash: OptionalHash::None,
+        });
+        events::ticket_lent(&env, ticket.event_id, ticket_id, borrower, until);
+        
+        Ok(())
+    }
+
+    /// End a ticket's loan before its deadline, returning it to the owner
+    pub fn end_loan(env: Env, owner: Address, ticket_id: u64) -> Result<(), LumentixError> {
+        owner.require_auth();
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != owner {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if storage::get_active_loan(&env, ticket_id).is_none() {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        storage::remove_loan(&env, ticket_id);
+        
+        Ok(())
+    }
+
+    /// Get a ticket's loan, until it ends
+    pub fn get_ticket_loan(env: Env, ticket_id: u64) -> Option<TicketLoan> {
+        storage::get_active_loan(&env, ticket_id)
+    }
+
+    /// Get who currently holds a ticket: its borrower during a loan,
+    /// otherwise its owner
+    pub fn get_ticket_holder(env: Env, ticket_id: u64) -> Result<Address, LumentixError> {
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        Ok(storage::get_active_loan(&env, ticket_id).map_or(ticket.owner, |loan| loan.borrower))
+    }
+
+    /// Sell an event's tickets will-call only, held by the contract until
+    /// picked up from `opens_before` seconds before the event starts
+    ///
+    /// `None` sells normally again. Can only be changed before any ticket is
+    /// sold.
+    pub fn set_will_call(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        opens_before: Option<u64>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::TicketsAlreadySold);
+        }
+        
+        storage::set_will_call(&env, event_id, opens_before);
+        
+        Ok(())
+    }
+
+    /// Get how long before an event starts its will-call pickup opens, if it
+    /// sells will-call only
+    pub fn get_will_call(env: Env, event_id: u64) -> Option<u64> {
+        storage::get_will_call(&env, event_id)
+    }
+
+    /// Name who may pick up a will-call ticket and the `sha256` of the code
+    /// they will present, replacing any earlier choice
+    pub fn set_pickup(
+        env: Env,
+        owner: Address,
+        ticket_id: u64,
+        pickup: Address,
+        code_hash: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        owner.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&pickup)?;
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != owner {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if ticket.status != TicketStatus::WillCall {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        storage::set_pickup(&env, ticket_id, &WillCallPickup { pickup, code_hash });
+        
+        Ok(())
+    }
+
+    /// Get who may pick up a will-call ticket
+    pub fn get_pickup(env: Env, ticket_id: u64) -> Option<WillCallPickup> {
+        storage::get_pickup(&env, ticket_id)
+    }
+
+    /// Pick up a will-call ticket with its code, making it valid
+    ///
+    /// The owner or the named pickup address may claim it once pickup opens
+    /// and until the event ends. A ticket picked up by someone other than its
+    /// owner becomes theirs.
+    pub fn claim_will_call(
+        env: Env,
+        claimer: Address,
+        ticket_id: u64,
+        code: Bytes,
+    ) -> Result<(), LumentixError> {
+        claimer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.status != TicketStatus::WillCall {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let pickup = storage::get_pickup(&env, ticket_id).ok_or(LumentixError::Unauthorized)?;
+        
+        if claimer != ticket.owner && claimer != pickup.pickup {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if BytesN::from(env.crypto().sha256(&code)) != pickup.code_hash {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        let event = storage::get_event(&env, ticket.event_id)?;
+        let opens_before = storage::get_will_call(&env, event.id).unwrap_or(0);
+        let now = env.ledger().timestamp();
+        if now < event.start_time.saturating_sub(opens_before) || now >= event.end_time {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        
+        ticket.status = TicketStatus::Valid;
+        if claimer != ticket.owner {
+            let owner = ticket.owner.clone();
+            ticket.owner = claimer.clone();
+            record_history(&env, &owner, HistoryAction::TransferredOut, &ticket, 0);
+            record_history(&env, &claimer, HistoryAction::TransferredIn, &ticket, 0);
+            storage::remove_holding(&env, &owner, event.id);
+            storage::add_holding(&env, &claimer, event.id);
+        }
+        storage::set_ticket(&env, ticket_id, &ticket);
+        storage::remove_pickup(&env, ticket_id);
+        events::ticket_picked_up(&env, event.id, ticket_id, claimer);
+        
+        Ok(())
+    }
+
+    /// Burn a ticket for redemption outside the contract, such as conversion
+    /// into a partner's system
+    ///
+    /// `redemption_hash` commits to the off-chain redemption record. Only an
+    /// unused ticket that could change hands can be burned; its seat stays
+    /// sold and its escrow with the event.
+    pub fn burn_for_external(
+        env: Env,
+        owner: Address,
+        ticket_id: u64,
+        redemption_hash: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        owner.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != owner {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        check_resellable(&env, &ticket)?;
+        check_not_lent(&env, ticket_id)?;
+        
+        ticket.status = TicketStatus::Redeemed;
+        storage::set_ticket(&env, ticket_id, &ticket);
+        storage::set_redemption(&env, ticket_id, &redemption_hash);
+        record_history(&env, &owner, HistoryAction::Redeemed, &ticket, 0);
+        storage::remove_holding(&env, &owner, ticket.event_id);
+        events::ticket_redeemed(&env, ticket.event_id, ticket_id, redemption_hash);
+        
+        Ok(())
+    }
+
+    /// Get the redemption record hash of a ticket burned for external
+    /// redemption
+    pub fn get_external_redemption(env: Env, ticket_id: u64) -> Option<BytesN<32>> {
+        storage::get_redemption(&env, ticket_id)
+    }
+
+    /// Get the terms-of-sale hash the buyer accepted when purchasing a ticket
+    pub fn get_terms_hash(env: Env, ticket_id: u64) -> Option<BytesN<32>> {
+        storage::get_terms_hash(&env, ticket_id)
+    }
+
+    /// Get the buyer's memo recorded when purchasing a ticket
+    pub fn get_purchase_memo(env: Env, ticket_id: u64) -> Option<BytesN<32>> {
+        storage::get_purchase_memo(&env, ticket_id)
+    }
+
+    /// Check a scanned check-in secret against the ticket's current commitment
+    pub fn verify_checkin_secret(env: Env, ticket_id: u64, secret: Bytes) -> bool {
+        match checkin_hash(&env, ticket_id) {
+            Some(hash) => BytesN::from(env.crypto().sha256(&secret)) == hash,
+            None => false,
+        }
+    }
+
+    /// Allow each ticket to be checked in up to `max_uses` times, once per event day
+    ///
+    /// Intended for multi-day passes; can only be changed before any ticket is sold.
+    pub fn set_max_uses(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        max_uses: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_positive_capacity(max_uses)?;
+        
+        let mut event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::TicketsAlreadySold);
+        }
+        
+        event.max_uses = max_uses;
+        storage::set_event(&env, event_id, &event);
+        
+        Ok(())
+    }
+
+    /// Set the asset an event's tickets are priced and paid in
+    ///
+    /// The declared decimals are checked against the token contract so prices
+    /// can never be interpreted in the wrong unit. Must be set before any
+    /// ticket is sold.
+    pub fn set_event_payment_asset(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        asset: PaymentAsset,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::TicketsAlreadySold);
+        }
+        
+        check_payment_asset(&env, &asset)?;
+        
+        storage::set_payment_asset(&env, event_id, &asset);
+        set_asset_display(&env, event_id, &asset);
+        
+        Ok(())
+    }
+
+    /// Override how an event's prices are displayed
+    ///
+    /// Setting a payment asset fills this in from the token's symbol and
+    /// decimals; organizers can replace the symbol (e.g. `$` for a dollar
+    /// stablecoin) or describe the unit of an event without an asset. With
+    /// an asset configured the decimals must stay the asset's.
+    pub fn set_currency_display(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        display: CurrencyDisplay,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        validation::validate_currency_symbol(&display.symbol)?;
+        if let Some(asset) = storage::get_payment_asset(&env, event_id) {
+            if asset.decimals != display.decimals {
+                return Err(LumentixError::WrongPaymentAsset);
+            }
+        }
+        
+        storage::set_currency_display(&env, event_id, &display);
+        
+        Ok(())
+    }
+
+    /// Get an event's base ticket price with the token and display metadata
+    /// frontends need to render it
+    pub fn get_event_pricing(env: Env, event_id: u64) -> Result<EventPricing, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        Ok(EventPricing {
+            ticket_price: event.ticket_price,
+            token: storage::get_payment_asset(&env, event_id).map(|asset| asset.token),
+            currency: storage::get_currency_display(&env, event_id).into(),
+        })
+    }
+
+    /// Enable or disable the waitlist that opens when an event sells out
+    ///
+    /// Enabling it on an event that is already sold out opens it at once.
+    pub fn set_waitlist_enabled(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        enabled: bool,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        storage::set_waitlist_enabled(&env, event_id, enabled);
+        if event.tickets_sold == event.max_tickets {
+            open_waitlist(&env, event_id);
+        }
+        
+        Ok(())
+    }
+
+    /// Join a sold-out event's waitlist, returning the 1-based position
+    pub fn join_waitlist(
+        env: Env,
+        account: Address,
+        event_id: u64,
+    ) -> Result<u32, LumentixError> {
+        account.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        let mut waitlist = storage::get_waitlist(&env, event_id)
+            .ok_or(LumentixError::WaitlistClosed)?;
+        
+        if waitlist.contains(&account) {
+            return Err(LumentixError::AlreadyOnWaitlist);
+        }
+        
+        if waitlist.len() >= event.max_tickets {
+            return Err(LumentixError::WaitlistFull);
+        }
+        
+        waitlist.push_back(account);
+        storage::set_waitlist(&env, event_id, &waitlist);
+        
+        Ok(waitlist.len())
+    }
+
+    /// Get an event's waitlist in registration order (empty until it opens)
+    pub fn get_waitlist(env: Env, event_id: u64) -> Vec<Address> {
+        storage::get_waitlist(&env, event_id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Whether an event's waitlist is open for registration
+    pub fn is_waitlist_open(env: Env, event_id: u64) -> bool {
+        storage::get_waitlist(&env, event_id).is_some()
+    }
+
+    /// Get the asset an event is paid in, if one is configured
+    pub fn get_event_payment_asset(env: Env, event_id: u64) -> Option<PaymentAsset> {
+        storage::get_payment_asset(&env, event_id)
+    }
+
+    /// Register a new version of an event's terms/refund policy document
+    ///
+    /// Earlier versions are kept; tickets already sold stay bound to the
+    /// version they were bought under. Returns the new version number.
+    pub fn set_event_policy(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        policy_hash: BytesN<32>,
+    ) -> Result<u32, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        Ok(storage::add_policy_version(&env, event_id, &policy_hash))
+    }
+
+    /// Get an event's policy version history, oldest first
+    pub fn get_event_policies(env: Env, event_id: u64) -> Result<Vec<PolicyVersion>, LumentixError> {
+        if !storage::has_event(&env, event_id) {
+            return Err(LumentixError::EventNotFound);
+        }
+        
+        Ok(storage::get_policy_versions(&env, event_id))
+    }
+
+    /// Get the policy version a ticket was bought under, if the event had one
+    pub fn get_ticket_policy_version(env: Env, ticket_id: u64) -> Option<u32> {
+        storage::get_ticket_policy_version(&env, ticket_id)
+    }
+
+    /// Allow up to `oversell_bps` of an event's capacity to be sold on
+    /// standby once every seat is sold; 0 turns overselling off
+    pub fn set_oversell(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        oversell_bps: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if oversell_bps > 10_000 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        storage::set_oversell_bps(&env, event_id, oversell_bps);
+        
+        Ok(())
+    }
+
+    /// Get the share of an event's capacity sold on standby, in basis points
+    pub fn get_oversell(env: Env, event_id: u64) -> u32 {
+        storage::get_oversell_bps(&env, event_id).unwrap_or(0)
+    }
+
+    /// Get the tickets sold on standby for an event and not yet resolved
+    pub fn get_standby_tickets(env: Env, event_id: u64) -> Vec<u64> {
+        storage::get_standby(&env, event_id)
+    }
+
+    /// Resolve an event's standby pool a day before it starts, returning the
+    /// number of standby tickets confirmed
+    ///
+    /// Each seat freed by a refund confirms one standby ticket, in purchase
+    /// order; the rest are refunded with `RefundReason::Oversold`. Anyone can
+    /// call this once the resolution time is reached.
+    pub fn resolve_standby(env: Env, event_id: u64) -> Result<u32, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.status != EventStatus::Active
+            || env.ledger().timestamp().saturating_add(STANDBY_RESOLUTION_SECS) < event.start_time
+        {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let pool = storage::get_standby(&env, event_id);
+        storage::remove_standby(&env, event_id);
+        
+        let mut pending = Vec::new(&env);
+        for ticket_id in pool.iter() {
+            let ticket = storage::get_ticket(&env, ticket_id)?;
+            if ticket.status == TicketStatus::Standby {
+                pending.push_back(ticket);
+            }
+        }
+        
+        // Seats not held by a live seated or imported ticket are free
+        let (seated, _) = storage::get_refundable_principal(&env, event_id);
+        let imported = storage::get_import_allowance(&env, event_id).map_or(0, |allowance| allowance.imported);
+        let seats = event.max_tickets.saturating_sub(seated + imported);
+        
+        let mut confirmed = 0;
+        for mut ticket in pending.iter() {
+            if confirmed < seats {
+                ticket.status = issued_status(&env, event_id);
+                storage::set_ticket(&env, ticket.id, &ticket);
+                storage::add_refundable_ticket(&env, event_id, ticket_principal(&env, &ticket, &event));
+                confirmed += 1;
+            } else {
+                execute_refund(&env, ticket, &event, RefundReason::Oversold)?;
+            }
+        }
+        
+        Ok(confirmed)
+    }
+
+    /// Register interest in a draft or active event, returning its follower
+    /// count
+    ///
+    /// Following an event twice has no further effect. Followers are
+    /// notified off-chain when a draft is published.
+    pub fn follow_event(env: Env, user: Address, event_id: u64) -> Result<u32, LumentixError> {
+        user.require_auth();
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let mut followers = storage::get_followers(&env, event_id);
+        if let Err(index) = followers.binary_search(&user) {
+            if followers.len() >= validation::MAX_FOLLOWERS_PER_EVENT {
+                return Err(LumentixError::CapacityExceeded);
+            }
+            followers.insert(index, user);
+            storage::set_followers(&env, event_id, &followers);
+        }
+        
+        Ok(followers.len())
+    }
+
+    /// Withdraw interest in an event
+    pub fn unfollow_event(env: Env, user: Address, event_id: u64) -> Result<(), LumentixError> {
+        user.require_auth();
+        
+        let mut followers = storage::get_followers(&env, event_id);
+        if let Ok(index) = followers.binary_search(&user) {
+            followers.remove(index);
+            storage::set_followers(&env, event_id, &followers);
+        }
+        
+        Ok(())
+    }
+
+    /// Get the number of accounts following an event
+    pub fn get_follower_count(env: Env, event_id: u64) -> u32 {
+        storage::get_followers(&env, event_id).len()
+    }
+
+    /// List the accounts following an event, in address order
+    ///
+    /// Returns up to `limit` followers after the `after` cursor together with
+    /// the cursor of the next page, if any; `limit` may not exceed
+    /// `MAX_BATCH_SIZE`.
+    pub fn get_followers(
+        env: Env,
+        event_id: u64,
+        after: Option<Address>,
+        limit: u32,
+    ) -> Result<(Vec<Address>, Option<Address>), LumentixError> {
+        page(&storage::get_followers(&env, event_id), after, limit, |follower| follower.clone())
+    }
+
+    /// Post the hash of an off-chain announcement to an event's ticket
+    /// holders, returning its number
+    ///
+    /// Announcements can be posted in any event status, up to
+    /// `MAX_ANNOUNCEMENTS_PER_EVENT` per event.
+    pub fn post_announcement(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        content_hash: BytesN<32>,
+    ) -> Result<u32, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if storage::get_announcements(&env, event_id).len() >= validation::MAX_ANNOUNCEMENTS_PER_EVENT {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        let number = storage::add_announcement(&env, event_id, &content_hash);
+        events::announcement_posted(&env, event_id, number, content_hash);
+        
+        Ok(number)
+    }
+
+    /// List an event's announcements in number order, oldest first
+    ///
+    /// Returns up to `limit` announcements numbered after the `after` cursor
+    /// together with the cursor of the next page, if any; `limit` may not
+    /// exceed `MAX_BATCH_SIZE`.
+    pub fn get_announcements(
+        env: Env,
+        event_id: u64,
+        after: Option<u32>,
+        limit: u32,
+    ) -> Result<(Vec<Announcement>, Option<u32>), LumentixError> {
+        page(&storage::get_announcements(&env, event_id), after, limit, |announcement| announcement.number)
+    }
+
+    /// Replace an event's discovery tags
+    ///
+    /// Repeated tags are stored once; at most `MAX_EVENT_TAGS` distinct tags
+    /// are accepted. An empty list removes all tags.
+    pub fn set_event_tags(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        tags: Vec<Symbol>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        let mut distinct = Vec::new(&env);
+        for tag in tags.iter() {
+            if !distinct.contains(&tag) {
+                distinct.push_back(tag);
+            }
+        }
+        if distinct.len() > validation::MAX_EVENT_TAGS {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        storage::set_event_tags(&env, event_id, &distinct);
+        
+        Ok(())
+    }
+
+    /// Get an event's discovery tags
+    pub fn get_event_tags(env: Env, event_id: u64) -> Vec<Symbol> {
+        storage::get_event_tags(&env, event_id)
+    }
+
+    /// List the IDs of events carrying a tag, in ID order
+    ///
+    /// Returns up to `limit` IDs after the `after` cursor together with the
+    /// cursor of the next page, if any; `limit` may not exceed
+    /// `MAX_BATCH_SIZE`.
+    pub fn list_events_by_tag(
+        env: Env,
+        tag: Symbol,
+        after: Option<u64>,
+        limit: u32,
+    ) -> Result<(Vec<u64>, Option<u64>), LumentixError> {
+        page(&storage::get_tagged_events(&env, &tag), after, limit, |id| *id)
+    }
+
+    /// Cancel an event
+    ///
+    /// `reason` and the optional `detail_hash` of an off-chain notice are
+    /// recorded for refund disputes and insurance claims.
+    pub fn cancel_event(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        reason: CancellationReason,
+        detail_hash: Option<BytesN<32>>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&organizer)?;
+        
+        let mut event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        event.status = EventStatus::Cancelled;
+        storage::set_event(&env, event_id, &event);
+        events::status_changed(&env, event_id, event.status.clone());
+        storage::decrement_organizer_active_events(&env, &organizer);
+        storage::set_cancellation(&env, event_id, &EventCancellation {
+            reason,
+            cancelled_at: env.ledger().timestamp(),
+        });
+        if let Some(hash) = detail_hash {
+            storage::set_cancellation_detail(&env, event_id, &hash);
+        }
+        events::event_cancelled(&env, event_id, reason);
+        
+        // Rent for the time the event no longer needs is returned
+        consume_rent(&env, event_id)?;
+        if let Some(mut rent) = storage::get_rent_deposit(&env, event_id) {
+            let unused = rent.amount - rent.consumed - rent.refunded;
+            rent.refunded += unused;
+            storage::set_rent_deposit(&env, event_id, &rent);
+            storage::release_rent(&env, event_id, unused);
+            storage::deduct_platform_balance(&env, &storage::event_token(&env, event_id), unused)?;
+            storage::record_withdrawal(&env, unused);
+            payments::pay_out(&env, event_id, &organizer, unused);
+        }
+        
+        Ok(())
+    }
+
+    /// Get the cancellation notice of an event, if it was cancelled
+    pub fn get_event_cancellation(env: Env, event_id: u64) -> Option<EventCancellation> {
+        storage::get_cancellation(&env, event_id)
+    }
+
+    /// Get the hash of the detailed cancellation notice, if the organizer
+    /// provided one
+    pub fn get_cancellation_detail(env: Env, event_id: u64) -> Option<BytesN<32>> {
+        storage::get_cancellation_detail(&env, event_id)
+    }
+
+    /// Request refund for a ticket (only if event is cancelled)
+    pub fn refund_ticket(
+        env: Env,
+        ticket_id: u64,
+        buyer: Address,
+    ) -> Result<(), LumentixError> {
+        buyer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&buyer)?;
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != buyer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if ticket.uses > 0 {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
+        
+        check_refundable(&ticket)?;
+        check_not_lent(&env, ticket_id)?;
+        
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        if event.status != EventStatus::Cancelled {
+            return Err(LumentixError::EventNotCancelled);
+        }
+        
+        execute_refund(&env, ticket, &event, RefundReason::EventCancelled)
+    }
+
+    /// Get until when a ticket can be refunded with `refund_ticket`
+    ///
+    /// Refunds open when the event is cancelled and stay open until the
+    /// ticket is refunded; used tickets and tickets already paid a pro-rata
+    /// refund are not refundable.
+    pub fn get_refund_deadline(env: Env, ticket_id: u64) -> Result<RefundDeadline, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        if ticket.uses > 0
+            || check_refundable(&ticket).is_err()
+            || storage::is_imported(&env, ticket_id)
+            || event.status != EventStatus::Cancelled
+            || storage::get_prorata_refund(&env, ticket_id).is_some()
+        {
+            return Ok(RefundDeadline::NotRefundable);
+        }
+        
+        Ok(RefundDeadline::NoDeadline)
+    }
+
+    /// Get the token and amount a ticket was paid with, which a full refund
+    /// returns
+    pub fn get_ticket_payment(env: Env, ticket_id: u64) -> Option<TicketPayment> {
+        storage::get_ticket_payment(&env, ticket_id)
+    }
+
+    /// List an account's ticket purchases, refunds and transfers in entry ID
+    /// order, oldest first
+    ///
+    /// Returns up to `limit` entries after the `after` cursor together with
+    /// the cursor of the next page, if any; `limit` may not exceed
+    /// `MAX_BATCH_SIZE`. Only the latest 200 entries are kept.
+    pub fn get_buyer_history(
+        env: Env,
+        owner: Address,
+        after: Option<u64>,
+        limit: u32,
+    ) -> Result<(Vec<HistoryEntry>, Option<u64>), LumentixError> {
+        page(&storage::get_history(&env, &owner), after, limit, |entry| entry.id)
+    }
+
+    /// Get how a refunded ticket's refund was funded, if it was refunded
+    pub fn get_refund_receipt(env: Env, ticket_id: u64) -> Option<RefundReceipt> {
+        storage::get_refund_receipt(&env, ticket_id)
+    }
+
+    /// Ask the organizer for a refund outside the normal refund rules
+    ///
+    /// Creates a pending request that the organizer can approve or deny. Only
+    /// one request can be made per ticket, and it is kept after the decision.
+    pub fn request_late_refund(
+        env: Env,
+        owner: Address,
+        ticket_id: u64,
+        reason: RefundReason,
+    ) -> Result<(), LumentixError> {
+        owner.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != owner {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if ticket.uses > 0 {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
+        
+        check_refundable(&ticket)?;
+        
+        if storage::get_late_refund(&env, ticket_id).is_some() {
+            return Err(LumentixError::RefundAlreadyRequested);
+        }
+        
+        let request = LateRefundRequest {
+            ticket_id,
+            event_id: ticket.event_id,
+            owner,
+            reason,
+            status: LateRefundStatus::Pending,
+            requested_at: env.ledger().timestamp(),
+            decided_at: 0,
+        };
+        storage::set_late_refund(&env, ticket_id, &request);
+        check_refund_spike(&env, ticket.event_id);
+        
+        Ok(())
+    }
+
+    /// Approve a pending late refund request, refunding the ticket from escrow
+    pub fn approve_late_refund(
+        env: Env,
+        organizer: Address,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let (mut request, event) = pending_late_refund(&env, &organizer, ticket_id)?;
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        // The ticket may have been scanned since the request was made
+        if ticket.uses > 0 {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
+        
+        check_refundable(&ticket)?;
+        check_not_lent(&env, ticket_id)?;
+        
+        request.status = LateRefundStatus::Approved;
+        request.decided_at = env.ledger().timestamp();
+        storage::set_late_refund(&env, ticket_id, &request);
+        
+        execute_refund(&env, ticket, &event, request.reason)
+    }
+
+    /// Refund a ticket from its event's escrow on the organizer's behalf
+    /// (admin only)
+    ///
+    /// For support escalations the organizer cannot act on, e.g. a lost
+    /// organizer key. Works whatever the event's status, as long as its
+    /// escrow still covers the ticket. A pending late refund request for the
+    /// ticket is marked approved, and the intervention is logged with an
+    /// `adm_rfnd` event.
+    pub fn admin_refund_ticket(env: Env, admin: Address, ticket_id: u64) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.uses > 0 {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
+        
+        check_refundable(&ticket)?;
+        check_not_lent(&env, ticket_id)?;
+        
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        if let Some(mut request) = storage::get_late_refund(&env, ticket_id) {
+            if request.status == LateRefundStatus::Pending {
+                request.status = LateRefundStatus::Approved;
+                request.decided_at = env.ledger().timestamp();
+                storage::set_late_refund(&env, ticket_id, &request);
+            }
+        }
+        
+        events::admin_refunded(&env, event.id, ticket_id, admin);
+        execute_refund(&env, ticket, &event, RefundReason::Other)
+    }
+
+    /// Deny a pending late refund request
+    pub fn deny_late_refund(
+        env: Env,
+        organizer: Address,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let (mut request, _) = pending_late_refund(&env, &organizer, ticket_id)?;
+        
+        request.status = LateRefundStatus::Denied;
+        request.decided_at = env.ledger().timestamp();
+        storage::set_late_refund(&env, ticket_id, &request);
+        
+        Ok(())
+    }
+
+    /// Get the late refund request for a ticket, if one was made
+    pub fn get_late_refund(env: Env, ticket_id: u64) -> Option<LateRefundRequest> {
+        storage::get_late_refund(&env, ticket_id)
+    }
+
+    /// Get the number of refunds per reason for an event
+    pub fn get_refund_breakdown(env: Env, event_id: u64) -> Result<Map<RefundReason, u32>, LumentixError> {
+        if !storage::has_event(&env, event_id) {
+            return Err(LumentixError::EventNotFound);
+        }
+        
+        Ok(storage::get_refund_breakdown(&env, event_id))
+    }
+
+    /// Lift an event's payout freeze after reviewing its refund spike
+    /// (admin only)
+    pub fn unfreeze_payouts(env: Env, admin: Address, event_id: u64) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        if storage::get_payout_freeze(&env, event_id).is_none() {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        storage::remove_payout_freeze(&env, event_id);
+        storage::remove_refund_window(&env, event_id);
+        
+        Ok(())
+    }
+
+    /// Get when an event's payouts were frozen by a refund spike, if they are
+    pub fn get_payout_freeze(env: Env, event_id: u64) -> Option<u64> {
+        storage::get_payout_freeze(&env, event_id)
+    }
+
+    /// Attest that an event took place, so its escrow can be released
+    /// without meeting the platform's check-in quorum (admin only)
+    pub fn attest_event(env: Env, admin: Address, event_id: u64) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        storage::get_event(&env, event_id)?;
+        storage::set_attestation(&env, event_id, env.ledger().timestamp());
+        
+        Ok(())
+    }
+
+    /// Get when the admin attested that an event took place, if they did
+    pub fn get_event_attestation(env: Env, event_id: u64) -> Option<u64> {
+        storage::get_attestation(&env, event_id)
+    }
+
+    /// Extend the storage TTL of an event's core records, consuming the
+    /// share of its prepaid rent accrued so far, which is returned
+    ///
+    /// Anyone can call this.
+    pub fn extend_event_storage(env: Env, event_id: u64) -> Result<i128, LumentixError> {
+        if !storage::has_event(&env, event_id) {
+            return Err(LumentixError::EventNotFound);
+        }
+        
+        let consumed = consume_rent(&env, event_id)?;
+        storage::extend_event_ttl(&env, event_id);
+        
+        Ok(consumed)
+    }
+
+    /// Get the storage rent prepaid for an event, if any
+    pub fn get_rent_deposit(env: Env, event_id: u64) -> Option<RentDeposit> {
+        storage::get_rent_deposit(&env, event_id)
+    }
+
+    /// Whitelist or delist a yield vault for escrow deposits (admin only)
+    pub fn set_yield_vault(
+        env: Env,
+        admin: Address,
+        vault: Address,
+        allowed: bool,
+    ) -> Result<(), LumentixError> {
+        require_admin(&env, &admin)?;
+        
+        storage::set_yield_vault(&env, &vault, allowed);
+        
+        Ok(())
+    }
+
+    /// Check whether a yield vault is whitelisted for escrow deposits
+    pub fn is_yield_vault(env: Env, vault: Address) -> bool {
+        storage::is_yield_vault(&env, &vault)
+    }
+
+    /// Park part of an active event's escrow in a whitelisted yield vault
+    ///
+    /// The escrow balance keeps counting the principal. The position is
+    /// redeemed before any payout from the event's escrow, or earlier with
+    /// `redeem_escrow_yield`.
+    pub fn deposit_escrow_yield(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        vault: Address,
+        amount: i128,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if !storage::is_yield_vault(&env, &vault) {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status != EventStatus::Active
+            || storage::get_yield_position(&env, event_id).is_some()
+        {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        if storage::get_payment_asset(&env, event_id).is_none() {
+            return Err(LumentixError::InvalidConfig);
+        }
+        if amount <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        if amount > storage::get_escrow(&env, event_id)? {
+            return Err(LumentixError::InsufficientEscrow);
+        }
+        
+        storage::set_yield_position(&env, event_id, &YieldPosition {
+            vault: vault.clone(),
+            principal: amount,
+            deposited_at: env.ledger().timestamp(),
+        });
+        payments::deposit_to_vault(&env, event_id, &vault, amount);
+        
+        Ok(())
+    }
+
+    /// Redeem an event's escrow from its yield vault, returning the yield
+    /// earned
+    pub fn redeem_escrow_yield(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<i128, LumentixError> {
+        organizer.require_auth();
+        
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if storage::get_yield_position(&env, event_id).is_none() {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        settle_yield(&env, event_id)
+    }
+
+    /// Get an event's escrow parked in a yield vault, if any
+    pub fn get_yield_position(env: Env, event_id: u64) -> Option<YieldPosition> {
+        storage::get_yield_position(&env, event_id)
+    }
+
+    /// Release escrow funds to organizer (after event completion)
+    ///
+    /// When the platform sets `min_completion_check_ins`, the event must
+    /// have had that many check-ins (capped at its tickets sold) or an admin
+    /// attestation. Events whose payouts were frozen by a refund spike
+    /// cannot release escrow until the admin lifts the freeze, nor can
+    /// events with an unresolved standby pool.
+    pub fn release_escrow(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<i128, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&organizer)?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Completed
+            || storage::get_payout_freeze(&env, event_id).is_some()
+            || !storage::get_standby(&env, event_id).is_empty()
+        {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        // A completed event proves it took place by check-ins or attestation
+        let quorum = storage::get_config(&env).min_completion_check_ins.min(event.tickets_sold);
+        if storage::get_stats(&env, event_id).tickets_used < quorum
+            && storage::get_attestation(&env, event_id).is_none()
+        {
+            return Err(LumentixError::TicketNotCheckedIn);
+        }
+        
+        // Escrow parked in a yield vault comes back with its share of yield
+        settle_yield(&env, event_id)?;
+        
+        // Pro-rata refunds and deposits still owed to holders stay in escrow,
+        // as does tax withheld for the event's tax recipient
+        let reserved = storage::get_curtailment(&env, event_id)
+            .map(|curtailment| curtailment.outstanding)
+            .unwrap_or(0)
+            + storage::get_deposits_owed(&env, event_id)
+            + storage::get_tax_collected(&env, event_id);
+        let escrow_amount = storage::get_escrow(&env, event_id)? - reserved;
+        
+        if escrow_amount <= 0 {
+            return Err(LumentixError::EscrowAlreadyReleased);
+        }
+        
+        if reserved == 0 {
+            storage::clear_escrow(&env, event_id);
+        } else {
+            storage::deduct_escrow(&env, event_id, escrow_amount)?;
+        }
+        storage::clear_tier_escrow(&env, event_id);
+        
+        storage::add_organizer_volume(&env, &organizer, escrow_amount);
+        storage::record_withdrawal(&env, escrow_amount);
+        consume_rent(&env, event_id)?;
+        payments::pay_out(&env, event_id, &organizer, escrow_amount);
+        
+        Ok(escrow_amount)
+    }
+
+    /// Get the ticket proceeds an event's escrow holds for one tier
+    ///
+    /// Each sale credits its tier with the price net of the platform fee;
+    /// refunds, pro-rata refunds and deposit returns debit the ticket's tier.
+    /// Sponsorships and vault yield are held for the event as a whole, and
+    /// every tier reads zero once the organizer has been paid.
+    pub fn get_tier_escrow(env: Env, event_id: u64, tier: u32) -> i128 {
+        storage::get_tier_escrow(&env, event_id).get(tier).unwrap_or(0)
+    }
+
+    /// Get the ticket proceeds an event's escrow holds, keyed by tier
+    pub fn get_tier_escrows(env: Env, event_id: u64) -> Map<u32, i128> {
+        storage::get_tier_escrow(&env, event_id)
+    }
+
+    /// Complete an event (after end time)
+    pub fn complete_event(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&organizer)?;
+        
+        complete(&env, &organizer, event_id)
+    }
+
+    /// Complete an event early because only part of it was delivered
+    ///
+    /// Each ticket holder is owed `refund_bps` of the ticket price, paid out
+    /// by the organizer with `issue_prorata_refunds`. The owed amount stays
+    /// reserved in escrow until it has been refunded.
+    pub fn curtail_event(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        refund_bps: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if refund_bps == 0 || refund_bps > 10_000 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let mut event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        // Only seated tickets holding escrow are owed a refund; standby and
+        // imported tickets are not counted
+        let (tickets_owed, principal) = storage::get_refundable_principal(&env, event_id);
+        
+        // Each refund is rounded down from the ticket's own principal, so
+        // this covers them all; any remainder is freed with the last one
+        storage::set_curtailment(&env, event_id, &Curtailment {
+            refund_bps,
+            tickets_owed,
+            outstanding: money::bps_of(principal, refund_bps)?,
+            curtailed_at: env.ledger().timestamp(),
+        });
+        
+        event.status = EventStatus::Completed;
+        storage::set_event(&env, event_id, &event);
+        events::status_changed(&env, event_id, event.status.clone());
+        storage::decrement_organizer_active_events(&env, &organizer);
+        storage::release_event_fees(&env, event_id);
+        
+        Ok(())
+    }
+
+    /// Pay the pro-rata refund of a curtailed event to a batch of tickets
+    ///
+    /// Tickets from other events, refunded tickets and tickets already paid
+    /// are skipped, so a batch can safely be resubmitted. Returns the total
+    /// amount refunded.
+    pub fn issue_prorata_refunds(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        ticket_ids: Vec<u64>,
+    ) -> Result<i128, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(ticket_ids.len())?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        let mut curtailment = storage::get_curtailment(&env, event_id)
+            .ok_or(LumentixError::EventNotCurtailed)?;
+        
+        let mut total = 0;
+        let mut payees = Vec::new(&env);
+        for ticket_id in ticket_ids.iter() {
+            let ticket = match storage::get_ticket(&env, ticket_id) {
+                Ok(ticket) if ticket.event_id == event_id
+                    && ticket.status != TicketStatus::Refunded
+                    && ticket.status != TicketStatus::Standby => ticket,
+                _ => continue,
+            };
+            if storage::get_prorata_refund(&env, ticket_id).is_some() || storage::is_imported(&env, ticket_id) {
+                continue;
+            }
+            
+            let refund = prorata_refund_for(&env, &ticket, &event, curtailment.refund_bps)?;
+            storage::set_prorata_refund(&env, ticket_id, refund);
+            storage::deduct_tier_escrow(&env, event_id, ticket.tier, refund);
+            record_history(&env, &ticket.owner, HistoryAction::Refunded, &ticket, refund);
+            payees.push_back((ticket.owner, refund));
+            total += refund;
+            curtailment.tickets_owed -= 1;
+        }
+        
+        settle_yield(&env, event_id)?;
+        storage::deduct_escrow(&env, event_id, total)?;
+        storage::record_refund_paid(&env, total);
+        storage::record_partial_refund(&env, event_id, total);
+        curtailment.outstanding -= total;
+        if curtailment.tickets_owed == 0 {
+            curtailment.outstanding = 0;
+        }
+        storage::set_curtailment(&env, event_id, &curtailment);
+        payments::pay_out_all(&env, event_id, &payees);
+        
+        Ok(total)
+    }
+
+    /// Get the curtailment terms of an event, if it was completed early
+    pub fn get_curtailment(env: Env, event_id: u64) -> Option<Curtailment> {
+        storage::get_curtailment(&env, event_id)
+    }
+
+    /// Get the pro-rata refund paid for a ticket, if any
+    pub fn get_prorata_refund(env: Env, ticket_id: u64) -> Option<i128> {
+        storage::get_prorata_refund(&env, ticket_id)
+    }
+
+    /// Sell an event's tickets as deposits: `refundable_bps` of each ticket's
+    /// principal is returned to holders who check in, the rest is kept as a
+    /// no-show penalty. Must be set before any ticket is sold.
+    pub fn set_deposit_terms(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        refundable_bps: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if refundable_bps == 0 || refundable_bps > 10_000 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::TicketsAlreadySold);
+        }
+        
+        storage::set_deposit_bps(&env, event_id, refundable_bps);
+        
+        Ok(())
+    }
+
+    /// Get the refundable share of an event's deposit tickets, in basis points
+    pub fn get_deposit_terms(env: Env, event_id: u64) -> Option<u32> {
+        storage::get_deposit_bps(&env, event_id)
+    }
+
+    /// Configure how resale proceeds for an event's tickets are split
+    /// between seller, organizer royalty and platform
+    pub fn set_resale_split(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        split: ResaleSplit,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        validation::validate_resale_split(&storage::get_config(&env), &split)?;
+        storage::set_resale_split(&env, event_id, &split);
+        
+        Ok(())
+    }
+
+    /// Get the split applied to resales of an event's tickets
+    pub fn get_resale_split(env: Env, event_id: u64) -> Result<ResaleSplit, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        storage::get_event(&env, event_id)?;
+        
+        Ok(resale_split(&env, event_id))
+    }
+
+    /// Bid `amount` for a specific ticket, or any ticket of an event or tier
+    ///
+    /// The amount is held by the contract, in the event's payment asset,
+    /// until a ticket holder accepts the offer or the buyer cancels it.
+    pub fn place_offer(
+        env: Env,
+        buyer: Address,
+        target: OfferTarget,
+        amount: i128,
+    ) -> Result<u64, LumentixError> {
+        buyer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_positive_amount(amount)?;
+        
+        let event_id = match target {
+            OfferTarget::Ticket(ticket_id) => {
+                let ticket = storage::get_ticket(&env, ticket_id)?;
+                if ticket.owner == buyer {
+                    return Err(LumentixError::Unauthorized);
+                }
+                check_resellable(&env, &ticket)?;
+                ticket.event_id
+            }
+            OfferTarget::Event(event_id) | OfferTarget::Tier(event_id, _) => event_id,
+        };
+        
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Active {
+            return Err(LumentixError::EventNotOnSale);
+        }
+        check_transferable(&env, event_id);
+        
+        let offer_id = storage::get_next_offer_id(&env);
+        storage::set_offer(&env, &Offer {
+            id: offer_id,
+            buyer: buyer.clone(),
+            target,
+            event_id,
+            amount,
+            created_at: env.ledger().timestamp(),
+        });
+        storage::increment_offer_id(&env);
+        
+        storage::hold_offer_funds(&env, amount);
+        payments::collect(&env, event_id, &buyer, amount);
+        
+        Ok(offer_id)
+    }
+
+    /// Sell `ticket_id` to an offer's buyer, splitting the offer amount
+    /// between seller, organizer and platform per the event's resale split
+    pub fn accept_offer(
+        env: Env,
+        seller: Address,
+        offer_id: u64,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        seller.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let offer = storage::get_offer(&env, offer_id).ok_or(LumentixError::TicketNotFound)?;
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != seller || seller == offer.buyer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        let matches = match offer.target {
+            OfferTarget::Ticket(id) => id == ticket_id,
+            OfferTarget::Event(event_id) => event_id == ticket.event_id,
+            OfferTarget::Tier(event_id, tier) => event_id == ticket.event_id && tier == ticket.tier,
+        };
+        if !matches {
+            return Err(LumentixError::TicketNotFound);
+        }
+        check_resellable(&env, &ticket)?;
+        check_not_lent(&env, ticket_id)?;
+        
+        let event = storage::get_event(&env, offer.event_id)?;
+        if event.status != EventStatus::Active {
+            return Err(LumentixError::EventNotOnSale);
+        }
+        check_transferable(&env, offer.event_id);
+        
+        let split = resale_split(&env, offer.event_id);
+        let rounding = storage::get_config(&env).rounding;
+        let platform_share = money::platform_share(offer.amount, split.platform_bps, rounding)?;
+        let royalty = money::organizer_share(offer.amount, split.organizer_bps, rounding)?;
+        let proceeds = offer.amount - platform_share - royalty;
+        
+        ticket.owner = offer.buyer.clone();
+        storage::set_ticket(&env, ticket_id, &ticket);
+        storage::remove_offer(&env, offer_id);
+        record_history(&env, &seller, HistoryAction::TransferredOut, &ticket, offer.amount);
+        record_history(&env, &offer.buyer, HistoryAction::TransferredIn, &ticket, offer.amount);
+        storage::remove_holding(&env, &seller, ticket.event_id);
+        storage::add_holding(&env, &offer.buyer, ticket.event_id);
+        events::ticket_transferred(&env, ticket.event_id, ticket_id);
+        
+        // The offer settles: the platform's share stays in the contract as
+        // platform balance and the rest is paid out
+        storage::release_offer_funds(&env, offer.amount);
+        storage::add_platform_balance(&env, &storage::event_token(&env, offer.event_id), platform_share);
+        storage::record_withdrawal(&env, proceeds + royalty);
+        
+        payments::pay_out(&env, offer.event_id, &seller, proceeds);
+        payments::pay_out(&env, offer.event_id, &event.organizer, royalty);
+        
+        Ok(())
+    }
+
+    /// Withdraw an open offer and return its held amount to the buyer
+    pub fn cancel_offer(env: Env, buyer: Address, offer_id: u64) -> Result<(), LumentixError> {
+        buyer.require_auth();
+        
+        let offer = storage::get_offer(&env, offer_id).ok_or(LumentixError::TicketNotFound)?;
+        if offer.buyer != buyer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        storage::remove_offer(&env, offer_id);
+        storage::release_offer_funds(&env, offer.amount);
+        storage::record_refund_paid(&env, offer.amount);
+        payments::pay_out(&env, offer.event_id, &buyer, offer.amount);
+        
+        Ok(())
+    }
+
+    /// Get an open resale offer
+    pub fn get_offer(env: Env, offer_id: u64) -> Option<Offer> {
+        storage::get_offer(&env, offer_id)
+    }
+
+    /// Propose exchanging `ticket_a` for another holder's `ticket_b`,
+    /// optionally adding `top_up` in cash
+    ///
+    /// The top-up is held by the contract, in `ticket_a`'s event payment
+    /// asset, until the holder of `ticket_b` accepts or the proposer cancels.
+    pub fn propose_swap(
+        env: Env,
+        proposer: Address,
+        ticket_a: u64,
+        ticket_b: u64,
+        top_up: i128,
+    ) -> Result<u64, LumentixError> {
+        proposer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if top_up < 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let (mut a, b) = swappable_tickets(&env, ticket_a, ticket_b)?;
+        if a.owner != proposer || b.owner == proposer {
+            return Err(LumentixError::Unauthorized);
+        }
+        check_resellable(&env, &a)?;
+        
+        // The proposer's ticket is locked until the swap settles
+        a.status = TicketStatus::PendingTransfer;
+        storage::set_ticket(&env, ticket_a, &a);
+        
+        let swap_id = storage::get_next_swap_id(&env);
+        storage::set_swap(&env, &Swap {
+            id: swap_id,
+            proposer: proposer.clone(),
+            ticket_a,
+            ticket_b,
+            top_up,
+            created_at: env.ledger().timestamp(),
+        });
+        storage::increment_swap_id(&env);
+        
+        if top_up > 0 {
+            payments::collect(&env, a.event_id, &proposer, top_up);
+        }
+        
+        Ok(swap_id)
+    }
+
+    /// Accept a swap as the holder of its `ticket_b`, exchanging the two
+    /// tickets atomically and receiving any top-up
+    pub fn accept_swap(env: Env, holder: Address, swap_id: u64) -> Result<(), LumentixError> {
+        holder.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let swap = storage::get_swap(&env, swap_id).ok_or(LumentixError::TicketNotFound)?;
+        let (mut a, mut b) = swappable_tickets(&env, swap.ticket_a, swap.ticket_b)?;
+        
+        // The requested ticket may have changed hands since the proposal
+        if b.owner != holder {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        a.status = TicketStatus::Valid;
+        a.owner = holder.clone();
+        b.owner = swap.proposer.clone();
+        storage::set_ticket(&env, swap.ticket_a, &a);
+        storage::set_ticket(&env, swap.ticket_b, &b);
+        storage::remove_swap(&env, swap_id);
+        
+        // The top-up travels with the proposer's ticket
+        record_history(&env, &swap.proposer, HistoryAction::TransferredOut, &a, swap.top_up);
+        record_history(&env, &holder, HistoryAction::TransferredIn, &a, swap.top_up);
+        record_history(&env, &holder, HistoryAction::TransferredOut, &b, 0);
+        record_history(&env, &swap.proposer, HistoryAction::TransferredIn, &b, 0);
+        events::ticket_transferred(&env, a.event_id, a.id);
+        events::ticket_transferred(&env, b.event_id, b.id);
+        storage::remove_holding(&env, &swap.proposer, a.event_id);
+        storage::add_holding(&env, &holder, a.event_id);
+        storage::remove_holding(&env, &holder, b.event_id);
+        storage::add_holding(&env, &swap.proposer, b.event_id);
+        
+        payments::pay_out(&env, a.event_id, &holder, swap.top_up);
+        
+        Ok(())
+    }
+
+    /// Withdraw a pending swap and return its top-up to the proposer
+    pub fn cancel_swap(env: Env, proposer: Address, swap_id: u64) -> Result<(), LumentixError> {
+        proposer.require_auth();
+        
+        let swap = storage::get_swap(&env, swap_id).ok_or(LumentixError::TicketNotFound)?;
+        if swap.proposer != proposer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        let mut a = storage::get_ticket(&env, swap.ticket_a)?;
+        if a.status == TicketStatus::PendingTransfer {
+            a.status = TicketStatus::Valid;
+            storage::set_ticket(&env, swap.ticket_a, &a);
+        }
+        storage::remove_swap(&env, swap_id);
+        payments::pay_out(&env, a.event_id, &proposer, swap.top_up);
+        
+        Ok(())
+    }
+
+    /// Get a pending ticket swap
+    pub fn get_swap(env: Env, swap_id: u64) -> Option<Swap> {
+        storage::get_swap(&env, swap_id)
+    }
+
+    /// Withhold `rate_bps` of each ticket's net price as tax, paid out to
+    /// `recipient` once the event is completed
+    ///
+    /// Must be set before the first ticket is sold.
+    pub fn set_tax_terms(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        rate_bps: u32,
+        recipient: Address,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_address(&recipient)?;
+        
+        if rate_bps == 0 || rate_bps > 10_000 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::TicketsAlreadySold);
+        }
+        
+        storage::set_tax_terms(&env, event_id, &TaxTerms { rate_bps, recipient });
+        
+        Ok(())
+    }
+
+    /// Get an event's tax withholding terms
+    pub fn get_tax_terms(env: Env, event_id: u64) -> Option<TaxTerms> {
+        storage::get_tax_terms(&env, event_id)
+    }
+
+    /// Get the tax withheld from an event's sales that has not been paid out
+    pub fn get_tax_collected(env: Env, event_id: u64) -> i128 {
+        storage::get_tax_collected(&env, event_id)
+    }
+
+    /// Pay the tax withheld from a completed event's sales to its tax
+    /// recipient, returning the amount paid
+    ///
+    /// Anyone may trigger the payout; the funds can only go to the recipient
+    /// the organizer configured.
+    pub fn withdraw_tax(env: Env, event_id: u64) -> Result<i128, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.status != EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let amount = storage::get_tax_collected(&env, event_id);
+        
+        if amount <= 0 {
+            return Err(LumentixError::EscrowAlreadyReleased);
+        }
+        
+        let terms = storage::get_tax_terms(&env, event_id).ok_or(LumentixError::EscrowAlreadyReleased)?;
+        
+        settle_yield(&env, event_id)?;
+        storage::deduct_escrow(&env, event_id, amount)?;
+        storage::add_tax_collected(&env, event_id, -amount);
+        storage::record_withdrawal(&env, amount);
+        payments::pay_out(&env, event_id, &terms.recipient, amount);
+        
+        Ok(amount)
+    }
+
+    /// Settle a batch of tickets of a completed deposit-model event,
+    /// returning the deposits of attendees who checked in
+    ///
+    /// Anyone may submit a batch, so settlement can run from a keeper without
+    /// holders claiming. Every settled ticket is added to its holder's
+    /// attendance record; no-shows are settled with a zero return. Refunded
+    /// and already settled tickets are skipped. Returns the total returned.
+    pub fn settle_deposits(
+        env: Env,
+        event_id: u64,
+        ticket_ids: Vec<u64>,
+    ) -> Result<i128, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(ticket_ids.len())?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.status != EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let refundable_bps = storage::get_deposit_bps(&env, event_id)
+            .ok_or(LumentixError::NotDepositEvent)?;
+        
+        let mut total = 0;
+        let mut payees = Vec::new(&env);
+        for ticket_id in ticket_ids.iter() {
+            let ticket = match storage::get_ticket(&env, ticket_id) {
+                Ok(ticket) if ticket.event_id == event_id && ticket.status != TicketStatus::Refunded => ticket,
+                _ => continue,
+            };
+            if storage::get_deposit_return(&env, ticket_id).is_some() || storage::is_imported(&env, ticket_id) {
+                continue;
+            }
+            
+            let attended = ticket.uses > 0;
+            storage::record_attendance(&env, &ticket.owner, attended);
+            if !attended {
+                storage::set_deposit_return(&env, ticket_id, 0);
+                continue;
+            }
+            
+            let deposit = deposit_for(&env, &ticket, &event, refundable_bps)?;
+            storage::set_deposit_return(&env, ticket_id, deposit);
+            storage::deduct_tier_escrow(&env, event_id, ticket.tier, deposit);
+            payees.push_back((ticket.owner, deposit));
+            total += deposit;
+        }
+        
+        settle_yield(&env, event_id)?;
+        storage::deduct_escrow(&env, event_id, total)?;
+        storage::record_refund_paid(&env, total);
+        storage::record_partial_refund(&env, event_id, total);
+        storage::add_deposits_owed(&env, event_id, -total);
+        payments::pay_out_all(&env, event_id, &payees);
+        
+        Ok(total)
+    }
+
+    /// Expire a batch of tickets never used at their event, returning how
+    /// many were expired
+    ///
+    /// A `Valid` ticket with no check-ins expires once its event's end time
+    /// plus `ticket_expiry_grace_secs` has passed, unless the event was
+    /// cancelled and its holders are owed refunds. Anyone may submit a
+    /// batch; tickets that do not qualify are skipped. Expired tickets can
+    /// no longer be admitted or change hands and count as no-shows in the
+    /// event's stats.
+    pub fn expire_tickets(env: Env, ticket_ids: Vec<u64>) -> Result<u32, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(ticket_ids.len())?;
+        
+        let grace = storage::get_config(&env).ticket_expiry_grace_secs;
+        let now = env.ledger().timestamp();
+        let mut expired = 0;
+        for ticket_id in ticket_ids.iter() {
+            let mut ticket = match storage::get_ticket(&env, ticket_id) {
+                Ok(ticket) if matches!(ticket.status, TicketStatus::Valid | TicketStatus::WillCall) && ticket.uses == 0 => {
+                    ticket
+                }
+                _ => continue,
+            };
+            let event = storage::get_event(&env, ticket.event_id)?;
+            if event.status == EventStatus::Cancelled || now < event.end_time.saturating_add(grace) {
+                continue;
+            }
+            
+            ticket.status = TicketStatus::Expired;
+            storage::set_ticket(&env, ticket_id, &ticket);
+            storage::record_expiry(&env, ticket.event_id);
+            expired += 1;
+        }
+        
+        Ok(expired)
+    }
+
+    /// Get the deposit returned for a settled ticket (0 for a no-show)
+    pub fn get_deposit_return(env: Env, ticket_id: u64) -> Option<i128> {
+        storage::get_deposit_return(&env, ticket_id)
+    }
+
+    /// Get a buyer's attendance history across deposit-model events
+    pub fn get_attendance_record(env: Env, buyer: Address) -> AttendanceRecord {
+        storage::get_attendance(&env, &buyer)
+    }
+
+    /// Reject purchases from buyers whose no-show rate exceeds `max_bps`
+    pub fn set_max_no_show_rate(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        max_bps: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if max_bps > 10_000 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        storage::set_max_no_show_bps(&env, event_id, max_bps);
+        
+        Ok(())
+    }
+
+    /// Get the maximum buyer no-show rate an event accepts, in basis points
+    pub fn get_max_no_show_rate(env: Env, event_id: u64) -> Option<u32> {
+        storage::get_max_no_show_bps(&env, event_id)
+    }
+
+    /// Rate an attended event from 1 to 5, once per checked-in ticket, after
+    /// the event ends. `comment_hash` commits to an optional off-chain comment.
+    pub fn submit_review(
+        env: Env,
+        ticket_id: u64,
+        reviewer: Address,
+        rating: u32,
+        comment_hash: Option<BytesN<32>>,
+    ) -> Result<(), LumentixError> {
+        reviewer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        if !(1..=5).contains(&rating) {
+            return Err(LumentixError::InvalidRating);
+        }
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        
+        if ticket.owner != reviewer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if storage::get_review(&env, ticket_id).is_some() {
+            return Err(LumentixError::AlreadyReviewed);
+        }
+        
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        // Only attendees can vouch for an event, and only once it is over
+        if ticket.uses == 0 || ticket.status == TicketStatus::Refunded || env.ledger().timestamp() < event.end_time {
+            return Err(LumentixError::ReviewNotAllowed);
+        }
+        
+        storage::set_review(&env, ticket_id, &Review {
+            ticket_id,
+            event_id: ticket.event_id,
+            rating,
+            submitted_at: env.ledger().timestamp(),
+        });
+        if let Some(hash) = &comment_hash {
+            storage::set_review_comment(&env, ticket_id, hash);
+        }
+        storage::record_rating(&env, ticket.event_id, &event.organizer, rating);
+        
+        Ok(())
+    }
+
+    /// Get the review submitted with a ticket, if any
+    pub fn get_review(env: Env, ticket_id: u64) -> Option<Review> {
+        storage::get_review(&env, ticket_id)
+    }
+
+    /// Get the hash of a review's off-chain comment, if one was submitted
+    pub fn get_review_comment(env: Env, ticket_id: u64) -> Option<BytesN<32>> {
+        storage::get_review_comment(&env, ticket_id)
+    }
+
+    /// Get the aggregate rating of an event
+    pub fn get_event_rating(env: Env, event_id: u64) -> RatingSummary {
+        storage::get_event_rating(&env, event_id)
+    }
+
+    /// Get the aggregate rating across all of an organizer's events
+    pub fn get_organizer_rating(env: Env, organizer: Address) -> RatingSummary {
+        storage::get_organizer_rating(&env, &organizer)
+    }
+
+    /// Offer a sponsorship slot on an event, returning the slot ID
+    pub fn create_sponsorship(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        price: i128,
+        benefits_hash: BytesN<32>,
+    ) -> Result<u64, LumentixError> {
+        organizer.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_positive_amount(price)?;
+        
+        let event = storage::get_event(&env, event_id)?;
+        
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if event.status != EventStatus::Draft && event.status != EventStatus::Active {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        
+        let mut slots = storage::get_event_sponsorships(&env, event_id);
+        if slots.len() >= validation::MAX_SPONSORSHIPS_PER_EVENT {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        
+        let sponsorship_id = storage::get_next_sponsorship_id(&env);
+        storage::increment_sponsorship_id(&env);
+        
+        storage::set_sponsorship(&env, &Sponsorship {
+            id: sponsorship_id,
+            event_id,
+            price,
+            benefits_hash,
+            sponsor: None,
+            paid: 0,
+            refunded: false,
+            created_at: env.ledger().timestamp(),
+        });
+        slots.push_back(sponsorship_id);
+        storage::set_event_sponsorships(&env, event_id, &slots);
+        
+        Ok(sponsorship_id)
+    }
+
+    /// Buy a sponsorship slot; the payment is held in the event's escrow
+    /// like ticket revenue
+    pub fn purchase_sponsorship(
+        env: Env,
+        sponsor: Address,
+        sponsorship_id: u64,
+        payment_amount: i128,
+    ) -> Result<(), LumentixError> {
+        sponsor.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut sponsorship = storage::get_sponsorship(&env, sponsorship_id)?;
+        
+        if sponsorship.sponsor.is_some() {
+            return Err(LumentixError::SponsorshipTaken);
+        }
+        
+        let event = storage::get_event(&env, sponsorship.event_id)?;
+        
+        if event.status != EventStatus::Active {
+            return Err(LumentixError::EventNotOnSale);
+        }
+        
+        if env.ledger().timestamp() >= event.end_time {
+            return Err(LumentixError::SalesClosed);
+        }
+        
+        if payment_amount < sponsorship.price {
+            return Err(LumentixError::InsufficientFunds);
+        }
+        
+        sponsorship.sponsor = Some(sponsor.clone());
+        sponsorship.paid = payment_amount;
+        storage::set_sponsorship(&env, &sponsorship);
+        
+        storage::record_deposit(&env, payment_amount);
+        storage::add_escrow(&env, event.id, payment_amount);
+        payments::collect(&env, event.id, &sponsor, payment_amount);
+        
+        Ok(())
+    }
+
+    /// Refund a sponsorship of a cancelled event to its sponsor
+    pub fn refund_sponsorship(
+        env: Env,
+        sponsorship_id: u64,
+        sponsor: Address,
+    ) -> Result<(), LumentixError> {
+        sponsor.require_auth();
+        
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let mut sponsorship = storage::get_sponsorship(&env, sponsorship_id)?;
+        
+        if sponsorship.sponsor.as_ref() != Some(&sponsor) {
+            return Err(LumentixError::Unauthorized);
+        }
+        
+        if sponsorship.refunded {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+        
+        let event = storage::get_event(&env, sponsorship.event_id)?;
+        
+        if event.status != EventStatus::Cancelled {
+            return Err(LumentixError::EventNotCancelled);
+        }
+        
+        sponsorship.refunded = true;
+        storage::set_sponsorship(&env, &sponsorship);
+        
+        settle_yield(&env, event.id)?;
+        storage::deduct_escrow(&env, event.id, sponsorship.paid)?;
+        storage::record_refund_paid(&env, sponsorship.paid);
+        payments::pay_out(&env, event.id, &sponsor, sponsorship.paid);
+        
+        Ok(())
+    }
+
+    /// Get a sponsorship slot
+    pub fn get_sponsorship(env: Env, sponsorship_id: u64) -> Result<Sponsorship, LumentixError> {
+        storage::get_sponsorship(&env, sponsorship_id)
+    }
+
+    /// Get the IDs of an event's sponsorship slots, oldest first
+    pub fn get_event_sponsorships(env: Env, event_id: u64) -> Vec<u64> {
+        storage::get_event_sponsorships(&env, event_id)
+    }
+
+    /// Get event details
     pub fn get_event(env: Env, event_id: u64) -> Result<Event, LumentixError> {
         if !storage::is_initialized(&env) {
             return Err(LumentixError::NotInitialized);
         }
         
-        storage::get_event(&env, event_id)
+        storage::get_event(&env, event_id)
+    }
+
+    /// Get running statistics (sold, used, refunded, revenue) for an event
+    pub fn get_event_stats(env: Env, event_id: u64) -> Result<EventStats, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        storage::get_event(&env, event_id)?;
+        Ok(storage::get_stats(&env, event_id))
+    }
+
+    /// Get a commitment to an event's tickets, escrow and counters, for
+    /// auditors to compare with an off-chain reconstruction
+    ///
+    /// Computed as `lumentix_types::compute_state_digest` over the running
+    /// ticket digest, the escrow balance and the event's statistics.
+    pub fn get_event_state_digest(env: Env, event_id: u64) -> Result<BytesN<32>, LumentixError> {
+        if !storage::has_event(&env, event_id) {
+            return Err(LumentixError::EventNotFound);
+        }
+        
+        Ok(lumentix_types::compute_state_digest(
+            &env,
+            &storage::get_ticket_digest(&env, event_id),
+            storage::get_escrow(&env, event_id)?,
+            &storage::get_stats(&env, event_id),
+        ))
+    }
+
+    /// Get the number of tickets still available for an event
+    pub fn get_remaining_capacity(env: Env, event_id: u64) -> Result<u32, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        Ok(event.max_tickets.saturating_sub(event.tickets_sold))
+    }
+
+    /// Whether `purchase_ticket` would currently accept a sale for an event:
+    /// active, before its sales close and not sold out of released capacity
+    pub fn is_on_sale(env: Env, event_id: u64) -> Result<bool, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let event = storage::get_event(&env, event_id)?;
+        Ok(event.status == EventStatus::Active
+            && env.ledger().timestamp() < sales_close_time(&env, &event)
+            && event.tickets_sold < released_capacity(&env, &event))
     }
 
     /// Get ticket details
@@ -437,6 +4333,69 @@ impl LumentixContract {
         storage::get_ticket(&env, ticket_id)
     }
 
+    /// Get a ticket together with its event's name, start time and status
+    pub fn get_ticket_details(env: Env, ticket_id: u64) -> Result<TicketDetails, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        
+        Ok(TicketDetails {
+            ticket,
+            event_name: event.name,
+            event_start_time: event.start_time,
+            event_status: event.status,
+        })
+    }
+
+    /// Get summaries of several events at once; missing IDs yield `None`
+    pub fn get_events(env: Env, event_ids: Vec<u64>) -> Result<Vec<Option<EventSummary>>, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(event_ids.len())?;
+        
+        let mut events = Vec::new(&env);
+        for event_id in event_ids.iter() {
+            events.push_back(storage::get_event(&env, event_id).ok().map(|event| event_summary(&event)));
+        }
+        
+        Ok(events)
+    }
+
+    /// Get several tickets at once; missing IDs yield `None`
+    pub fn get_tickets(env: Env, ticket_ids: Vec<u64>) -> Result<Vec<Option<Ticket>>, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        validation::validate_batch_size(ticket_ids.len())?;
+        
+        let mut tickets = Vec::new(&env);
+        for ticket_id in ticket_ids.iter() {
+            tickets.push_back(storage::get_ticket(&env, ticket_id).ok());
+        }
+        
+        Ok(tickets)
+    }
+
+    /// Get the contract build version
+    pub fn get_version(env: Env) -> String {
+        String::from_str(&env, CONTRACT_VERSION)
+    }
+
+    /// Get the ledger timestamp at which the contract was initialized
+    pub fn get_initialized_at(env: Env) -> Result<u64, LumentixError> {
+        if !storage::is_initialized(&env) {
+            return Err(LumentixError::NotInitialized);
+        }
+        
+        Ok(storage::get_initialized_at(&env))
+    }
+
     /// Get admin address
     pub fn get_admin(env: Env) -> Result<Address, LumentixError> {
         if !storage::is_initialized(&env) {
@@ -446,4 +4405,1161 @@ impl LumentixContract {
         Ok(storage::get_admin(&env))
     }
 }
+
+/// Check that `validator` may check tickets in at `event`: its organizer or
+/// one of its gate devices whose registration has not lapsed
+fn check_gate_validator(env: &Env, validator: &Address, event: &Event) -> Result<(), LumentixError> {
+    if *validator == event.organizer {
+        return Ok(());
+    }
+    
+    let now = env.ledger().timestamp();
+    let registered = storage::get_gate_devices(env, event.id)
+        .iter()
+        .any(|gate| gate.device == *validator && now < gate.expires_at);
+    if !registered {
+        return Err(LumentixError::Unauthorized);
+    }
+    
+    Ok(())
+}
+
+/// Admit a ticket, reporting why it is not admissible as an error
+fn admit_or_fail(env: &Env, ticket: Ticket, event: &Event, secret: &Bytes) -> Result<CheckInResult, LumentixError> {
+    match check_admissible(env, &ticket, event) {
+        Err(CheckInRejection::Refunded) => panic_with_error!(env, TicketError::TicketRefunded),
+        Err(CheckInRejection::Expired) => panic_with_error!(env, TicketError::TicketExpired),
+        Err(CheckInRejection::Redeemed) => panic_with_error!(env, TicketError::TicketRedeemed),
+        Err(CheckInRejection::AlreadyUsedToday) => Err(LumentixError::TicketAlreadyUsedToday),
+        Err(CheckInRejection::Locked) => Err(LumentixError::InvalidStatusTransition),
+        Err(_) => Err(LumentixError::TicketAlreadyUsed),
+        Ok(()) if !secret_matches(env, ticket.id, secret) => Err(LumentixError::Unauthorized),
+        Ok(()) => admit_ticket(env, ticket, event),
+    }
+}
+
+/// Check a scanned QR secret against a ticket's check-in commitment
+///
+/// Tickets whose holder never set a commitment admit any secret.
+fn secret_matches(env: &Env, ticket_id: u64, secret: &Bytes) -> bool {
+    match checkin_hash(env, ticket_id) {
+        Some(hash) => BytesN::from(env.crypto().sha256(secret)) == hash,
+        None => true,
+    }
+}
+
+/// Mark a validated ticket as used and build the check-in result
+///
+/// Multi-use tickets stay valid until they have been scanned `max_uses`
+/// times; the ticket statistics count a ticket as used on its first scan.
+fn admit_ticket(env: &Env, mut ticket: Ticket, event: &Event) -> Result<CheckInResult, LumentixError> {
+    if !is_reentry(env, &ticket, event) {
+        if ticket.uses == 0 {
+            storage::record_check_in(env, ticket.event_id);
+            
+            // Attendance earns the refundable part of a deposit ticket back;
+            // imported tickets paid no deposit on-chain
+            if let Some(refundable_bps) = storage::get_deposit_bps(env, ticket.event_id) {
+                if !storage::is_imported(env, ticket.id) {
+                    storage::add_deposits_owed(env, ticket.event_id, deposit_for(env, &ticket, event, refundable_bps)?);
+                }
+            }
+        }
+        
+        ticket.uses += 1;
+        ticket.last_used_at = env.ledger().timestamp();
+        if ticket.uses >= event.max_uses {
+            ticket.status = TicketStatus::Used;
+        }
+    }
+    
+    // A multi-day pass scanned again without checking out is the same
+    // person still inside
+    if !ticket.inside {
+        storage::record_entry(env, ticket.event_id);
+    }
+    ticket.inside = true;
+    storage::set_ticket(env, ticket.id, &ticket);
+    events::ticket_checked_in(env, ticket.event_id, ticket.id);
+    
+    Ok(CheckInResult {
+        ticket_id: ticket.id,
+        event_id: ticket.event_id,
+        owner: ticket.owner,
+        tier: ticket.tier,
+        checked_in_at: env.ledger().timestamp(),
+    })
+}
+
+/// Check whether a ticket may be admitted at the gate right now
+fn check_admissible(env: &Env, ticket: &Ticket, event: &Event) -> Result<(), CheckInRejection> {
+    match ticket.status {
+        TicketStatus::Valid | TicketStatus::Used => {}
+        TicketStatus::Refunded => return Err(CheckInRejection::Refunded),
+        TicketStatus::Expired => return Err(CheckInRejection::Expired),
+        TicketStatus::Redeemed => return Err(CheckInRejection::Redeemed),
+        TicketStatus::PendingTransfer | TicketStatus::Standby | TicketStatus::WillCall => {
+            return Err(CheckInRejection::Locked)
+        }
+    }
+    
+    if is_reentry(env, ticket, event) {
+        return Ok(());
+    }
+    
+    if ticket.status == TicketStatus::Used {
+        return Err(CheckInRejection::AlreadyUsed);
+    }
+    
+    if used_today(env, ticket, event) {
+        return Err(CheckInRejection::AlreadyUsedToday);
+    }
+    
+    Ok(())
+}
+
+/// Check whether a scan is a re-entry of a checked-out attendee
+///
+/// Re-entry is only possible on events that allow it, for tickets already
+/// admitted on the current event day, and only until the event ends.
+fn is_reentry(env: &Env, ticket: &Ticket, event: &Event) -> bool {
+    event.allow_reentry
+        && !ticket.inside
+        && used_today(env, ticket, event)
+        && env.ledger().timestamp() < event.end_time
+}
+
+/// Check whether a multi-use ticket was already scanned on the current event day
+///
+/// Event days are counted in 24-hour periods from the event's start time.
+fn used_today(env: &Env, ticket: &Ticket, event: &Event) -> bool {
+    if ticket.uses == 0 {
+        return false;
+    }
+    
+    let day = |timestamp: u64| timestamp.saturating_sub(event.start_time) / SECONDS_PER_DAY;
+    day(ticket.last_used_at) == day(env.ledger().timestamp())
+}
+
+/// Move one of `organizer`'s drafts to `Active`
+fn publish(env: &Env, organizer: &Address, event_id: u64) -> Result<(), LumentixError> {
+    let mut event = storage::get_event(env, event_id)?;
+    
+    if event.organizer != *organizer {
+        return Err(LumentixError::Unauthorized);
+    }
+    
+    if event.status != EventStatus::Draft {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    
+    event.status = EventStatus::Active;
+    storage::set_event(env, event_id, &event);
+    events::status_changed(env, event_id, event.status.clone());
+    events::sales_opened(env, event_id, storage::get_followers(env, event_id).len());
+    
+    charge_rent(env, &event)
+}
+
+/// Move one of `organizer`'s ended events to `Completed`
+fn complete(env: &Env, organizer: &Address, event_id: u64) -> Result<(), LumentixError> {
+    let mut event = storage::get_event(env, event_id)?;
+    
+    if event.organizer != *organizer {
+        return Err(LumentixError::Unauthorized);
+    }
+    
+    if event.status != EventStatus::Active {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    
+    if env.ledger().timestamp() < event.end_time {
+        return Err(LumentixError::EventNotEnded);
+    }
+    
+    event.status = EventStatus::Completed;
+    storage::set_event(env, event_id, &event);
+    events::status_changed(env, event_id, event.status.clone());
+    storage::decrement_organizer_active_events(env, organizer);
+    storage::release_event_fees(env, event_id);
+    
+    Ok(())
+}
+
+/// Open an event's waitlist if the organizer enabled it and it is not open yet
+fn open_waitlist(env: &Env, event_id: u64) {
+    if storage::is_waitlist_enabled(env, event_id) && storage::get_waitlist(env, event_id).is_none() {
+        storage::set_waitlist(env, event_id, &Vec::new(env));
+        events::waitlist_opened(env, event_id);
+    }
+}
+
+/// Principal a ticket's buyer paid into escrow for the ticket itself
+///
+/// This is the recorded payment less the fee and any add-ons, so bundle and
+/// member discounts are reflected; tickets without a recorded payment fall
+/// back to the list price.
+fn ticket_principal(env: &Env, ticket: &Ticket, event: &Event) -> i128 {
+    match storage::get_ticket_payment(env, ticket.id) {
+        Some(payment) => {
+            let add_ons = storage::get_ticket_add_ons(env, ticket.id).map_or(0, |bought| bought.paid);
+            payment.amount - ticket.fee_paid - add_ons
+        }
+        None => event.ticket_price - ticket.fee_paid,
+    }
+}
+
+/// Tax withheld from a ticket's principal
+fn tax_for(env: &Env, ticket: &Ticket, event: &Event, rate_bps: u32) -> Result<i128, LumentixError> {
+    money::bps_of(ticket_principal(env, ticket, event), rate_bps)
+}
+
+/// Pro-rata refund of a curtailed event's ticket: `refund_bps` of the
+/// principal paid for it
+fn prorata_refund_for(env: &Env, ticket: &Ticket, event: &Event, refund_bps: u32) -> Result<i128, LumentixError> {
+    money::bps_of(ticket_principal(env, ticket, event), refund_bps)
+}
+
+/// Refundable part of a deposit ticket's principal
+fn deposit_for(env: &Env, ticket: &Ticket, event: &Event, refundable_bps: u32) -> Result<i128, LumentixError> {
+    money::bps_of(ticket_principal(env, ticket, event), refundable_bps)
+}
+
+/// Time at which an event's ticket sales close: its end time, or earlier if
+/// the organizer set a cutoff before the start
+fn sales_close_time(env: &Env, event: &Event) -> u64 {
+    match storage::get_sales_cutoff(env, event.id) {
+        Some(cutoff) => event.start_time.saturating_sub(cutoff).min(event.end_time),
+        None => event.end_time,
+    }
+}
+
+/// Capacity of an event currently open for sale: all of it, unless the
+/// organizer scheduled waves, in which case only the waves already released
+/// plus any unlocked bonus capacity
+fn released_capacity(env: &Env, event: &Event) -> u32 {
+    let waves = storage::get_capacity_waves(env, event.id);
+    if waves.is_empty() {
+        return event.max_tickets;
+    }
+    
+    // Unlocked bonus capacity sits outside the waves
+    let bonus = storage::get_bonus_capacity(env, event.id)
+        .filter(|bonus| bonus.unlocked)
+        .map_or(0, |bonus| bonus.count);
+    
+    let now = env.ledger().timestamp();
+    let released = waves
+        .iter()
+        .filter(|wave| wave.release_at <= now)
+        .map(|wave| wave.count)
+        .sum::<u32>();
+    (released + bonus).min(event.max_tickets)
+}
+
+/// An event's resale split: the organizer's, or by default the platform's
+/// base fee with the rest to the seller
+fn resale_split(env: &Env, event_id: u64) -> ResaleSplit {
+    storage::get_resale_split(env, event_id).unwrap_or_else(|| {
+        let platform_bps = storage::get_config(env).platform_fee_bps;
+        ResaleSplit {
+            seller_bps: 10_000 - platform_bps,
+            organizer_bps: 0,
+            platform_bps,
+        }
+    })
+}
+
+/// Check that a ticket can still change hands: valid and never scanned in
+fn check_resellable(env: &Env, ticket: &Ticket) -> Result<(), LumentixError> {
+    match ticket.status {
+        TicketStatus::Valid if ticket.uses == 0 => Ok(()),
+        TicketStatus::Valid | TicketStatus::Used => Err(LumentixError::TicketAlreadyUsed),
+        TicketStatus::PendingTransfer | TicketStatus::Standby | TicketStatus::WillCall => {
+            Err(LumentixError::InvalidStatusTransition)
+        }
+        TicketStatus::Refunded => panic_with_error!(env, TicketError::TicketRefunded),
+        TicketStatus::Expired => panic_with_error!(env, TicketError::TicketExpired),
+        TicketStatus::Redeemed => panic_with_error!(env, TicketError::TicketRedeemed),
+    }
+}
+
+/// Check that an event's organizer has not disabled ticket transfers
+fn check_transferable(env: &Env, event_id: u64) {
+    if !storage::is_transferable(env, event_id) {
+        panic_with_error!(env, TicketError::NotTransferable);
+    }
+}
+
+/// Check that a ticket's status still allows a refund
+fn check_refundable(ticket: &Ticket) -> Result<(), LumentixError> {
+    match ticket.status {
+        TicketStatus::Valid | TicketStatus::Standby | TicketStatus::WillCall => Ok(()),
+        TicketStatus::Used => Err(LumentixError::TicketAlreadyUsed),
+        _ => Err(LumentixError::RefundNotAllowed),
+    }
+}
+
+/// The status a newly confirmed ticket of an event starts in: held for
+/// pickup on will-call events, otherwise valid
+fn issued_status(env: &Env, event_id: u64) -> TicketStatus {
+    if storage::get_will_call(env, event_id).is_some() {
+        TicketStatus::WillCall
+    } else {
+        TicketStatus::Valid
+    }
+}
+
+/// Check that a ticket is not out on loan, which keeps it with its borrower
+fn check_not_lent(env: &Env, ticket_id: u64) -> Result<(), LumentixError> {
+    if storage::get_active_loan(env, ticket_id).is_some() {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    Ok(())
+}
+
+/// A ticket's check-in commitment: its borrower's during a loan, otherwise
+/// its owner's
+fn checkin_hash(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    match storage::get_active_loan(env, ticket_id) {
+        Some(loan) => loan.checkin_hash.into(),
+        None => storage::get_checkin_hash(env, ticket_id),
+    }
+}
+
+/// Load two distinct tickets of active, transferable events for a swap;
+/// `ticket_b` must be able to change hands, while `ticket_a`'s status is
+/// left to the caller
+fn swappable_tickets(env: &Env, ticket_a: u64, ticket_b: u64) -> Result<(Ticket, Ticket), LumentixError> {
+    if ticket_a == ticket_b {
+        panic_with_error!(env, TicketError::SameTicket);
+    }
+    
+    let a = storage::get_ticket(env, ticket_a)?;
+    let b = storage::get_ticket(env, ticket_b)?;
+    check_resellable(env, &b)?;
+    for ticket in [&a, &b] {
+        if storage::get_event(env, ticket.event_id)?.status != EventStatus::Active {
+            return Err(LumentixError::EventNotOnSale);
+        }
+        check_transferable(env, ticket.event_id);
+        check_not_lent(env, ticket.id)?;
+    }
+    
+    Ok((a, b))
+}
+
+/// Add an event's bonus capacity once its unlock threshold is reached
+fn unlock_bonus_capacity(env: &Env, event: &mut Event) {
+    let Some(mut bonus) = storage::get_bonus_capacity(env, event.id) else {
+        return;
+    };
+    if bonus.unlocked {
+        return;
+    }
+    
+    let reached = match bonus.unlock {
+        BonusUnlock::Revenue(amount) => storage::get_stats(env, event.id).revenue >= amount,
+        BonusUnlock::SellThroughBps(bps) => {
+            event.tickets_sold as u64 * 10_000 >= bps as u64 * event.max_tickets as u64
+        }
+    };
+    if !reached {
+        return;
+    }
+    
+    bonus.unlocked = true;
+    event.max_tickets += bonus.count;
+    storage::set_bonus_capacity(env, event.id, &bonus);
+    storage::set_event(env, event.id, event);
+    events::bonus_unlocked(env, event.id, event.max_tickets);
+}
+
+/// Standby tickets an event can still sell: once every seat is sold, up to
+/// its `oversell_bps` of capacity, and at most `MAX_BATCH_SIZE` so the pool
+/// can be resolved in one call, until a day before the event
+fn standby_room(env: &Env, event: &Event) -> u32 {
+    let Some(bps) = storage::get_oversell_bps(env, event.id) else {
+        return 0;
+    };
+    if event.tickets_sold < event.max_tickets
+        || env.ledger().timestamp().saturating_add(STANDBY_RESOLUTION_SECS) >= event.start_time
+    {
+        return 0;
+    }
+    
+    let pool = (event.max_tickets as u64 * bps as u64 / 10_000).min(validation::MAX_BATCH_SIZE as u64) as u32;
+    pool.saturating_sub(storage::get_standby(env, event.id).len())
+}
+
+/// Build the list view of an event
+fn event_summary(event: &Event) -> EventSummary {
+    EventSummary {
+        id: event.id,
+        name: event.name.clone(),
+        start_time: event.start_time,
+        status: event.status.clone(),
+        price: event.ticket_price,
+        remaining: event.max_tickets.saturating_sub(event.tickets_sold),
+    }
+}
+
+/// Checks shared by ticket sales and their simulation: the event and the
+/// ticket's tier are on sale, the event has `quantity` tickets of released
+/// capacity left and accepts the buyer
+fn check_sale(
+    env: &Env,
+    event: &Event,
+    buyer: &Address,
+    tier: u32,
+    quantity: u32,
+) -> Result<(), LumentixError> {
+    // Validate event status
+    if event.status != EventStatus::Active {
+        return Err(LumentixError::EventNotOnSale);
+    }
+    
+    let now = env.ledger().timestamp();
+    if now >= sales_close_time(env, event) {
+        return Err(LumentixError::SalesClosed);
+    }
+    
+    // A tier window narrows the event's sales period further
+    if let Some(window) = storage::get_tier_sales_windows(env, event.id).get(tier) {
+        if now < window.opens_at || now >= window.closes_at {
+            return Err(LumentixError::SalesClosed);
+        }
+    }
+    
+    // Check capacity, counting only waves released so far, then standby
+    if event.tickets_sold + quantity > released_capacity(env, event)
+        && standby_room(env, event) < quantity
+    {
+        return Err(LumentixError::EventSoldOut);
+    }
+    
+    if let Some(max_bps) = storage::get_max_no_show_bps(env, event.id) {
+        let record = storage::get_attendance(env, buyer);
+        if record.settled > 0 && record.no_shows as u64 * 10_000 > max_bps as u64 * record.settled as u64 {
+            return Err(LumentixError::NoShowRateTooHigh);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Check the buyer-supplied parts of a purchase against what the event
+/// requires, returning the policy version the ticket will be bound to
+fn check_purchase_request(
+    env: &Env,
+    event_id: u64,
+    request: &PurchaseRequest,
+) -> Result<Option<PolicyVersion>, LumentixError> {
+    // Events with a payment asset must be paid in exactly that asset
+    let configured = storage::get_payment_asset(env, event_id);
+    let payment_asset: Option<PaymentAsset> = request.payment_asset.clone().into();
+    if configured.is_some() && payment_asset != configured {
+        return Err(LumentixError::WrongPaymentAsset);
+    }
+    
+    let policy = storage::get_current_policy(env, event_id);
+    if let Some(policy) = &policy {
+        if request.terms_hash != OptionalHash::Some(policy.hash.clone()) {
+            return Err(LumentixError::PolicyMismatch);
+        }
+    }
+    
+    // Exactly the event's custom fields must be supplied
+    let required = storage::get_ticket_fields(env, event_id);
+    let fields = &request.fields;
+    if fields.len() != required.len() || required.iter().any(|field| !fields.contains_key(field)) {
+        return Err(LumentixError::PolicyMismatch);
+    }
+    
+    Ok(policy)
+}
+
+/// Take `add_ons` out of inventory `copies` times, returning the updated
+/// catalog and the price of one set
+fn reserve_add_ons(
+    env: &Env,
+    event_id: u64,
+    add_ons: &Vec<u32>,
+    copies: u32,
+) -> Result<(Vec<AddOn>, i128), LumentixError> {
+    validation::validate_batch_size(add_ons.len())?;
+    let mut catalog = storage::get_add_ons(env, event_id);
+    let mut total = 0;
+    for _ in 0..copies {
+        total = 0;
+        for add_on_id in add_ons.iter() {
+            let mut add_on = catalog.get(add_on_id).ok_or(LumentixError::AddOnUnavailable)?;
+            if add_on.sold >= add_on.inventory {
+                return Err(LumentixError::AddOnUnavailable);
+            }
+            add_on.sold += 1;
+            total += add_on.price;
+            catalog.set(add_on_id, add_on);
+        }
+    }
+    
+    Ok((catalog, total))
+}
+
+/// Sell a ticket, with optional add-ons and custom fields, on behalf of
+/// `purchase_ticket_with_request` and the narrower purchase entrypoints
+fn sell_ticket(
+    env: &Env,
+    buyer: Address,
+    event_id: u64,
+    payment_amount: i128,
+    request: PurchaseRequest,
+) -> Result<u64, LumentixError> {
+    buyer.require_auth();
+    
+    if !storage::is_initialized(env) {
+        return Err(LumentixError::NotInitialized);
+    }
+    
+    validation::validate_address(&buyer)?;
+    validation::validate_positive_amount(payment_amount)?;
+    
+    let mut event = storage::get_event(env, event_id)?;
+    
+    check_sale(env, &event, &buyer, BASE_TIER, 1)?;
+    let policy = check_purchase_request(env, event_id, &request)?;
+    let (catalog, add_ons_total) = reserve_add_ons(env, event_id, &request.add_ons, 1)?;
+    
+    // Validate payment amount
+    let quote = fee_quote(env, &event)?;
+    if payment_amount < price_for(env, &event, &buyer, &quote)? + add_ons_total {
+        return Err(LumentixError::InsufficientFunds);
+    }
+    
+    let PurchaseRequest { terms_hash, add_ons, fields, memo, .. } = request;
+    let terms_hash: Option<BytesN<32>> = terms_hash.into();
+    let memo: Option<BytesN<32>> = memo.into();
+    
+    let ticket_id = storage::get_next_ticket_id(env);
+    
+    // Sales past capacity passed `check_sale` on standby room
+    let standby = event.tickets_sold >= event.max_tickets;
+    
+    let ticket = Ticket {
+        id: ticket_id,
+        event_id,
+        owner: buyer.clone(),
+        tier: BASE_TIER,
+        purchase_time: env.ledger().timestamp(),
+        uses: 0,
+        last_used_at: 0,
+        status: if standby { TicketStatus::Standby } else { issued_status(env, event_id) },
+        inside: false,
+        fee_bps: quote.fee_bps,
+        fee_paid: quote.fee,
+    };
+    
+    storage::set_ticket(env, ticket_id, &ticket);
+    storage::set_ticket_payment(env, ticket_id, &TicketPayment {
+        token: storage::get_payment_asset(env, event_id).map(|asset| asset.token),
+        amount: payment_amount,
+    });
+    record_history(env, &buyer, HistoryAction::Purchased, &ticket, payment_amount);
+    storage::add_holding(env, &buyer, event_id);
+    events::ticket_purchased(env, event_id, ticket_id);
+    if let Some(hash) = terms_hash {
+        storage::set_terms_hash(env, ticket_id, &hash);
+    }
+    if let Some(memo) = memo {
+        storage::set_purchase_memo(env, ticket_id, &memo);
+    }
+    if !fields.is_empty() {
+        storage::set_field_values(env, ticket_id, &fields);
+    }
+    if let Some(policy) = policy {
+        storage::set_ticket_policy_version(env, ticket_id, policy.version);
+    }
+    if !add_ons.is_empty() {
+        storage::set_add_ons(env, event_id, &catalog);
+        storage::set_ticket_add_ons(env, ticket_id, &TicketAddOns {
+            items: add_ons,
+            paid: add_ons_total,
+        });
+    }
+    storage::increment_ticket_id(env);
+    
+    // Standby tickets count as sold but only take a seat once confirmed
+    if standby {
+        let mut pool = storage::get_standby(env, event_id);
+        pool.push_back(ticket_id);
+        storage::set_standby(env, event_id, &pool);
+    }
+    event.tickets_sold += 1;
+    storage::set_event(env, event_id, &event);
+    
+    // Store payment in escrow, less the platform fee
+    storage::record_deposit(env, payment_amount);
+    storage::add_escrow(env, event_id, payment_amount - quote.fee);
+    storage::add_tier_escrow(env, event_id, ticket.tier, payment_amount - quote.fee);
+    storage::add_platform_balance(env, &storage::event_token(env, event_id), quote.fee);
+    storage::reserve_fee(env, event_id, quote.fee);
+    storage::record_sale(env, event_id, payment_amount - quote.fee);
+    if let Some(tax) = storage::get_tax_terms(env, event_id) {
+        storage::add_tax_collected(env, event_id, tax_for(env, &ticket, &event, tax.rate_bps)?);
+    }
+    if !standby {
+        storage::add_refundable_ticket(env, event_id, ticket_principal(env, &ticket, &event));
+    }
+    
+    // Bonus capacity unlocks on the sale's revenue, before sell-out is judged
+    unlock_bonus_capacity(env, &mut event);
+    if !standby && event.tickets_sold == event.max_tickets {
+        events::sold_out(env, event_id, event.tickets_sold);
+        open_waitlist(env, event_id);
+    }
+    
+    // Tokens move only once every balance above is committed
+    payments::collect(env, event_id, &buyer, payment_amount);
+    
+    Ok(ticket_id)
+}
+
+/// Mark a ticket refunded and return its price from the event's escrow
+fn execute_refund(
+    env: &Env,
+    mut ticket: Ticket,
+    event: &Event,
+    reason: RefundReason,
+) -> Result<(), LumentixError> {
+    // Imported tickets were paid for off-chain and hold no escrow
+    if storage::is_imported(env, ticket.id) {
+        return Err(LumentixError::RefundNotAllowed);
+    }
+    
+    // A full refund replaces the pro-rata refund reserved for the ticket,
+    // unless that has already been paid out
+    let seated = ticket.status != TicketStatus::Standby;
+    if let Some(mut curtailment) = storage::get_curtailment(env, event.id) {
+        if storage::get_prorata_refund(env, ticket.id).is_some() {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+        if seated {
+            curtailment.outstanding -= prorata_refund_for(env, &ticket, event, curtailment.refund_bps)?;
+            curtailment.tickets_owed -= 1;
+            if curtailment.tickets_owed == 0 {
+                curtailment.outstanding = 0;
+            }
+            storage::set_curtailment(env, event.id, &curtailment);
+        }
+    }
+    if seated {
+        storage::remove_refundable_ticket(env, event.id, ticket_principal(env, &ticket, event));
+    }
+    
+    ticket.status = TicketStatus::Refunded;
+    storage::set_ticket(env, ticket.id, &ticket);
+    storage::remove_pickup(env, ticket.id);
+    
+    // The principal comes back out of escrow; the fee recorded at purchase
+    // comes out of the platform balance only if the platform refunds fees
+    let mut principal = event.ticket_price - ticket.fee_paid;
+    
+    // Add-ons are refunded with the ticket and go back into inventory
+    if let Some(bought) = storage::get_ticket_add_ons(env, ticket.id) {
+        let mut catalog = storage::get_add_ons(env, event.id);
+        for add_on_id in bought.items.iter() {
+            if let Some(mut add_on) = catalog.get(add_on_id) {
+                add_on.sold -= 1;
+                catalog.set(add_on_id, add_on);
+            }
+        }
+        storage::set_add_ons(env, event.id, &catalog);
+        principal += bought.paid;
+    }
+    
+    // What the buyer actually paid, overpayment included, takes precedence
+    // over the price for tickets sold since payments were recorded
+    let payment = storage::get_ticket_payment(env, ticket.id);
+    if let Some(payment) = &payment {
+        principal = payment.amount - ticket.fee_paid;
+    }
+    
+    // The ticket's withheld tax is refunded along with the principal
+    if let Some(tax) = storage::get_tax_terms(env, event.id) {
+        storage::add_tax_collected(env, event.id, -tax_for(env, &ticket, event, tax.rate_bps)?);
+    }
+    
+    let fee = if storage::get_config(env).refund_platform_fee {
+        ticket.fee_paid
+    } else {
+        0
+    };
+    settle_yield(env, event.id)?;
+    storage::deduct_escrow(env, event.id, principal)?;
+    storage::deduct_tier_escrow(env, event.id, ticket.tier, principal);
+    let token = match &payment {
+        Some(payment) => payment.token.clone(),
+        None => storage::event_token(env, event.id),
+    };
+    storage::deduct_platform_balance(env, &token, fee)?;
+    storage::record_refund_paid(env, principal + fee);
+    storage::record_refund(env, event.id, principal, reason);
+    
+    // Settled events already released their fee reservations
+    if event.status != EventStatus::Completed {
+        storage::release_fee(env, event.id, ticket.fee_paid);
+    }
+    
+    storage::set_refund_receipt(env, ticket.id, &RefundReceipt {
+        ticket_id: ticket.id,
+        from_escrow: principal,
+        from_platform: fee,
+        refunded_at: env.ledger().timestamp(),
+    });
+    record_history(env, &ticket.owner, HistoryAction::Refunded, &ticket, principal + fee);
+    storage::remove_holding(env, &ticket.owner, event.id);
+    events::ticket_refunded(env, event.id, ticket.id);
+    
+    match payment {
+        Some(payment) => payments::refund(env, &payment, &ticket.owner, principal + fee),
+        None => payments::pay_out(env, event.id, &ticket.owner, principal + fee),
+    }
+    
+    Ok(())
+}
+
+/// Price a buyer pays for a ticket to an event: the ticket price less the
+/// best bundle discount they qualify for, or their member price if lower,
+/// never below the platform fee
+fn price_for(env: &Env, event: &Event, buyer: &Address, quote: &FeeQuote) -> Result<i128, LumentixError> {
+    let mut discount_bps = 0;
+    for discount in storage::get_bundle_discounts(env, event.id).iter() {
+        if discount.discount_bps > discount_bps && storage::get_holdings(env, buyer, discount.required_event_id) > 0 {
+            discount_bps = discount.discount_bps;
+        }
+    }
+    let mut price = event.ticket_price - money::bps_of(event.ticket_price, discount_bps)?;
+    
+    if let Some(pricing) = storage::get_member_pricing(env, event.id) {
+        let member_price = membership::tier_of(env, &pricing.contract, buyer)
+            .and_then(|tier| pricing.prices.get(tier));
+        if let Some(member_price) = member_price {
+            price = price.min(member_price);
+        }
+    }
+    
+    Ok(price.max(quote.fee))
+}
+
+/// Take the page of `items` following the `after` cursor
+///
+/// `items` must be sorted by `key` with no repeated keys. The returned cursor
+/// is the key of the page's last item while more items remain, so a caller
+/// passing it back sees every item exactly once even when items are added or
+/// removed between calls.
+fn page<T, K>(
+    items: &Vec<T>,
+    after: Option<K>,
+    limit: u32,
+    key: impl Fn(&T) -> K,
+) -> Result<(Vec<T>, Option<K>), LumentixError>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    K: Ord,
+{
+    validation::validate_batch_size(limit)?;
+    
+    let mut start = 0;
+    if let Some(after) = after {
+        let mut end = items.len();
+        while start < end {
+            let mid = start + (end - start) / 2;
+            if key(&items.get_unchecked(mid)) <= after {
+                start = mid + 1;
+            } else {
+                end = mid;
+            }
+        }
+    }
+    let end = start.saturating_add(limit).min(items.len());
+    let page = items.slice(start..end);
+    
+    let next = if end > 0 && end < items.len() {
+        Some(key(&items.get_unchecked(end - 1)))
+    } else {
+        None
+    };
+    
+    Ok((page, next))
+}
+
+/// Add a ticket entering or leaving an account to the account's history
+fn record_history(env: &Env, account: &Address, action: HistoryAction, ticket: &Ticket, amount: i128) {
+    storage::record_history(env, account, &HistoryEntry {
+        id: storage::next_history_id(env, account),
+        action,
+        ticket_id: ticket.id,
+        event_id: ticket.event_id,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    });
+}
+
+/// Escrow held for an event whose record no longer exists, which no refund
+/// or release can reach
+fn orphaned_escrow(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
+    if storage::get_event(env, event_id).is_ok() {
+        return Err(LumentixError::EventAlreadyExists);
+    }
+    
+    let amount = storage::get_escrow(env, event_id)?;
+    if amount <= 0 {
+        return Err(LumentixError::InsufficientEscrow);
+    }
+    
+    Ok(amount)
+}
+
+/// Load a ticket's pending late refund request on behalf of the event organizer
+fn pending_late_refund(
+    env: &Env,
+    organizer: &Address,
+    ticket_id: u64,
+) -> Result<(LateRefundRequest, Event), LumentixError> {
+    let request = storage::get_late_refund(env, ticket_id)
+        .filter(|request| request.status == LateRefundStatus::Pending)
+        .ok_or(LumentixError::RefundRequestNotFound)?;
+    let event = storage::get_event(env, request.event_id)?;
+    
+    if event.organizer != *organizer {
+        return Err(LumentixError::Unauthorized);
+    }
+    
+    Ok((request, event))
+}
+
+/// Platform fee due on a ticket sale at the current fee rate
+///
+/// The fee is the flat per-ticket fee plus the organizer's bps rate, capped
+/// at the ticket price. Exempt events and events of exempt organizers pay
+/// no fee.
+fn fee_quote(env: &Env, event: &Event) -> Result<FeeQuote, LumentixError> {
+    if storage::is_fee_exempt(env, &FeeExemptTarget::Event(event.id))
+        || storage::is_fee_exempt(env, &FeeExemptTarget::Organizer(event.organizer.clone()))
+    {
+        return Ok(FeeQuote {
+            ticket_price: event.ticket_price,
+            fee_bps: 0,
+            fee_flat: 0,
+            fee: 0,
+        });
+    }
+    
+    let fee_bps = fee_bps_for(env, &event.organizer);
+    let config = storage::get_config(env);
+    let fee_flat = config.platform_fee_flat;
+    let variable = money::platform_share(event.ticket_price, fee_bps, config.rounding)?;
+    
+    Ok(FeeQuote {
+        ticket_price: event.ticket_price,
+        fee_bps,
+        fee_flat,
+        fee: (fee_flat + variable).min(event.ticket_price),
+    })
+}
+
+/// Fee rate for an organizer: the highest volume tier they have reached,
+/// or the platform's base rate below the first tier
+fn fee_bps_for(env: &Env, organizer: &Address) -> u32 {
+    let volume = storage::get_organizer_volume(env, organizer);
+    let mut fee_bps = storage::get_config(env).platform_fee_bps;
+    for tier in storage::get_fee_tiers(env).iter() {
+        if volume < tier.min_volume {
+            break;
+        }
+        fee_bps = tier.fee_bps;
+    }
+    fee_bps
+}
+
+/// Configured fee destinations, or the admin alone when none are set
+fn fee_destinations(env: &Env) -> Vec<FeeDestination> {
+    storage::get_fee_destinations(env).unwrap_or_else(|| {
+        Vec::from_array(env, [FeeDestination {
+            recipient: storage::get_admin(env),
+            share_bps: 10_000,
+        }])
+    })
+}
+
+/// Derive a deterministic event ID from an organizer and salt
+fn derive_event_id(env: &Env, organizer: &Address, salt: &BytesN<32>) -> u64 {
+    let mut preimage = organizer.clone().to_xdr(env);
+    preimage.append(&salt.clone().into());
+    
+    let digest = env.crypto().sha256(&preimage).to_array();
+    let mut id_bytes = [0u8; 8];
+    id_bytes.copy_from_slice(&digest[..8]);
+    
+    u64::from_be_bytes(id_bytes) | SALTED_EVENT_ID_FLAG
+}
+
+/// Validate and store a newly created event, returning its assigned ID
+///
+/// Events created with a salt get a derived ID; all others take the next
+/// sequential ID.
+fn create_event_record(
+    env: &Env,
+    mut event: Event,
+    salt: Option<BytesN<32>>,
+) -> Result<u64, LumentixError> {
+    if !storage::is_initialized(env) {
+        return Err(LumentixError::NotInitialized);
+    }
+    
+    // Input validation
+    validation::validate_address(&event.organizer)?;
+    validation::validate_positive_amount(event.ticket_price)?;
+    validation::validate_positive_capacity(event.max_tickets)?;
+    validation::validate_time_range(event.start_time, event.end_time)?;
+    validation::validate_string_not_empty(&event.name)?;
+    validation::validate_summary(&event.summary)?;
+    let config = storage::get_config(env);
+    validation::validate_event_limits(&config, event.ticket_price, event.max_tickets)?;
+    validation::validate_event_schedule(&config, env.ledger().timestamp(), event.start_time, event.end_time)?;
+    
+    let quota = if storage::is_verified_organizer(env, &event.organizer) {
+        config.max_events_per_verified
+    } else {
+        config.max_events_per_organizer
+    };
+    if storage::get_organizer_active_events(env, &event.organizer) >= quota {
+        return Err(LumentixError::OrganizerEventLimitReached);
+    }
+    check_creation_rate(env, &config, &event.organizer)?;
+    
+    match salt {
+        Some(salt) => {
+            event.id = derive_event_id(env, &event.organizer, &salt);
+            if storage::has_event(env, event.id) {
+                return Err(LumentixError::EventAlreadyExists);
+            }
+        }
+        None => {
+            event.id = storage::get_next_event_id(env);
+            storage::increment_event_id(env);
+        }
+    }
+    
+    let defaults = storage::get_organizer_defaults(env, &event.organizer);
+    for default in defaults.iter() {
+        match default {
+            OrganizerDefault::MaxUses(max_uses) => event.max_uses = max_uses,
+            OrganizerDefault::AllowReentry(allowed) => event.allow_reentry = allowed,
+            _ => {}
+        }
+    }
+    
+    storage::set_event(env, event.id, &event);
+    storage::increment_organizer_active_events(env, &event.organizer);
+    storage::add_organizer_event(env, &event.organizer, event.id);
+    events::status_changed(env, event.id, event.status.clone());
+    if let Some(asset) = storage::get_default_payment_asset(env) {
+        storage::set_payment_asset(env, event.id, &asset);
+        set_asset_display(env, event.id, &asset);
+    }
+    for default in defaults.iter() {
+        match default {
+            OrganizerDefault::RefundPolicy(hash) => {
+                storage::add_policy_version(env, event.id, &hash);
+            }
+            OrganizerDefault::DepositTerms(bps) => storage::set_deposit_bps(env, event.id, bps),
+            OrganizerDefault::ResaleSplit(split) => storage::set_resale_split(env, event.id, &split),
+            OrganizerDefault::Transferable(transferable) => {
+                storage::set_transferable(env, event.id, transferable);
+            }
+            OrganizerDefault::TierWindow(tier, template) => {
+                let mut windows = storage::get_tier_sales_windows(env, event.id);
+                windows.set(tier, SalesWindow {
+                    opens_at: event.start_time.saturating_sub(template.opens_before_start),
+                    closes_at: event.start_time.saturating_sub(template.closes_before_start),
+                });
+                storage::set_tier_sales_windows(env, event.id, &windows);
+            }
+            _ => {}
+        }
+    }
+    
+    if event.status == EventStatus::Active {
+        charge_rent(env, &event)?;
+    }
+    
+    Ok(event.id)
+}
+
+/// Collect the storage rent for an event going on sale from its organizer:
+/// `rent_per_ticket_day` for each ticket of capacity and each started day
+/// until the event ends
+///
+/// The rent is held in the platform balance, reserved until consumed.
+fn charge_rent(env: &Env, event: &Event) -> Result<(), LumentixError> {
+    let now = env.ledger().timestamp();
+    let days = event.end_time.saturating_sub(now).div_ceil(SECONDS_PER_DAY);
+    let amount = storage::get_config(env)
+        .rent_per_ticket_day
+        .checked_mul(event.max_tickets as i128)
+        .and_then(|rent| rent.checked_mul(days as i128))
+        .ok_or(LumentixError::InvalidAmount)?;
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    storage::set_rent_deposit(env, event.id, &RentDeposit {
+        amount,
+        consumed: 0,
+        refunded: 0,
+        funded_from: now,
+        funded_until: event.end_time,
+    });
+    storage::record_deposit(env, amount);
+    storage::add_platform_balance(env, &storage::event_token(env, event.id), amount);
+    storage::reserve_rent(env, event.id, amount);
+    
+    payments::collect(env, event.id, &event.organizer, amount);
+    
+    Ok(())
+}
+
+/// Release the share of an event's prepaid rent accrued so far, pro-rata
+/// to the time elapsed, to the platform's withdrawable balance, returning
+/// the amount released
+fn consume_rent(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
+    let Some(mut rent) = storage::get_rent_deposit(env, event_id) else {
+        return Ok(0);
+    };
+    
+    let now = env.ledger().timestamp().min(rent.funded_until);
+    let period = rent.funded_until.saturating_sub(rent.funded_from);
+    let accrued = if period == 0 {
+        rent.amount
+    } else {
+        money::platform_part(
+            rent.amount,
+            now.saturating_sub(rent.funded_from) as i128,
+            period as i128,
+            storage::get_config(env).rounding,
+        )?
+    };
+    let released = (accrued - rent.consumed).min(rent.amount - rent.consumed - rent.refunded);
+    if released <= 0 {
+        return Ok(0);
+    }
+    
+    rent.consumed += released;
+    storage::set_rent_deposit(env, event_id, &rent);
+    storage::release_rent(env, event_id, released);
+    
+    Ok(released)
+}
+
+/// Redeem an event's yield position, if any, returning the yield earned
+///
+/// The vault must return at least the principal. The yield is split between
+/// the platform balance and the event's escrow by `yield_platform_bps`.
+fn settle_yield(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
+    let position = match storage::get_yield_position(env, event_id) {
+        Some(position) => position,
+        None => return Ok(0),
+    };
+    storage::remove_yield_position(env, event_id);
+    
+    let redeemed = payments::redeem_from_vault(env, event_id, &position.vault, position.principal);
+    let earned = redeemed - position.principal;
+    if earned < 0 {
+        return Err(LumentixError::InsufficientEscrow);
+    }
+    
+    let config = storage::get_config(env);
+    let (platform_share, escrow_share) = money::split_bps(earned, config.yield_platform_bps, config.rounding)?;
+    storage::record_deposit(env, earned);
+    storage::add_platform_balance(env, &storage::event_token(env, event_id), platform_share);
+    storage::add_escrow(env, event_id, escrow_share);
+    events::yield_redeemed(env, event_id, earned);
+    
+    Ok(earned)
+}
+
+/// Count a late refund request against its event's refund spike window and
+/// freeze the event's payouts once requests exceed `refund_spike_bps` of
+/// its tickets sold
+fn check_refund_spike(env: &Env, event_id: u64) {
+    let config = storage::get_config(env);
+    let now = env.ledger().timestamp();
+    let (started_at, requests) = match storage::get_refund_window(env, event_id) {
+        Some((started_at, requests)) if now - started_at < config.refund_spike_window_secs => {
+            (started_at, requests + 1)
+        }
+        _ => (now, 1),
+    };
+    storage::set_refund_window(env, event_id, started_at, requests);
+    
+    let tickets_sold = storage::get_stats(env, event_id).tickets_sold;
+    if storage::get_payout_freeze(env, event_id).is_none()
+        && requests as u64 * 10_000 > config.refund_spike_bps as u64 * tickets_sold as u64
+    {
+        storage::set_payout_freeze(env, event_id, now);
+        events::payouts_frozen(env, event_id, requests);
+    }
+}
+
+/// Count an event creation against the platform-wide and per-organizer
+/// limits of the current rate-limit window
+fn check_creation_rate(env: &Env, config: &PlatformConfig, organizer: &Address) -> Result<(), LumentixError> {
+    let window = env.ledger().sequence() / config.creation_window_ledgers;
+    let total = storage::get_window_creations(env, window);
+    let own = storage::get_organizer_window_creations(env, organizer, window);
+    if total >= config.max_creations_per_window || own >= config.max_creations_per_organizer {
+        return Err(LumentixError::OrganizerEventLimitReached);
+    }
+    
+    storage::set_window_creations(env, window, total + 1);
+    storage::set_organizer_window_creations(env, organizer, window, own + 1);
+    Ok(())
+}
+
+/// Check that a payment asset's token exists and reports the given decimals
+fn check_payment_asset(env: &Env, asset: &PaymentAsset) -> Result<(), LumentixError> {
+    match token::Client::new(env, &asset.token).try_decimals() {
+        Ok(Ok(decimals)) if decimals == asset.decimals => Ok(()),
+        _ => Err(LumentixError::WrongPaymentAsset),
+    }
+}
+
+/// Display an event's prices in its payment asset, using the token's symbol
+///
+/// Tokens without a usable symbol leave the display unset until the
+/// organizer names the currency with `set_currency_display`.
+fn set_asset_display(env: &Env, event_id: u64, asset: &PaymentAsset) {
+    match token::Client::new(env, &asset.token).try_symbol() {
+        Ok(Ok(symbol)) if validation::validate_currency_symbol(&symbol).is_ok() => {
+            storage::set_currency_display(env, event_id, &CurrencyDisplay { symbol, decimals: asset.decimals });
+        }
+        _ => storage::remove_currency_display(env, event_id),
+    }
+}
+
+/// Require that the caller is the initialized contract admin
+fn require_admin(env: &Env, caller: &Address) -> Result<(), LumentixError> {
+    caller.require_auth();
+    
+    if !storage::is_initialized(env) {
+        return Err(LumentixError::NotInitialized);
+    }
+    
+    if *caller != storage::get_admin(env) {
+        return Err(LumentixError::Unauthorized);
+    }
+    
+    Ok(())
+}
 >>>>>>
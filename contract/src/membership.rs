@@ -0,0 +1,24 @@
+//! Membership contracts consulted for member pricing
+//!
+//! An event can name an external membership contract, such as a club's
+//! membership NFT, and a price per membership tier. At purchase the contract
+//! is asked for the buyer's tier. A membership contract that fails or returns
+//! something unexpected is treated as reporting no membership, so it can
+//! never block a sale at the regular price.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface a membership contract exposes to events pricing on it
+#[contractclient(name = "MembershipClient")]
+pub trait Membership {
+    /// Membership tier held by `member`, or `None` for non-members
+    fn tier_of(env: Env, member: Address) -> Option<u32>;
+}
+
+/// Ask a membership contract for an account's tier
+pub fn tier_of(env: &Env, contract: &Address, member: &Address) -> Option<u32> {
+    match MembershipClient::new(env, contract).try_tier_of(member) {
+        Ok(Ok(tier)) => tier,
+        _ => None,
+    }
+}
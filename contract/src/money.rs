@@ -0,0 +1,162 @@
+//! Arithmetic on token amounts
+//!
+//! Amounts are `i128` in the asset's smallest unit and rates are basis
+//! points. Plain helpers truncate toward zero. Where an amount is divided
+//! between the platform and the organizer's side (purchase fees, resale
+//! shares and royalties, vault yield, consumed rent), the platform's
+//! `RoundingPolicy` decides which side keeps the fractional unit: one side's
+//! share is rounded up and the other's down, so the shares of a split never
+//! add up to more than the amount being split. Where an amount has to be paid
+//! out in full, the remainder goes to one named party: the seller on resale,
+//! the first fee destination on withdrawal, the event escrow on yield.
+//! Refunds return the amounts recorded at purchase and are never re-rounded.
+//! Overflow and division by zero fail with `InvalidAmount` instead of
+//! trapping.
+
+use crate::error::LumentixError;
+use crate::types::RoundingPolicy;
+
+/// Basis points in a whole
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// `amount * numerator / denominator`, truncated toward zero
+pub fn mul_div(amount: i128, numerator: i128, denominator: i128) -> Result<i128, LumentixError> {
+    amount
+        .checked_mul(numerator)
+        .and_then(|product| product.checked_div(denominator))
+        .ok_or(LumentixError::InvalidAmount)
+}
+
+/// `amount * numerator / denominator`, rounded away from zero when inexact
+pub fn mul_div_up(amount: i128, numerator: i128, denominator: i128) -> Result<i128, LumentixError> {
+    let product = amount.checked_mul(numerator).ok_or(LumentixError::InvalidAmount)?;
+    let quotient = product.checked_div(denominator).ok_or(LumentixError::InvalidAmount)?;
+    match product.checked_rem(denominator) {
+        Some(0) => Ok(quotient),
+        Some(rem) if (rem > 0) == (denominator > 0) => Ok(quotient + 1),
+        Some(_) => Ok(quotient - 1),
+        None => Err(LumentixError::InvalidAmount),
+    }
+}
+
+/// `bps` basis points of `amount`, truncated toward zero
+pub fn bps_of(amount: i128, bps: u32) -> Result<i128, LumentixError> {
+    mul_div(amount, bps as i128, BPS_DENOMINATOR)
+}
+
+/// The platform's `numerator / denominator` part of `amount`, rounded up
+/// under `TowardPlatform` and down under `TowardOrganizer`
+pub fn platform_part(
+    amount: i128,
+    numerator: i128,
+    denominator: i128,
+    policy: RoundingPolicy,
+) -> Result<i128, LumentixError> {
+    match policy {
+        RoundingPolicy::TowardPlatform => mul_div_up(amount, numerator, denominator),
+        RoundingPolicy::TowardOrganizer => mul_div(amount, numerator, denominator),
+    }
+}
+
+/// The platform's `bps` share of `amount`, rounded as `policy` directs
+pub fn platform_share(amount: i128, bps: u32, policy: RoundingPolicy) -> Result<i128, LumentixError> {
+    platform_part(amount, bps as i128, BPS_DENOMINATOR, policy)
+}
+
+/// The organizer's `bps` share of `amount`, rounded up under
+/// `TowardOrganizer` and down under `TowardPlatform`
+pub fn organizer_share(amount: i128, bps: u32, policy: RoundingPolicy) -> Result<i128, LumentixError> {
+    match policy {
+        RoundingPolicy::TowardOrganizer => mul_div_up(amount, bps as i128, BPS_DENOMINATOR),
+        RoundingPolicy::TowardPlatform => bps_of(amount, bps),
+    }
+}
+
+/// Split `amount` into the platform's `bps` share of it and the remainder
+pub fn split_bps(amount: i128, bps: u32, policy: RoundingPolicy) -> Result<(i128, i128), LumentixError> {
+    let share = platform_share(amount, bps, policy)?;
+    Ok((share, amount - share))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares_truncate_toward_zero() {
+        assert_eq!(bps_of(1_000, 250), Ok(25));
+        assert_eq!(bps_of(99, 250), Ok(2));
+        assert_eq!(bps_of(3, 3_333), Ok(0));
+        assert_eq!(bps_of(-99, 250), Ok(-2));
+        assert_eq!(bps_of(1_000, 10_000), Ok(1_000));
+        assert_eq!(mul_div(10, 1, 3), Ok(3));
+
+        // A split always accounts for the whole amount
+        assert_eq!(split_bps(101, 5_000, RoundingPolicy::TowardOrganizer), Ok((50, 51)));
+        assert_eq!(split_bps(0, 5_000, RoundingPolicy::TowardOrganizer), Ok((0, 0)));
+    }
+
+    #[test]
+    fn test_rounding_up_goes_away_from_zero() {
+        assert_eq!(mul_div_up(10, 1, 3), Ok(4));
+        assert_eq!(mul_div_up(9, 1, 3), Ok(3));
+        assert_eq!(mul_div_up(-10, 1, 3), Ok(-4));
+        assert_eq!(mul_div_up(10, 1, -3), Ok(-4));
+        assert_eq!(mul_div_up(0, 7, 3), Ok(0));
+        assert_eq!(mul_div_up(1, 1, 0), Err(LumentixError::InvalidAmount));
+        assert_eq!(mul_div_up(i128::MAX, 2, 1), Err(LumentixError::InvalidAmount));
+    }
+
+    #[test]
+    fn test_rounding_policy_picks_who_keeps_the_fraction() {
+        use RoundingPolicy::{TowardOrganizer, TowardPlatform};
+
+        // (amount, bps, platform share and organizer share toward the
+        // organizer, then toward the platform)
+        let cases = [
+            (1_000, 250, (25, 25), (25, 25)),
+            (99, 250, (2, 3), (3, 2)),
+            (1, 5_000, (0, 1), (1, 0)),
+            (3, 3_333, (0, 1), (1, 0)),
+            (0, 5_000, (0, 0), (0, 0)),
+            (12_345, 0, (0, 0), (0, 0)),
+            (12_345, 10_000, (12_345, 12_345), (12_345, 12_345)),
+        ];
+        for (amount, bps, organizer_favoured, platform_favoured) in cases {
+            assert_eq!(
+                (platform_share(amount, bps, TowardOrganizer), organizer_share(amount, bps, TowardOrganizer)),
+                (Ok(organizer_favoured.0), Ok(organizer_favoured.1))
+            );
+            assert_eq!(
+                (platform_share(amount, bps, TowardPlatform), organizer_share(amount, bps, TowardPlatform)),
+                (Ok(platform_favoured.0), Ok(platform_favoured.1))
+            );
+        }
+
+        assert_eq!(platform_part(10, 1, 3, TowardOrganizer), Ok(3));
+        assert_eq!(platform_part(10, 1, 3, TowardPlatform), Ok(4));
+        assert_eq!(split_bps(101, 5_000, TowardPlatform), Ok((51, 50)));
+    }
+
+    #[test]
+    fn test_opposite_shares_never_exceed_the_amount() {
+        for policy in [RoundingPolicy::TowardOrganizer, RoundingPolicy::TowardPlatform] {
+            for amount in [0, 1, 2, 3, 7, 99, 101, 9_999, 10_001] {
+                for platform_bps in [0, 1, 333, 2_500, 5_000, 6_667, 9_999, 10_000] {
+                    let organizer_bps = 10_000 - platform_bps;
+                    let platform = platform_share(amount, platform_bps, policy).unwrap();
+                    let organizer = organizer_share(amount, organizer_bps, policy).unwrap();
+                    assert!(platform + organizer <= amount);
+                    assert!(platform >= 0 && organizer >= 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflow_and_zero_denominator_fail() {
+        assert_eq!(bps_of(i128::MAX, 2), Err(LumentixError::InvalidAmount));
+        assert_eq!(mul_div(i128::MIN, -1, 1), Err(LumentixError::InvalidAmount));
+        assert_eq!(mul_div(1, 1, 0), Err(LumentixError::InvalidAmount));
+    }
+}
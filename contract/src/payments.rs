@@ -0,0 +1,90 @@
+//! Token movements for events with a configured payment asset
+//!
+//! Events without a payment asset keep accounting-only escrow: balances are
+//! tracked in storage and no tokens move. For events with one, payments are
+//! pulled from the buyer at purchase and every payout is transferred out in
+//! the same asset. Callers update the storage balances themselves, and do so
+//! before moving tokens: a transfer is always the last step of a call, so a
+//! token contract that calls back in can only observe fully settled state.
+//! Yield vault redemptions are the exception, as the amount returned is
+//! only known after the call: the position is removed before redeeming.
+
+use soroban_sdk::{contractclient, token, Address, Env, Vec};
+use crate::storage;
+use crate::types::TicketPayment;
+
+/// Pull a payment for an event from the payer into the contract
+pub fn collect(env: &Env, event_id: u64, from: &Address, amount: i128) {
+    if let Some(asset) = storage::get_payment_asset(env, event_id) {
+        token::Client::new(env, &asset.token).transfer(from, &env.current_contract_address(), &amount);
+    }
+}
+
+/// Pay an amount of an event's funds out of the contract
+pub fn pay_out(env: &Env, event_id: u64, to: &Address, amount: i128) {
+    if amount == 0 {
+        return;
+    }
+    if let Some(asset) = storage::get_payment_asset(env, event_id) {
+        token::Client::new(env, &asset.token).transfer(&env.current_contract_address(), to, &amount);
+    }
+}
+
+/// Pay an amount held in `token` out of the contract; `None` stands for
+/// accounting-only funds, which move no tokens
+pub fn pay_out_token(env: &Env, token: &Option<Address>, to: &Address, amount: i128) {
+    if amount == 0 {
+        return;
+    }
+    if let Some(token) = token {
+        token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    }
+}
+
+/// Return part of a ticket's payment in the token it was paid in, whatever
+/// the event's payment asset is now
+pub fn refund(env: &Env, payment: &TicketPayment, to: &Address, amount: i128) {
+    if amount == 0 {
+        return;
+    }
+    if let Some(token) = &payment.token {
+        token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    }
+}
+
+/// Interface of a whitelisted yield vault escrow can be parked in
+#[contractclient(name = "YieldVaultClient")]
+pub trait YieldVault {
+    /// Credit `depositor` with `amount` of `token` already transferred to
+    /// the vault
+    fn deposit(env: Env, depositor: Address, token: Address, amount: i128);
+    
+    /// Return `principal` of `token` plus any yield earned on it to
+    /// `depositor`, returning the total amount sent
+    fn withdraw(env: Env, depositor: Address, token: Address, principal: i128) -> i128;
+}
+
+/// Move an event's funds into a yield vault
+pub fn deposit_to_vault(env: &Env, event_id: u64, vault: &Address, amount: i128) {
+    if let Some(asset) = storage::get_payment_asset(env, event_id) {
+        let contract = env.current_contract_address();
+        token::Client::new(env, &asset.token).transfer(&contract, vault, &amount);
+        YieldVaultClient::new(env, vault).deposit(&contract, &asset.token, &amount);
+    }
+}
+
+/// Redeem an event's funds from a yield vault, returning the amount received
+pub fn redeem_from_vault(env: &Env, event_id: u64, vault: &Address, principal: i128) -> i128 {
+    match storage::get_payment_asset(env, event_id) {
+        Some(asset) => YieldVaultClient::new(env, vault)
+            .withdraw(&env.current_contract_address(), &asset.token, &principal),
+        None => principal,
+    }
+}
+
+/// Pay each `(recipient, amount)` pair out of an event's funds
+pub fn pay_out_all(env: &Env, event_id: u64, payees: &Vec<(Address, i128)>) {
+    for (to, amount) in payees.iter() {
+        pay_out(env, event_id, &to, amount);
+    }
+}
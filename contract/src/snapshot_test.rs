@@ -0,0 +1,138 @@
+//! Ledger snapshot fixture tests
+//!
+//! Builds a realistic ledger (several events with thousands of tickets sold)
+//! once, captures it as a `Snapshot`, and reloads it for each test so
+//! batch reads and TTL behaviour are exercised at production-like sizes.
+//! The contract has no storage migration entrypoint yet; when one lands, its
+//! tests should start from this fixture too.
+
+extern crate std;
+
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger, Snapshot};
+use soroban_sdk::xdr::ScAddress;
+use soroban_sdk::{Address, Env, String, TryFromVal, Vec};
+use std::sync::OnceLock;
+use crate::storage::DataKey;
+use crate::validation::MAX_BATCH_SIZE;
+use crate::{LumentixContract, LumentixContractClient, PlatformConfig};
+
+const EVENTS: u32 = 4;
+const TICKETS_PER_EVENT: u32 = 500;
+const PRICE: i128 = 1_000;
+
+/// A snapshot of a populated ledger and the IDs it contains
+struct Fixture {
+    snapshot: Snapshot,
+    contract_id: ScAddress,
+    event_ids: std::vec::Vec<u64>,
+    ticket_ids: std::vec::Vec<u64>,
+}
+
+fn fixture() -> &'static Fixture {
+    static FIXTURE: OnceLock<Fixture> = OnceLock::new();
+    FIXTURE.get_or_init(build_fixture)
+}
+
+fn build_fixture() -> Fixture {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    
+    let contract_id = env.register_contract(None, LumentixContract);
+    let client = LumentixContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &PlatformConfig::default(), &Vec::new(&env), &None);
+    
+    let name = String::from_str(&env, "Snapshot Event");
+    let mut event_ids = std::vec::Vec::new();
+    let mut ticket_ids = std::vec::Vec::new();
+    for _ in 0..EVENTS {
+        let organizer = Address::generate(&env);
+        let event_id = client.create_event(
+            &organizer, &name, &name, &name, &1_000, &2_000, &PRICE, &TICKETS_PER_EVENT,
+        );
+        for _ in 0..TICKETS_PER_EVENT {
+            let buyer = Address::generate(&env);
+            ticket_ids.push(client.purchase_ticket(&buyer, &event_id, &PRICE, &None, &None, &None));
+        }
+        event_ids.push(event_id);
+    }
+    
+    Fixture {
+        snapshot: env.to_snapshot(),
+        contract_id: (&contract_id).into(),
+        event_ids,
+        ticket_ids,
+    }
+}
+
+/// Reload the fixture ledger into a fresh environment
+fn load(fixture: &Fixture) -> (Env, LumentixContractClient<'static>) {
+    let env = Env::from_snapshot(fixture.snapshot.clone());
+    env.mock_all_auths();
+    let contract_id = Address::try_from_val(&env, &fixture.contract_id).unwrap();
+    env.register_contract(&contract_id, LumentixContract);
+    let client = LumentixContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+#[test]
+fn test_snapshot_reload_preserves_state() {
+    let fixture = fixture();
+    let (_env, client) = load(fixture);
+    
+    for event_id in &fixture.event_ids {
+        assert_eq!(client.get_event(event_id).tickets_sold, TICKETS_PER_EVENT);
+    }
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.deposits, PRICE * (EVENTS * TICKETS_PER_EVENT) as i128);
+}
+
+#[test]
+fn test_snapshot_ticket_pages_fit_default_budget() {
+    let fixture = fixture();
+    let (env, client) = load(fixture);
+    
+    let mut sold = std::vec![0u32; fixture.event_ids.len()];
+    for page in fixture.ticket_ids.chunks(MAX_BATCH_SIZE as usize) {
+        env.budget().reset_default();
+        let tickets = client.get_tickets(&Vec::from_slice(&env, page));
+        assert_eq!(tickets.len(), page.len() as u32);
+        
+        for ticket in tickets.iter() {
+            let ticket = ticket.expect("ticket missing from snapshot");
+            let index = fixture.event_ids.iter().position(|id| *id == ticket.event_id).unwrap();
+            sold[index] += 1;
+        }
+    }
+    assert!(sold.iter().all(|count| *count == TICKETS_PER_EVENT));
+    
+    env.budget().reset_default();
+    let events = client.get_events(&Vec::from_slice(&env, &fixture.event_ids));
+    assert!(events.iter().all(|event| event.is_some()));
+}
+
+#[test]
+fn test_snapshot_reads_extend_ticket_ttl() {
+    let fixture = fixture();
+    let (env, client) = load(fixture);
+    let ttl = |ticket_id: u64| {
+        env.as_contract(&client.address, || {
+            env.storage().persistent().get_ttl(&DataKey::Ticket(ticket_id))
+        })
+    };
+    
+    // A month passes without the tickets being touched
+    env.ledger().with_mut(|li| li.sequence_number += 30 * 17_280);
+    let read = fixture.ticket_ids[0];
+    let unread = fixture.ticket_ids[fixture.ticket_ids.len() - 1];
+    let stale = ttl(read);
+    assert!(stale > 0);
+    assert_eq!(ttl(unread), stale);
+    
+    client.get_tickets(&Vec::from_array(&env, [read]));
+    
+    // Reading a ticket keeps it live; untouched tickets keep ageing towards archival
+    assert!(ttl(read) > stale);
+    assert_eq!(ttl(unread), stale);
+}
@@ -0,0 +1,242 @@
+//! Property-based state machine tests
+//!
+//! Generates random sequences of lifecycle, sales, transfer, refund and
+//! check-in operations, runs them against the contract and a small model of it, and
+//! checks the contract's invariants after every step.
+
+extern crate std;
+
+use proptest::prelude::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Bytes, Env, String};
+use std::vec::Vec;
+use crate::{storage, CancellationReason, LumentixContractClient, LumentixError, OfferTarget, PlatformConfig, TicketStatus};
+
+const MAX_EVENTS: usize = 4;
+const MAX_TICKETS: usize = 16;
+const BUYERS: usize = 3;
+const START_TIME: u64 = 1_000;
+const END_TIME: u64 = 2_000;
+const PRICE: i128 = 1_000;
+
+#[derive(Clone, Debug)]
+pub enum Op {
+    Create { capacity: u32, draft: bool },
+    Publish { event: usize },
+    Purchase { event: usize, buyer: usize },
+    Offer { ticket: usize, buyer: usize },
+    AcceptOffer { offer: usize },
+    Swap { ticket_a: usize, ticket_b: usize },
+    Refund { ticket: usize },
+    Cancel { event: usize },
+    Use { ticket: usize },
+    Complete { event: usize },
+    Release { event: usize },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        2 => (1u32..4, any::<bool>()).prop_map(|(capacity, draft)| Op::Create { capacity, draft }),
+        1 => (0..MAX_EVENTS).prop_map(|event| Op::Publish { event }),
+        5 => (0..MAX_EVENTS, 0..BUYERS).prop_map(|(event, buyer)| Op::Purchase { event, buyer }),
+        5 => transfer_op(),
+        2 => (0..MAX_TICKETS).prop_map(|ticket| Op::Refund { ticket }),
+        1 => (0..MAX_EVENTS).prop_map(|event| Op::Cancel { event }),
+        2 => (0..MAX_TICKETS).prop_map(|ticket| Op::Use { ticket }),
+        1 => (0..MAX_EVENTS).prop_map(|event| Op::Complete { event }),
+        1 => (0..MAX_EVENTS).prop_map(|event| Op::Release { event }),
+    ]
+}
+
+/// Resale and swap operations, grouped so `op` stays within the ten arms
+/// `prop_oneof!` supports without `vec!`
+fn transfer_op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        2 => (0..MAX_TICKETS, 0..BUYERS).prop_map(|(ticket, buyer)| Op::Offer { ticket, buyer }),
+        2 => (0..MAX_TICKETS).prop_map(|offer| Op::AcceptOffer { offer }),
+        1 => (0..MAX_TICKETS, 0..MAX_TICKETS).prop_map(|(ticket_a, ticket_b)| Op::Swap { ticket_a, ticket_b }),
+    ]
+}
+
+struct ModelEvent {
+    id: u64,
+    capacity: u32,
+    sold: u32,
+}
+
+struct ModelTicket {
+    id: u64,
+    event: usize,
+    owner: Address,
+    refunded: bool,
+}
+
+struct ModelOffer {
+    id: u64,
+    ticket: usize,
+    buyer: Address,
+    open: bool,
+}
+
+/// Run a sequence of operations, panicking on the first invariant violation
+pub fn run(ops: &[Op]) {
+    let env = Env::default();
+    let (admin, client) = crate::testutils::setup_contract(&env);
+    let config = PlatformConfig {
+        platform_fee_bps: 500,
+        ..client.get_config()
+    };
+    crate::testutils::apply_config(&env, &client, &admin, &config);
+    
+    let organizer = Address::generate(&env);
+    let buyers: Vec<Address> = (0..BUYERS).map(|_| Address::generate(&env)).collect();
+    let mut events: Vec<ModelEvent> = Vec::new();
+    let mut tickets: Vec<ModelTicket> = Vec::new();
+    let mut offers: Vec<ModelOffer> = Vec::new();
+    
+    for op in ops {
+        match *op {
+            Op::Create { capacity, draft } => {
+                if events.len() == MAX_EVENTS {
+                    continue;
+                }
+                let name = String::from_str(&env, "Event");
+                let id = if draft {
+                    client.create_draft_event(&organizer, &name, &name, &name, &START_TIME, &END_TIME, &PRICE, &capacity)
+                } else {
+                    client.create_event(&organizer, &name, &name, &name, &START_TIME, &END_TIME, &PRICE, &capacity)
+                };
+                events.push(ModelEvent { id, capacity, sold: 0 });
+            }
+            Op::Publish { event } => {
+                if let Some(event) = events.get(event) {
+                    let _ = client.try_publish_event(&organizer, &event.id);
+                }
+            }
+            Op::Purchase { event, buyer } => {
+                let Some(model) = events.get_mut(event) else { continue };
+                let result = client.try_purchase_ticket(&buyers[buyer], &model.id, &PRICE, &None, &None, &None);
+                match result {
+                    Ok(Ok(id)) => {
+                        assert!(model.sold < model.capacity, "sold past capacity");
+                        model.sold += 1;
+                        tickets.push(ModelTicket { id, event, owner: buyers[buyer].clone(), refunded: false });
+                    }
+                    Err(Ok(LumentixError::EventSoldOut)) => assert_eq!(model.sold, model.capacity),
+                    Err(Ok(_)) => {}
+                    other => panic!("unexpected purchase result {:?}", other),
+                }
+            }
+            Op::Offer { ticket, buyer } => {
+                let Some(model) = tickets.get(ticket) else { continue };
+                let result = client.try_place_offer(&buyers[buyer], &OfferTarget::Ticket(model.id), &PRICE);
+                if let Ok(Ok(id)) = result {
+                    assert_ne!(model.owner, buyers[buyer], "offer placed on own ticket");
+                    offers.push(ModelOffer { id, ticket, buyer: buyers[buyer].clone(), open: true });
+                }
+            }
+            Op::AcceptOffer { offer } => {
+                let Some(offer) = offers.get_mut(offer) else { continue };
+                let model = &mut tickets[offer.ticket];
+                let result = client.try_accept_offer(&model.owner, &offer.id, &model.id);
+                if let Ok(Ok(())) = result {
+                    assert!(offer.open, "offer accepted twice");
+                    assert!(!model.refunded, "refunded ticket resold");
+                    offer.open = false;
+                    model.owner = offer.buyer.clone();
+                }
+            }
+            Op::Swap { ticket_a, ticket_b } => {
+                let (Some(a), Some(b)) = (tickets.get(ticket_a), tickets.get(ticket_b)) else { continue };
+                let Ok(Ok(swap_id)) = client.try_propose_swap(&a.owner, &a.id, &b.id, &0) else { continue };
+                if let Ok(Ok(())) = client.try_accept_swap(&b.owner, &swap_id) {
+                    assert!(!a.refunded && !b.refunded, "refunded ticket swapped");
+                    let owner_a = a.owner.clone();
+                    tickets[ticket_a].owner = tickets[ticket_b].owner.clone();
+                    tickets[ticket_b].owner = owner_a;
+                } else {
+                    let result = client.try_cancel_swap(&a.owner, &swap_id);
+                    assert_eq!(result, Ok(Ok(())), "pending swap could not be cancelled");
+                }
+            }
+            Op::Refund { ticket } => {
+                let Some(model) = tickets.get_mut(ticket) else { continue };
+                let result = client.try_refund_ticket(&model.id, &model.owner);
+                if let Ok(Ok(())) = result {
+                    assert!(!model.refunded, "ticket refunded twice");
+                    model.refunded = true;
+                }
+            }
+            Op::Cancel { event } => {
+                if let Some(event) = events.get(event) {
+                    let _ = client.try_cancel_event(&organizer, &event.id, &CancellationReason::Other, &None);
+                }
+            }
+            Op::Use { ticket } => {
+                if let Some(model) = tickets.get(ticket) {
+                    let result = client.try_use_ticket(&model.id, &organizer, &Bytes::new(&env));
+                    if let Ok(Ok(_)) = result {
+                        assert!(!model.refunded, "refunded ticket admitted");
+                    }
+                }
+            }
+            Op::Complete { event } => {
+                if let Some(event) = events.get(event) {
+                    env.ledger().with_mut(|li| li.timestamp = END_TIME);
+                    let _ = client.try_complete_event(&organizer, &event.id);
+                    env.ledger().with_mut(|li| li.timestamp = 0);
+                }
+            }
+            Op::Release { event } => {
+                if let Some(event) = events.get(event) {
+                    let _ = client.try_release_escrow(&organizer, &event.id);
+                }
+            }
+        }
+        
+        check_invariants(&env, &client, &events, &tickets);
+    }
+}
+
+fn check_invariants(
+    env: &Env,
+    client: &LumentixContractClient,
+    events: &[ModelEvent],
+    tickets: &[ModelTicket],
+) {
+    let mut escrow = 0;
+    for (index, model) in events.iter().enumerate() {
+        let event = client.get_event(&model.id);
+        assert!(event.tickets_sold <= event.max_tickets, "capacity exceeded");
+        assert_eq!(event.tickets_sold, model.sold);
+        
+        let refunded = tickets.iter().filter(|t| t.event == index && t.refunded).count();
+        assert_eq!(client.get_event_stats(&model.id).tickets_refunded as usize, refunded);
+        
+        escrow += env.as_contract(&client.address, || storage::get_escrow(env, model.id).unwrap());
+    }
+    
+    for model in tickets {
+        let ticket = client.get_ticket(&model.id);
+        assert_eq!(ticket.status == TicketStatus::Refunded, model.refunded);
+        assert_eq!(ticket.owner, model.owner, "ticket owner out of sync");
+    }
+    
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.escrow, escrow, "escrow total out of sync with events");
+    assert_eq!(
+        totals.escrow + totals.platform_balance + totals.offers_held,
+        totals.deposits - totals.withdrawals - totals.refunds,
+        "escrow conservation violated"
+    );
+    assert!(totals.reserved_fees <= totals.platform_balance);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn random_operation_sequences_preserve_invariants(ops in prop::collection::vec(op(), 1..40)) {
+        run(&ops);
+    }
+}
@@ -1,122 +1,1870 @@
-use soroban_sdk::{Address, Env};
+//! Storage layout
+//!
+//! Instance storage holds small, global configuration that every call needs
+//! (initialization flag, admin, ID counters). It shares a single TTL with the
+//! contract instance and is extended on every write.
+//!
+//! Persistent storage holds per-event and per-ticket records (events, tickets,
+//! escrow balances, statistics). Each entry carries its own TTL, extended
+//! whenever the entry is written or read, so records of live events never
+//! expire while abandoned ones eventually stop costing rent.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec};
 use crate::error::LumentixError;
-use crate::types::{Event, Ticket};
+use crate::types::{
+    AddOn, Announcement, AttendanceRecord, BonusCapacity, BundleDiscount, CapacityWave, CurrencyDisplay, Curtailment, Event, EventCancellation, EventStats,
+    FailedCheckIn, FeaturedEvent, FeeChange, FeeDestination, FeeExemptTarget, FeeTier, GateDevice, HistoryEntry, ImportAllowance, LateRefundRequest, MemberPricing, Offer, OrganizerDefault, PaymentAsset,
+    PlatformConfig, PolicyVersion, Promotion, RatingSummary, Reconciliation, RecoveryRequest, RefundReason,
+    RefundReceipt, RentDeposit, ResaleSplit, Review, SalesWindow, Sponsorship, Swap, TaxTerms, Ticket,
+    TicketAddOns, TicketLoan, TicketPayment, WillCallPickup, YieldPosition,
+};
+
+// TTL policy, expressed in ledgers (~5 seconds each)
+const DAY_IN_LEDGERS: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 7 * DAY_IN_LEDGERS;
+
+/// Number of failed check-in attempts retained per event
+const MAX_FAILED_CHECK_INS: u32 = 50;
+
+/// Number of ticket history entries retained per account
+const MAX_HISTORY_ENTRIES: u32 = 200;
+
+/// Typed storage keys
+///
+/// Variants without a payload live in instance storage; variants keyed by an
+/// ID live in persistent storage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // Instance storage
+    Initialized,
+    InitializedAt,
+    Admin,
+    EventIdCounter,
+    TicketIdCounter,
+    SponsorshipIdCounter,
+    Config,
+    PlatformBalance,
+    FeeDestinations,
+    FeeTiers,
+    TotalDeposits,
+    TotalWithdrawals,
+    TotalRefunds,
+    TotalEscrow,
+    ReservedFees,
+    // Persistent storage
+    Event(u64),
+    Ticket(u64),
+    Escrow(u64),
+    Stats(u64),
+    RefundBreakdown(u64),
+    CheckInHash(u64),
+    TermsHash(u64),
+    PurchaseMemo(u64),
+    PolicyVersions(u64),
+    TicketPolicyVersion(u64),
+    LateRefund(u64),
+    RefundReceipt(u64),
+    Curtailment(u64),
+    PaymentAsset(u64),
+    ProRataRefund(u64),
+    WaitlistEnabled(u64),
+    DepositBps(u64),
+    DepositsOwed(u64),
+    DepositReturn(u64),
+    MaxNoShowBps(u64),
+    Review(u64),
+    ReviewComment(u64),
+    EventRating(u64),
+    Sponsorship(u64),
+    EventSponsorships(u64),
+    AddOns(u64),
+    TicketAddOns(u64),
+    Waitlist(u64),
+    FailedCheckIns(u64),
+    VerifiedOrganizer(Address),
+    OrganizerActiveEvents(Address),
+    FeeExempt(FeeExemptTarget),
+    OrganizerVolume(Address),
+    Attendance(Address),
+    OrganizerRating(Address),
+}
+
+/// Instance storage keys added after `DataKey` reached the 50-variant limit
+/// of `#[contracttype]` enums
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InstanceKey {
+    DefaultPaymentAsset,
+    OfferIdCounter,
+    SwapIdCounter,
+    CreationWindow,
+    Featured,
+    FeeChange,
+    AssetBalance(Option<Address>),
+    AssetReserved(Option<Address>),
+    TotalOffersHeld,
+}
+
+/// Persistent per-ticket storage keys added after `DataKey` reached the
+/// 50-variant limit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TicketKey {
+    Payment(u64),
+    Loan(u64),
+    FieldValues(u64),
+    Pickup(u64),
+    Imported(u64),
+    Redemption(u64),
+}
+
+/// Persistent per-account storage keys added after `DataKey` reached the
+/// 50-variant limit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountKey {
+    History(Address),
+    Holdings(Address, u64),
+    CheckInNonce(Address, u64),
+}
+
+/// Persistent secondary-market storage keys
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResaleKey {
+    Offer(u64),
+    Swap(u64),
+}
+
+/// Persistent per-organizer storage keys added after `DataKey` reached the
+/// 50-variant limit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OrganizerKey {
+    CreationWindow(Address),
+    Defaults(Address),
+    Events(Address),
+}
+
+/// Persistent platform-wide storage keys added after `DataKey` reached the
+/// 50-variant limit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PlatformKey {
+    YieldVault(Address),
+    TaggedEvents(Symbol),
+    Promotion(u32),
+}
+
+/// Persistent per-event storage keys added after `DataKey` reached the
+/// 50-variant limit of `#[contracttype]` enums
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventKey {
+    TaxTerms(u64),
+    TaxCollected(u64),
+    CapacityWaves(u64),
+    SalesCutoff(u64),
+    Cancellation(u64),
+    CancellationDetail(u64),
+    Recovery(u64),
+    TierSalesWindows(u64),
+    BonusCapacity(u64),
+    ResaleSplit(u64),
+    Attestation(u64),
+    RefundWindow(u64),
+    PayoutFreeze(u64),
+    YieldPosition(u64),
+    Tags(u64),
+    Followers(u64),
+    OversellBps(u64),
+    Standby(u64),
+    TierEscrow(u64),
+    Announcements(u64),
+    TicketFields(u64),
+    BundleDiscounts(u64),
+    MemberPricing(u64),
+    RentDeposit(u64),
+    TicketDigest(u64),
+    WillCall(u64),
+    ImportAllowance(u64),
+    GateDevices(u64),
+    CurrencyDisplay(u64),
+    RefundablePrincipal(u64),
+    Transferable(u64),
+}
+
+/// Extend the TTL of the contract instance and its instance storage
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Write an instance entry and extend the instance TTL
+fn set_instance<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(env: &Env, key: &K, value: &V) {
+    env.storage().instance().set(key, value);
+    extend_instance_ttl(env);
+}
+
+/// Read an instance entry
+fn get_instance<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>>(env: &Env, key: &K) -> Option<V> {
+    env.storage().instance().get(key)
+}
+
+/// Extend the TTL of an existing persistent entry
+fn extend_persistent_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
 
-// Storage keys
-const INITIALIZED: &str = "INIT";
-const ADMIN: &str = "ADMIN";
-const EVENT_ID_COUNTER: &str = "EVENT_CTR";
-const TICKET_ID_COUNTER: &str = "TICKET_CTR";
-const EVENT_PREFIX: &str = "EVENT_";
-const TICKET_PREFIX: &str = "TICKET_";
-const ESCROW_PREFIX: &str = "ESCROW_";
+/// Write a persistent entry and extend its TTL
+fn set_persistent<K: IntoVal<Env, Val>, V: IntoVal<Env, Val>>(env: &Env, key: &K, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent_ttl(env, key);
+}
+
+/// Read a persistent entry, extending its TTL if it exists
+fn get_persistent<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>>(env: &Env, key: &K) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent_ttl(env, key);
+    }
+    value
+}
+
+/// Remove a persistent entry
+fn remove_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage().persistent().remove(key);
+}
 
 /// Check if contract is initialized
 pub fn is_initialized(env: &Env) -> bool {
-    env.storage().instance().has(&INITIALIZED)
+    env.storage().instance().has(&DataKey::Initialized)
 }
 
 /// Mark contract as initialized
 pub fn set_initialized(env: &Env) {
-    env.storage().instance().set(&INITIALIZED, &true);
+    set_instance(env, &DataKey::Initialized, &true);
+}
+
+/// Record the initialization timestamp
+pub fn set_initialized_at(env: &Env, timestamp: u64) {
+    set_instance(env, &DataKey::InitializedAt, &timestamp);
+}
+
+/// Get the initialization timestamp
+pub fn get_initialized_at(env: &Env) -> u64 {
+    get_instance(env, &DataKey::InitializedAt).unwrap_or(0)
 }
 
 /// Set admin address
 pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&ADMIN, admin);
+    set_instance(env, &DataKey::Admin, admin);
 }
 
 /// Get admin address
 pub fn get_admin(env: &Env) -> Address {
-    env.storage().instance().get(&ADMIN).unwrap()
+    get_instance(env, &DataKey::Admin).unwrap()
+}
+
+/// Get platform configuration, falling back to defaults
+///
+/// A scheduled fee change that has come into effect overrides the stored
+/// `platform_fee_bps`.
+pub fn get_config(env: &Env) -> PlatformConfig {
+    let mut config: PlatformConfig = get_instance(env, &DataKey::Config).unwrap_or_default();
+    if let Some(change) = get_due_fee_change(env) {
+        config.platform_fee_bps = change.fee_bps;
+    }
+    config
+}
+
+/// Set platform configuration; a scheduled fee change already in effect is
+/// folded into it
+pub fn set_config(env: &Env, config: &PlatformConfig) {
+    if get_due_fee_change(env).is_some() {
+        remove_fee_change(env);
+    }
+    set_instance(env, &DataKey::Config, config);
+}
+
+/// Get the scheduled platform fee change if it has not taken effect yet
+pub fn get_pending_fee_change(env: &Env) -> Option<FeeChange> {
+    get_instance::<_, FeeChange>(env, &InstanceKey::FeeChange)
+        .filter(|change| env.ledger().timestamp() < change.effective_at)
+}
+
+/// Get the scheduled platform fee change if it has come into effect
+fn get_due_fee_change(env: &Env) -> Option<FeeChange> {
+    get_instance::<_, FeeChange>(env, &InstanceKey::FeeChange)
+        .filter(|change| env.ledger().timestamp() >= change.effective_at)
+}
+
+/// Schedule a platform fee change, replacing any earlier one; a change
+/// already in effect is first folded into the stored configuration
+pub fn set_fee_change(env: &Env, change: &FeeChange) {
+    if get_due_fee_change(env).is_some() {
+        set_instance(env, &DataKey::Config, &get_config(env));
+    }
+    set_instance(env, &InstanceKey::FeeChange, change);
+}
+
+/// Remove the scheduled platform fee change
+pub fn remove_fee_change(env: &Env) {
+    env.storage().instance().remove(&InstanceKey::FeeChange);
+}
+
+/// Get the payment asset new events are created with, if any
+pub fn get_default_payment_asset(env: &Env) -> Option<PaymentAsset> {
+    get_instance(env, &InstanceKey::DefaultPaymentAsset)
+}
+
+/// Set the payment asset new events are created with
+pub fn set_default_payment_asset(env: &Env, asset: &PaymentAsset) {
+    set_instance(env, &InstanceKey::DefaultPaymentAsset, asset);
+}
+
+/// Get the platform fees collected and not yet withdrawn, across all assets
+pub fn get_platform_balance(env: &Env) -> i128 {
+    get_instance(env, &DataKey::PlatformBalance).unwrap_or(0)
+}
+
+/// Get the platform fees held in one token; `None` is the accounting-only
+/// balance of events without a payment asset
+pub fn get_asset_balance(env: &Env, token: &Option<Address>) -> i128 {
+    get_instance(env, &InstanceKey::AssetBalance(token.clone())).unwrap_or(0)
+}
+
+/// Add fees collected in `token` to the platform balance
+pub fn add_platform_balance(env: &Env, token: &Option<Address>, amount: i128) {
+    let balance = get_platform_balance(env);
+    set_instance(env, &DataKey::PlatformBalance, &(balance + amount));
+    let held = get_asset_balance(env, token);
+    set_instance(env, &InstanceKey::AssetBalance(token.clone()), &(held + amount));
+}
+
+/// Deduct fees held in `token` from the platform balance
+pub fn deduct_platform_balance(env: &Env, token: &Option<Address>, amount: i128) -> Result<(), LumentixError> {
+    let held = get_asset_balance(env, token);
+    
+    if held < amount {
+        return Err(LumentixError::InsufficientPlatformBalance);
+    }
+    
+    set_instance(env, &InstanceKey::AssetBalance(token.clone()), &(held - amount));
+    let balance = get_platform_balance(env);
+    set_instance(env, &DataKey::PlatformBalance, &(balance - amount));
+    Ok(())
+}
+
+/// Get one of the contract-wide money totals
+fn get_total<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> i128 {
+    get_instance(env, key).unwrap_or(0)
+}
+
+/// Adjust one of the contract-wide money totals
+fn adjust_total<K: IntoVal<Env, Val>>(env: &Env, key: &K, delta: i128) {
+    let total = get_total(env, key);
+    set_instance(env, key, &(total + delta));
+}
+
+/// Record a buyer payment entering the contract
+pub fn record_deposit(env: &Env, amount: i128) {
+    adjust_total(env, &DataKey::TotalDeposits, amount);
+}
+
+/// Record funds paid out to an organizer or fee destination
+pub fn record_withdrawal(env: &Env, amount: i128) {
+    adjust_total(env, &DataKey::TotalWithdrawals, amount);
+}
+
+/// Record funds returned to a ticket holder or bidder
+pub fn record_refund_paid(env: &Env, amount: i128) {
+    adjust_total(env, &DataKey::TotalRefunds, amount);
+}
+
+/// Record an offer amount entering the contract, held for its buyer
+pub fn hold_offer_funds(env: &Env, amount: i128) {
+    record_deposit(env, amount);
+    adjust_total(env, &InstanceKey::TotalOffersHeld, amount);
+}
+
+/// Release a settled or cancelled offer's amount from the funds held
+pub fn release_offer_funds(env: &Env, amount: i128) {
+    adjust_total(env, &InstanceKey::TotalOffersHeld, -amount);
+}
+
+/// Get the platform balance held back to cover fee refunds, across all assets
+pub fn get_reserved_fees(env: &Env) -> i128 {
+    get_total(env, &DataKey::ReservedFees)
+}
+
+/// Get the part of the platform balance in `token` held back for refunds
+pub fn get_asset_reserved(env: &Env, token: &Option<Address>) -> i128 {
+    get_instance(env, &InstanceKey::AssetReserved(token.clone())).unwrap_or(0)
+}
+
+/// Adjust the reservations both in total and in the asset of an event
+fn adjust_reserved(env: &Env, event_id: u64, delta: i128) {
+    adjust_total(env, &DataKey::ReservedFees, delta);
+    let token = event_token(env, event_id);
+    let reserved = get_asset_reserved(env, &token);
+    set_instance(env, &InstanceKey::AssetReserved(token), &(reserved + delta));
+}
+
+/// Reserve a ticket's platform fee until its event settles
+pub fn reserve_fee(env: &Env, event_id: u64, amount: i128) {
+    adjust_reserved(env, event_id, amount);
+    let mut stats = get_stats(env, event_id);
+    stats.refundable_fees += amount;
+    set_stats(env, event_id, &stats);
+}
+
+/// Release the fee reservation of a single refunded ticket
+pub fn release_fee(env: &Env, event_id: u64, amount: i128) {
+    adjust_reserved(env, event_id, -amount);
+    let mut stats = get_stats(env, event_id);
+    stats.refundable_fees -= amount;
+    set_stats(env, event_id, &stats);
+}
+
+/// Hold back an event's prepaid storage rent in the platform balance until
+/// consumed
+pub fn reserve_rent(env: &Env, event_id: u64, amount: i128) {
+    adjust_reserved(env, event_id, amount);
+}
+
+/// Release prepaid storage rent that was consumed or returned
+pub fn release_rent(env: &Env, event_id: u64, amount: i128) {
+    adjust_reserved(env, event_id, -amount);
+}
+
+/// Release all fee reservations of a settled event
+pub fn release_event_fees(env: &Env, event_id: u64) {
+    let stats = get_stats(env, event_id);
+    release_fee(env, event_id, stats.refundable_fees);
+}
+
+/// Get the contract-wide money totals
+pub fn get_reconciliation(env: &Env) -> Reconciliation {
+    Reconciliation {
+        deposits: get_total(env, &DataKey::TotalDeposits),
+        withdrawals: get_total(env, &DataKey::TotalWithdrawals),
+        refunds: get_total(env, &DataKey::TotalRefunds),
+        escrow: get_total(env, &DataKey::TotalEscrow),
+        platform_balance: get_platform_balance(env),
+        reserved_fees: get_reserved_fees(env),
+        offers_held: get_total(env, &InstanceKey::TotalOffersHeld),
+    }
+}
+
+/// Get the configured platform fee destinations
+pub fn get_fee_destinations(env: &Env) -> Option<Vec<FeeDestination>> {
+    get_instance(env, &DataKey::FeeDestinations)
+}
+
+/// Set the platform fee destinations
+pub fn set_fee_destinations(env: &Env, destinations: &Vec<FeeDestination>) {
+    set_instance(env, &DataKey::FeeDestinations, destinations);
+}
+
+/// Get the volume-based fee schedule, lowest threshold first
+pub fn get_fee_tiers(env: &Env) -> Vec<FeeTier> {
+    get_instance(env, &DataKey::FeeTiers).unwrap_or(Vec::new(env))
+}
+
+/// Set the volume-based fee schedule
+pub fn set_fee_tiers(env: &Env, tiers: &Vec<FeeTier>) {
+    set_instance(env, &DataKey::FeeTiers, tiers);
+}
+
+/// Get an organizer's lifetime settled volume
+pub fn get_organizer_volume(env: &Env, organizer: &Address) -> i128 {
+    get_persistent(env, &DataKey::OrganizerVolume(organizer.clone())).unwrap_or(0)
+}
+
+/// Add settled escrow to an organizer's lifetime volume
+pub fn add_organizer_volume(env: &Env, organizer: &Address, amount: i128) {
+    let volume = get_organizer_volume(env, organizer);
+    set_persistent(env, &DataKey::OrganizerVolume(organizer.clone()), &(volume + amount));
+}
+
+/// Check whether an organizer has been verified by the admin
+pub fn is_verified_organizer(env: &Env, organizer: &Address) -> bool {
+    get_persistent(env, &DataKey::VerifiedOrganizer(organizer.clone())).unwrap_or(false)
+}
+
+/// Set an organizer's verified flag
+pub fn set_verified_organizer(env: &Env, organizer: &Address, verified: bool) {
+    set_persistent(env, &DataKey::VerifiedOrganizer(organizer.clone()), &verified);
+}
+
+/// Check whether an event or organizer is exempt from the platform fee
+pub fn is_fee_exempt(env: &Env, target: &FeeExemptTarget) -> bool {
+    get_persistent(env, &DataKey::FeeExempt(target.clone())).unwrap_or(false)
+}
+
+/// Set or clear a platform fee exemption
+pub fn set_fee_exempt(env: &Env, target: &FeeExemptTarget, exempt: bool) {
+    let key = DataKey::FeeExempt(target.clone());
+    if exempt {
+        set_persistent(env, &key, &true);
+    } else {
+        remove_persistent(env, &key);
+    }
+}
+
+/// Get the number of active events owned by an organizer
+pub fn get_organizer_active_events(env: &Env, organizer: &Address) -> u32 {
+    get_persistent(env, &DataKey::OrganizerActiveEvents(organizer.clone())).unwrap_or(0)
+}
+
+/// Increment an organizer's active event count
+pub fn increment_organizer_active_events(env: &Env, organizer: &Address) {
+    let count = get_organizer_active_events(env, organizer) + 1;
+    set_persistent(env, &DataKey::OrganizerActiveEvents(organizer.clone()), &count);
+}
+
+/// Decrement an organizer's active event count
+pub fn decrement_organizer_active_events(env: &Env, organizer: &Address) {
+    let count = get_organizer_active_events(env, organizer).saturating_sub(1);
+    set_persistent(env, &DataKey::OrganizerActiveEvents(organizer.clone()), &count);
 }
 
 /// Get next event ID
 pub fn get_next_event_id(env: &Env) -> u64 {
-    env.storage()
-        .instance()
-        .get(&EVENT_ID_COUNTER)
-        .unwrap_or(1)
+    get_instance(env, &DataKey::EventIdCounter).unwrap_or(1)
 }
 
 /// Increment event ID counter
 pub fn increment_event_id(env: &Env) {
     let next_id = get_next_event_id(env) + 1;
-    env.storage().instance().set(&EVENT_ID_COUNTER, &next_id);
+    set_instance(env, &DataKey::EventIdCounter, &next_id);
 }
 
 /// Get next ticket ID
 pub fn get_next_ticket_id(env: &Env) -> u64 {
-    env.storage()
-        .instance()
-        .get(&TICKET_ID_COUNTER)
-        .unwrap_or(1)
+    get_instance(env, &DataKey::TicketIdCounter).unwrap_or(1)
 }
 
 /// Increment ticket ID counter
 pub fn increment_ticket_id(env: &Env) {
     let next_id = get_next_ticket_id(env) + 1;
-    env.storage().instance().set(&TICKET_ID_COUNTER, &next_id);
+    set_instance(env, &DataKey::TicketIdCounter, &next_id);
+}
+
+/// Get next sponsorship ID
+pub fn get_next_sponsorship_id(env: &Env) -> u64 {
+    get_instance(env, &DataKey::SponsorshipIdCounter).unwrap_or(1)
+}
+
+/// Increment sponsorship ID counter
+pub fn increment_sponsorship_id(env: &Env) {
+    let next_id = get_next_sponsorship_id(env) + 1;
+    set_instance(env, &DataKey::SponsorshipIdCounter, &next_id);
+}
+
+/// Get next resale offer ID
+pub fn get_next_offer_id(env: &Env) -> u64 {
+    get_instance(env, &InstanceKey::OfferIdCounter).unwrap_or(1)
+}
+
+/// Increment resale offer ID counter
+pub fn increment_offer_id(env: &Env) {
+    let next_id = get_next_offer_id(env) + 1;
+    set_instance(env, &InstanceKey::OfferIdCounter, &next_id);
+}
+
+/// Get next ticket swap ID
+pub fn get_next_swap_id(env: &Env) -> u64 {
+    get_instance(env, &InstanceKey::SwapIdCounter).unwrap_or(1)
+}
+
+/// Increment ticket swap ID counter
+pub fn increment_swap_id(env: &Env) {
+    let next_id = get_next_swap_id(env) + 1;
+    set_instance(env, &InstanceKey::SwapIdCounter, &next_id);
+}
+
+/// Get how many events were created platform-wide in rate-limit `window`
+pub fn get_window_creations(env: &Env, window: u32) -> u32 {
+    let stored: Option<(u32, u32)> = get_instance(env, &InstanceKey::CreationWindow);
+    stored.filter(|(stored_window, _)| *stored_window == window).map_or(0, |(_, count)| count)
+}
+
+/// Set how many events were created platform-wide in rate-limit `window`
+pub fn set_window_creations(env: &Env, window: u32, count: u32) {
+    set_instance(env, &InstanceKey::CreationWindow, &(window, count));
+}
+
+/// Get how many events an organizer created in rate-limit `window`
+pub fn get_organizer_window_creations(env: &Env, organizer: &Address, window: u32) -> u32 {
+    let stored: Option<(u32, u32)> = get_persistent(env, &OrganizerKey::CreationWindow(organizer.clone()));
+    stored.filter(|(stored_window, _)| *stored_window == window).map_or(0, |(_, count)| count)
+}
+
+/// Set how many events an organizer created in rate-limit `window`
+pub fn set_organizer_window_creations(env: &Env, organizer: &Address, window: u32, count: u32) {
+    set_persistent(env, &OrganizerKey::CreationWindow(organizer.clone()), &(window, count));
+}
+
+/// Get the settings an organizer's new events are created with
+pub fn get_organizer_defaults(env: &Env, organizer: &Address) -> Vec<OrganizerDefault> {
+    get_persistent(env, &OrganizerKey::Defaults(organizer.clone())).unwrap_or(Vec::new(env))
+}
+
+/// Set the settings an organizer's new events are created with; an empty
+/// profile removes the entry
+pub fn set_organizer_defaults(env: &Env, organizer: &Address, defaults: &Vec<OrganizerDefault>) {
+    let key = OrganizerKey::Defaults(organizer.clone());
+    if defaults.is_empty() {
+        remove_persistent(env, &key);
+    } else {
+        set_persistent(env, &key, defaults);
+    }
+}
+
+/// Get the IDs of every stored event an organizer controls, in ID order
+pub fn get_organizer_events(env: &Env, organizer: &Address) -> Vec<u64> {
+    get_persistent(env, &OrganizerKey::Events(organizer.clone())).unwrap_or(Vec::new(env))
+}
+
+/// Record that an organizer controls `event_id`
+///
+/// The index is kept sorted for cursor pagination. Salted event IDs are not
+/// issued in order, so each ID is inserted in place rather than appended.
+pub fn add_organizer_event(env: &Env, organizer: &Address, event_id: u64) {
+    let mut events = get_organizer_events(env, organizer);
+    if let Err(index) = events.binary_search(event_id) {
+        events.insert(index, event_id);
+        set_persistent(env, &OrganizerKey::Events(organizer.clone()), &events);
+    }
+}
+
+/// Drop `event_id` from an organizer's event index
+fn remove_organizer_event(env: &Env, organizer: &Address, event_id: u64) {
+    let key = OrganizerKey::Events(organizer.clone());
+    let mut events = get_organizer_events(env, organizer);
+    if let Some(index) = events.first_index_of(event_id) {
+        events.remove(index);
+    }
+    if events.is_empty() {
+        remove_persistent(env, &key);
+    } else {
+        set_persistent(env, &key, &events);
+    }
+}
+
+/// Move a persistent entry from one key to another, if it exists
+fn move_persistent<K: IntoVal<Env, Val>>(env: &Env, from: &K, to: &K) {
+    if let Some(value) = get_persistent::<K, Val>(env, from) {
+        set_persistent(env, to, &value);
+        remove_persistent(env, from);
+    }
+}
+
+/// Move every per-organizer record (event index, counters, volume,
+/// verification, rating, defaults, fee exemption) from `old` to `new`
+pub fn move_organizer(env: &Env, old: &Address, new: &Address) {
+    move_persistent(env, &OrganizerKey::Events(old.clone()), &OrganizerKey::Events(new.clone()));
+    move_persistent(env, &OrganizerKey::Defaults(old.clone()), &OrganizerKey::Defaults(new.clone()));
+    move_persistent(
+        env,
+        &OrganizerKey::CreationWindow(old.clone()),
+        &OrganizerKey::CreationWindow(new.clone()),
+    );
+    move_persistent(
+        env,
+        &DataKey::OrganizerActiveEvents(old.clone()),
+        &DataKey::OrganizerActiveEvents(new.clone()),
+    );
+    move_persistent(env, &DataKey::OrganizerVolume(old.clone()), &DataKey::OrganizerVolume(new.clone()));
+    move_persistent(env, &DataKey::VerifiedOrganizer(old.clone()), &DataKey::VerifiedOrganizer(new.clone()));
+    move_persistent(env, &DataKey::OrganizerRating(old.clone()), &DataKey::OrganizerRating(new.clone()));
+    move_persistent(
+        env,
+        &DataKey::FeeExempt(FeeExemptTarget::Organizer(old.clone())),
+        &DataKey::FeeExempt(FeeExemptTarget::Organizer(new.clone())),
+    );
 }
 
 /// Set event data
 pub fn set_event(env: &Env, event_id: u64, event: &Event) {
-    let key = (EVENT_PREFIX, event_id);
-    env.storage().persistent().set(&key, event);
+    let key = DataKey::Event(event_id);
+    set_persistent(env, &key, event);
+}
+
+/// Check whether an event exists
+pub fn has_event(env: &Env, event_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Event(event_id))
 }
 
 /// Get event data
 pub fn get_event(env: &Env, event_id: u64) -> Result<Event, LumentixError> {
-    let key = (EVENT_PREFIX, event_id);
-    env.storage()
-        .persistent()
-        .get(&key)
-        .ok_or(LumentixError::EventNotFound)
+    let key = DataKey::Event(event_id);
+    get_persistent(env, &key).ok_or(LumentixError::EventNotFound)
+}
+
+/// Remove an event together with its escrow and statistics entries
+pub fn remove_event(env: &Env, event_id: u64) {
+    if let Some(event) = get_persistent::<_, Event>(env, &DataKey::Event(event_id)) {
+        remove_organizer_event(env, &event.organizer, event_id);
+    }
+    remove_persistent(env, &DataKey::Event(event_id));
+    remove_persistent(env, &DataKey::Escrow(event_id));
+    remove_persistent(env, &DataKey::Stats(event_id));
+    remove_persistent(env, &DataKey::RefundBreakdown(event_id));
+    remove_persistent(env, &DataKey::Curtailment(event_id));
+    remove_persistent(env, &DataKey::PaymentAsset(event_id));
+    remove_persistent(env, &DataKey::FailedCheckIns(event_id));
+    remove_persistent(env, &DataKey::PolicyVersions(event_id));
+    remove_persistent(env, &DataKey::WaitlistEnabled(event_id));
+    remove_persistent(env, &DataKey::Waitlist(event_id));
+    remove_persistent(env, &DataKey::DepositBps(event_id));
+    remove_persistent(env, &DataKey::DepositsOwed(event_id));
+    remove_persistent(env, &EventKey::TaxTerms(event_id));
+    remove_persistent(env, &EventKey::TaxCollected(event_id));
+    remove_persistent(env, &EventKey::CapacityWaves(event_id));
+    remove_persistent(env, &EventKey::TierSalesWindows(event_id));
+    remove_persistent(env, &EventKey::BonusCapacity(event_id));
+    remove_persistent(env, &EventKey::ResaleSplit(event_id));
+    remove_persistent(env, &EventKey::Attestation(event_id));
+    remove_persistent(env, &EventKey::RefundWindow(event_id));
+    remove_persistent(env, &EventKey::PayoutFreeze(event_id));
+    remove_persistent(env, &EventKey::YieldPosition(event_id));
+    remove_persistent(env, &EventKey::CurrencyDisplay(event_id));
+    set_event_tags(env, event_id, &Vec::new(env));
+    remove_persistent(env, &EventKey::Followers(event_id));
+    remove_persistent(env, &EventKey::OversellBps(event_id));
+    remove_persistent(env, &EventKey::Standby(event_id));
+    remove_persistent(env, &EventKey::RefundablePrincipal(event_id));
+    remove_persistent(env, &EventKey::Transferable(event_id));
+    remove_persistent(env, &EventKey::TierEscrow(event_id));
+    remove_persistent(env, &EventKey::Announcements(event_id));
+    remove_persistent(env, &EventKey::TicketFields(event_id));
+    remove_persistent(env, &EventKey::BundleDiscounts(event_id));
+    remove_member_pricing(env, event_id);
+    remove_persistent(env, &EventKey::SalesCutoff(event_id));
+    remove_persistent(env, &EventKey::Cancellation(event_id));
+    remove_persistent(env, &EventKey::CancellationDetail(event_id));
+    remove_persistent(env, &DataKey::MaxNoShowBps(event_id));
+    remove_persistent(env, &DataKey::EventRating(event_id));
+    remove_persistent(env, &DataKey::EventSponsorships(event_id));
+    remove_persistent(env, &DataKey::AddOns(event_id));
 }
 
 /// Set ticket data
+///
+/// Every write is folded into the event's ticket digest.
 pub fn set_ticket(env: &Env, ticket_id: u64, ticket: &Ticket) {
-    let key = (TICKET_PREFIX, ticket_id);
-    env.storage().persistent().set(&key, ticket);
+    let key = DataKey::Ticket(ticket_id);
+    set_persistent(env, &key, ticket);
+    
+    let digest = lumentix_types::fold_ticket_digest(env, &get_ticket_digest(env, ticket.event_id), ticket);
+    set_persistent(env, &EventKey::TicketDigest(ticket.event_id), &digest);
+}
+
+/// Get the running digest of every ticket record written for an event
+pub fn get_ticket_digest(env: &Env, event_id: u64) -> BytesN<32> {
+    get_persistent(env, &EventKey::TicketDigest(event_id)).unwrap_or(BytesN::from_array(env, &[0; 32]))
 }
 
 /// Get ticket data
 pub fn get_ticket(env: &Env, ticket_id: u64) -> Result<Ticket, LumentixError> {
-    let key = (TICKET_PREFIX, ticket_id);
-    env.storage()
-        .persistent()
-        .get(&key)
-        .ok_or(LumentixError::TicketNotFound)
+    let key = DataKey::Ticket(ticket_id);
+    get_persistent(env, &key).ok_or(LumentixError::TicketNotFound)
 }
 
-/// Add amount to escrow for an event
-pub fn add_escrow(env: &Env, event_id: u64, amount: i128) {
-    let key = (ESCROW_PREFIX, event_id);
-    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-    env.storage().persistent().set(&key, &(current + amount));
+/// Get the check-in secret commitment for a ticket
+pub fn get_checkin_hash(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    get_persistent(env, &DataKey::CheckInHash(ticket_id))
 }
 
-/// Get escrow balance for an event
-pub fn get_escrow(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
-    let key = (ESCROW_PREFIX, event_id);
-    Ok(env.storage().persistent().get(&key).unwrap_or(0))
+/// Set the check-in secret commitment for a ticket
+pub fn set_checkin_hash(env: &Env, ticket_id: u64, hash: &BytesN<32>) {
+    set_persistent(env, &DataKey::CheckInHash(ticket_id), hash);
 }
 
-/// Deduct amount from escrow
-pub fn deduct_escrow(env: &Env, event_id: u64, amount: i128) -> Result<(), LumentixError> {
-    let key = (ESCROW_PREFIX, event_id);
-    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-    
-    if current < amount {
-        return Err(LumentixError::InsufficientEscrow);
-    }
-    
-    env.storage().persistent().set(&key, &(current - amount));
-    Ok(())
+/// Get the terms-of-sale hash the buyer accepted for a ticket
+pub fn get_terms_hash(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    get_persistent(env, &DataKey::TermsHash(ticket_id))
 }
 
-/// Clear escrow for an event
-pub fn clear_escrow(env: &Env, event_id: u64) {
-    let key = (ESCROW_PREFIX, event_id);
-    env.storage().persistent().set(&key, &0i128);
+/// Record the terms-of-sale hash the buyer accepted for a ticket
+pub fn set_terms_hash(env: &Env, ticket_id: u64, hash: &BytesN<32>) {
+    set_persistent(env, &DataKey::TermsHash(ticket_id), hash);
+}
+
+/// Get the buyer's purchase memo for a ticket
+pub fn get_purchase_memo(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    get_persistent(env, &DataKey::PurchaseMemo(ticket_id))
+}
+
+/// Record the buyer's purchase memo for a ticket
+pub fn set_purchase_memo(env: &Env, ticket_id: u64, memo: &BytesN<32>) {
+    set_persistent(env, &DataKey::PurchaseMemo(ticket_id), memo);
+}
+
+/// Get the policy version history for an event, oldest first
+pub fn get_policy_versions(env: &Env, event_id: u64) -> Vec<PolicyVersion> {
+    get_persistent(env, &DataKey::PolicyVersions(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Get the current (latest) policy version for an event, if any
+pub fn get_current_policy(env: &Env, event_id: u64) -> Option<PolicyVersion> {
+    get_policy_versions(env, event_id).last()
+}
+
+/// Append a new policy version for an event and return its version number
+pub fn add_policy_version(env: &Env, event_id: u64, hash: &BytesN<32>) -> u32 {
+    let mut versions = get_policy_versions(env, event_id);
+    let version = versions.len() + 1;
+    versions.push_back(PolicyVersion {
+        version,
+        hash: hash.clone(),
+        registered_at: env.ledger().timestamp(),
+    });
+    set_persistent(env, &DataKey::PolicyVersions(event_id), &versions);
+    version
+}
+
+/// Get an event's announcements, oldest first
+pub fn get_announcements(env: &Env, event_id: u64) -> Vec<Announcement> {
+    get_persistent(env, &EventKey::Announcements(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Append an announcement to an event, returning its number
+pub fn add_announcement(env: &Env, event_id: u64, content_hash: &BytesN<32>) -> u32 {
+    let mut announcements = get_announcements(env, event_id);
+    let number = announcements.len() + 1;
+    announcements.push_back(Announcement {
+        number,
+        content_hash: content_hash.clone(),
+        posted_at: env.ledger().timestamp(),
+    });
+    set_persistent(env, &EventKey::Announcements(event_id), &announcements);
+    number
+}
+
+/// Get the policy version a ticket was bought under
+pub fn get_ticket_policy_version(env: &Env, ticket_id: u64) -> Option<u32> {
+    get_persistent(env, &DataKey::TicketPolicyVersion(ticket_id))
+}
+
+/// Record the policy version a ticket was bought under
+pub fn set_ticket_policy_version(env: &Env, ticket_id: u64, version: u32) {
+    set_persistent(env, &DataKey::TicketPolicyVersion(ticket_id), &version);
+}
+
+/// Get the refund receipt for a ticket, if it was refunded
+pub fn get_refund_receipt(env: &Env, ticket_id: u64) -> Option<RefundReceipt> {
+    get_persistent(env, &DataKey::RefundReceipt(ticket_id))
+}
+
+/// Store the refund receipt for a ticket
+pub fn set_refund_receipt(env: &Env, ticket_id: u64, receipt: &RefundReceipt) {
+    set_persistent(env, &DataKey::RefundReceipt(ticket_id), receipt);
+}
+
+/// Get the late refund request for a ticket, if one was made
+pub fn get_late_refund(env: &Env, ticket_id: u64) -> Option<LateRefundRequest> {
+    get_persistent(env, &DataKey::LateRefund(ticket_id))
+}
+
+/// Store the late refund request for a ticket
+pub fn set_late_refund(env: &Env, ticket_id: u64, request: &LateRefundRequest) {
+    set_persistent(env, &DataKey::LateRefund(ticket_id), request);
+}
+
+/// Get the payment asset configured for an event, if any
+pub fn get_payment_asset(env: &Env, event_id: u64) -> Option<PaymentAsset> {
+    get_persistent(env, &DataKey::PaymentAsset(event_id))
+}
+
+/// Get the token an event's funds are held in, `None` for an
+/// accounting-only event
+pub fn event_token(env: &Env, event_id: u64) -> Option<Address> {
+    get_payment_asset(env, event_id).map(|asset| asset.token)
+}
+
+/// Set the payment asset of an event
+pub fn set_payment_asset(env: &Env, event_id: u64, asset: &PaymentAsset) {
+    set_persistent(env, &DataKey::PaymentAsset(event_id), asset);
+}
+
+/// Get how an event's prices are displayed, if known
+pub fn get_currency_display(env: &Env, event_id: u64) -> Option<CurrencyDisplay> {
+    get_persistent(env, &EventKey::CurrencyDisplay(event_id))
+}
+
+/// Set how an event's prices are displayed
+pub fn set_currency_display(env: &Env, event_id: u64, display: &CurrencyDisplay) {
+    set_persistent(env, &EventKey::CurrencyDisplay(event_id), display);
+}
+
+/// Forget how an event's prices are displayed
+pub fn remove_currency_display(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::CurrencyDisplay(event_id));
+}
+
+/// Whether a waitlist opens automatically when an event sells out
+pub fn is_waitlist_enabled(env: &Env, event_id: u64) -> bool {
+    get_persistent(env, &DataKey::WaitlistEnabled(event_id)).unwrap_or(false)
+}
+
+/// Set whether a waitlist opens automatically when an event sells out
+pub fn set_waitlist_enabled(env: &Env, event_id: u64, enabled: bool) {
+    set_persistent(env, &DataKey::WaitlistEnabled(event_id), &enabled);
+}
+
+/// Whether an event's tickets may be resold or swapped
+pub fn is_transferable(env: &Env, event_id: u64) -> bool {
+    get_persistent(env, &EventKey::Transferable(event_id)).unwrap_or(true)
+}
+
+/// Set whether an event's tickets may be resold or swapped
+pub fn set_transferable(env: &Env, event_id: u64, transferable: bool) {
+    set_persistent(env, &EventKey::Transferable(event_id), &transferable);
+}
+
+/// Get an event's waitlist in registration order; `None` until it opens
+pub fn get_waitlist(env: &Env, event_id: u64) -> Option<Vec<Address>> {
+    get_persistent(env, &DataKey::Waitlist(event_id))
+}
+
+/// Set an event's waitlist
+pub fn set_waitlist(env: &Env, event_id: u64, waitlist: &Vec<Address>) {
+    set_persistent(env, &DataKey::Waitlist(event_id), waitlist);
+}
+
+/// Get the curtailment terms of an event, if it was completed early
+pub fn get_curtailment(env: &Env, event_id: u64) -> Option<Curtailment> {
+    get_persistent(env, &DataKey::Curtailment(event_id))
+}
+
+/// Set the curtailment terms of an event
+pub fn set_curtailment(env: &Env, event_id: u64, curtailment: &Curtailment) {
+    set_persistent(env, &DataKey::Curtailment(event_id), curtailment);
+}
+
+/// Get how many of an event's seated tickets hold escrow and the principal
+/// they paid, which is what a curtailment refunds a share of
+pub fn get_refundable_principal(env: &Env, event_id: u64) -> (u32, i128) {
+    get_persistent(env, &EventKey::RefundablePrincipal(event_id)).unwrap_or((0, 0))
+}
+
+/// Count a ticket taking a seat with `principal` held in escrow
+pub fn add_refundable_ticket(env: &Env, event_id: u64, principal: i128) {
+    let (tickets, total) = get_refundable_principal(env, event_id);
+    set_persistent(env, &EventKey::RefundablePrincipal(event_id), &(tickets + 1, total + principal));
+}
+
+/// Stop counting a refunded ticket's seat and principal
+pub fn remove_refundable_ticket(env: &Env, event_id: u64, principal: i128) {
+    let (tickets, total) = get_refundable_principal(env, event_id);
+    set_persistent(env, &EventKey::RefundablePrincipal(event_id), &(tickets - 1, total - principal));
+}
+
+/// Get the pro-rata refund already paid for a ticket, if any
+pub fn get_prorata_refund(env: &Env, ticket_id: u64) -> Option<i128> {
+    get_persistent(env, &DataKey::ProRataRefund(ticket_id))
+}
+
+/// Record the pro-rata refund paid for a ticket
+pub fn set_prorata_refund(env: &Env, ticket_id: u64, amount: i128) {
+    set_persistent(env, &DataKey::ProRataRefund(ticket_id), &amount);
+}
+
+/// Get the share of a deposit ticket's principal returned on attendance, if
+/// the event sells deposit tickets
+pub fn get_deposit_bps(env: &Env, event_id: u64) -> Option<u32> {
+    get_persistent(env, &DataKey::DepositBps(event_id))
+}
+
+/// Set the refundable share of an event's deposit tickets
+pub fn set_deposit_bps(env: &Env, event_id: u64, refundable_bps: u32) {
+    set_persistent(env, &DataKey::DepositBps(event_id), &refundable_bps);
+}
+
+/// Get the deposits owed to attendees that have not been returned yet
+pub fn get_deposits_owed(env: &Env, event_id: u64) -> i128 {
+    get_persistent(env, &DataKey::DepositsOwed(event_id)).unwrap_or(0)
+}
+
+/// Adjust the deposits owed to attendees by `delta`
+pub fn add_deposits_owed(env: &Env, event_id: u64, delta: i128) {
+    let owed = get_deposits_owed(env, event_id) + delta;
+    set_persistent(env, &DataKey::DepositsOwed(event_id), &owed);
+}
+
+/// Get an event's tax withholding terms, if any
+pub fn get_tax_terms(env: &Env, event_id: u64) -> Option<TaxTerms> {
+    get_persistent(env, &EventKey::TaxTerms(event_id))
+}
+
+/// Set an event's tax withholding terms
+pub fn set_tax_terms(env: &Env, event_id: u64, terms: &TaxTerms) {
+    set_persistent(env, &EventKey::TaxTerms(event_id), terms);
+}
+
+/// Get the tax withheld from an event's sales and not yet paid out
+pub fn get_tax_collected(env: &Env, event_id: u64) -> i128 {
+    get_persistent(env, &EventKey::TaxCollected(event_id)).unwrap_or(0)
+}
+
+/// Adjust the tax withheld from an event's sales by `delta`
+pub fn add_tax_collected(env: &Env, event_id: u64, delta: i128) {
+    let collected = get_tax_collected(env, event_id) + delta;
+    set_persistent(env, &EventKey::TaxCollected(event_id), &collected);
+}
+
+/// Get the deposit returned for a ticket, if any
+pub fn get_deposit_return(env: &Env, ticket_id: u64) -> Option<i128> {
+    get_persistent(env, &DataKey::DepositReturn(ticket_id))
+}
+
+/// Record the deposit returned for a ticket
+pub fn set_deposit_return(env: &Env, ticket_id: u64, amount: i128) {
+    set_persistent(env, &DataKey::DepositReturn(ticket_id), &amount);
+}
+
+/// Get the maximum no-show rate an event accepts from buyers, if gated
+pub fn get_max_no_show_bps(env: &Env, event_id: u64) -> Option<u32> {
+    get_persistent(env, &DataKey::MaxNoShowBps(event_id))
+}
+
+/// Set the maximum no-show rate an event accepts from buyers
+pub fn set_max_no_show_bps(env: &Env, event_id: u64, max_bps: u32) {
+    set_persistent(env, &DataKey::MaxNoShowBps(event_id), &max_bps);
+}
+
+/// Get a buyer's attendance history across deposit-model events
+pub fn get_attendance(env: &Env, buyer: &Address) -> AttendanceRecord {
+    get_persistent(env, &DataKey::Attendance(buyer.clone())).unwrap_or_default()
+}
+
+/// Record a settled deposit ticket in its holder's attendance history
+pub fn record_attendance(env: &Env, buyer: &Address, attended: bool) {
+    let mut record = get_attendance(env, buyer);
+    record.settled += 1;
+    if !attended {
+        record.no_shows += 1;
+    }
+    set_persistent(env, &DataKey::Attendance(buyer.clone()), &record);
+}
+
+/// Get an account's ticket history, oldest entry first
+pub fn get_history(env: &Env, account: &Address) -> Vec<HistoryEntry> {
+    get_persistent(env, &AccountKey::History(account.clone())).unwrap_or(Vec::new(env))
+}
+
+/// Get the ID the next entry of an account's ticket history will carry
+///
+/// IDs keep increasing as old entries are dropped, so they stay usable as
+/// pagination cursors.
+pub fn next_history_id(env: &Env, account: &Address) -> u64 {
+    get_history(env, account).last().map_or(0, |entry| entry.id + 1)
+}
+
+/// Append to an account's ticket history, dropping the oldest entry when full
+pub fn record_history(env: &Env, account: &Address, entry: &HistoryEntry) {
+    let mut history = get_history(env, account);
+    if history.len() >= MAX_HISTORY_ENTRIES {
+        history.pop_front();
+    }
+    history.push_back(entry.clone());
+    set_persistent(env, &AccountKey::History(account.clone()), &history);
+}
+
+/// Get the number of an event's tickets an account holds
+pub fn get_holdings(env: &Env, account: &Address, event_id: u64) -> u32 {
+    get_persistent(env, &AccountKey::Holdings(account.clone(), event_id)).unwrap_or(0)
+}
+
+/// Count a ticket of an event as held by an account
+pub fn add_holding(env: &Env, account: &Address, event_id: u64) {
+    let key = AccountKey::Holdings(account.clone(), event_id);
+    let held: u32 = get_persistent(env, &key).unwrap_or(0);
+    set_persistent(env, &key, &(held + 1));
+}
+
+/// Stop counting a ticket of an event as held by an account
+pub fn remove_holding(env: &Env, account: &Address, event_id: u64) {
+    let key = AccountKey::Holdings(account.clone(), event_id);
+    match get_persistent::<_, u32>(env, &key).unwrap_or(0) {
+        0 | 1 => remove_persistent(env, &key),
+        held => set_persistent(env, &key, &(held - 1)),
+    }
+}
+
+/// Check whether a ticket holder has already spent a signed check-in nonce
+pub fn is_checkin_nonce_used(env: &Env, holder: &Address, nonce: u64) -> bool {
+    env.storage().persistent().has(&AccountKey::CheckInNonce(holder.clone(), nonce))
+}
+
+/// Mark a ticket holder's signed check-in nonce as spent
+pub fn use_checkin_nonce(env: &Env, holder: &Address, nonce: u64) {
+    set_persistent(env, &AccountKey::CheckInNonce(holder.clone(), nonce), &true);
+}
+
+/// Get the review submitted with a ticket, if any
+pub fn get_review(env: &Env, ticket_id: u64) -> Option<Review> {
+    get_persistent(env, &DataKey::Review(ticket_id))
+}
+
+/// Store a ticket's review
+pub fn set_review(env: &Env, ticket_id: u64, review: &Review) {
+    set_persistent(env, &DataKey::Review(ticket_id), review);
+}
+
+/// Get the hash of the comment submitted with a ticket's review, if any
+pub fn get_review_comment(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    get_persistent(env, &DataKey::ReviewComment(ticket_id))
+}
+
+/// Store the hash of a review's off-chain comment
+pub fn set_review_comment(env: &Env, ticket_id: u64, hash: &BytesN<32>) {
+    set_persistent(env, &DataKey::ReviewComment(ticket_id), hash);
+}
+
+/// Get the aggregate rating of an event
+pub fn get_event_rating(env: &Env, event_id: u64) -> RatingSummary {
+    get_persistent(env, &DataKey::EventRating(event_id)).unwrap_or_default()
+}
+
+/// Get the aggregate rating across an organizer's events
+pub fn get_organizer_rating(env: &Env, organizer: &Address) -> RatingSummary {
+    get_persistent(env, &DataKey::OrganizerRating(organizer.clone())).unwrap_or_default()
+}
+
+/// Add a rating to an event's and its organizer's aggregates
+pub fn record_rating(env: &Env, event_id: u64, organizer: &Address, rating: u32) {
+    let add = |mut summary: RatingSummary| {
+        summary.count += 1;
+        summary.total += rating;
+        summary.average_x100 = summary.total * 100 / summary.count;
+        summary
+    };
+    set_persistent(env, &DataKey::EventRating(event_id), &add(get_event_rating(env, event_id)));
+    set_persistent(
+        env,
+        &DataKey::OrganizerRating(organizer.clone()),
+        &add(get_organizer_rating(env, organizer)),
+    );
+}
+
+/// Get a sponsorship slot
+pub fn get_sponsorship(env: &Env, sponsorship_id: u64) -> Result<Sponsorship, LumentixError> {
+    get_persistent(env, &DataKey::Sponsorship(sponsorship_id)).ok_or(LumentixError::SponsorshipNotFound)
+}
+
+/// Set a sponsorship slot
+pub fn set_sponsorship(env: &Env, sponsorship: &Sponsorship) {
+    set_persistent(env, &DataKey::Sponsorship(sponsorship.id), sponsorship);
+}
+
+/// Get the IDs of an event's sponsorship slots, oldest first
+pub fn get_event_sponsorships(env: &Env, event_id: u64) -> Vec<u64> {
+    get_persistent(env, &DataKey::EventSponsorships(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Set the IDs of an event's sponsorship slots
+pub fn set_event_sponsorships(env: &Env, event_id: u64, ids: &Vec<u64>) {
+    set_persistent(env, &DataKey::EventSponsorships(event_id), ids);
+}
+
+/// Get an event's add-on catalog, indexed by add-on ID
+pub fn get_add_ons(env: &Env, event_id: u64) -> Vec<AddOn> {
+    get_persistent(env, &DataKey::AddOns(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Set an event's add-on catalog
+pub fn set_add_ons(env: &Env, event_id: u64, add_ons: &Vec<AddOn>) {
+    set_persistent(env, &DataKey::AddOns(event_id), add_ons);
+}
+
+/// Get an event's scheduled capacity waves
+pub fn get_capacity_waves(env: &Env, event_id: u64) -> Vec<CapacityWave> {
+    get_persistent(env, &EventKey::CapacityWaves(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Set an event's scheduled capacity waves
+pub fn set_capacity_waves(env: &Env, event_id: u64, waves: &Vec<CapacityWave>) {
+    set_persistent(env, &EventKey::CapacityWaves(event_id), waves);
+}
+
+/// Get an open resale offer
+pub fn get_offer(env: &Env, offer_id: u64) -> Option<Offer> {
+    get_persistent(env, &ResaleKey::Offer(offer_id))
+}
+
+/// Store a resale offer
+pub fn set_offer(env: &Env, offer: &Offer) {
+    set_persistent(env, &ResaleKey::Offer(offer.id), offer);
+}
+
+/// Remove a resale offer once accepted or cancelled
+pub fn remove_offer(env: &Env, offer_id: u64) {
+    remove_persistent(env, &ResaleKey::Offer(offer_id));
+}
+
+/// Get a pending ticket swap
+pub fn get_swap(env: &Env, swap_id: u64) -> Option<Swap> {
+    get_persistent(env, &ResaleKey::Swap(swap_id))
+}
+
+/// Store a ticket swap proposal
+pub fn set_swap(env: &Env, swap: &Swap) {
+    set_persistent(env, &ResaleKey::Swap(swap.id), swap);
+}
+
+/// Remove a ticket swap once accepted or cancelled
+pub fn remove_swap(env: &Env, swap_id: u64) {
+    remove_persistent(env, &ResaleKey::Swap(swap_id));
+}
+
+/// Get when the admin attested that an event took place, if they did
+pub fn get_attestation(env: &Env, event_id: u64) -> Option<u64> {
+    get_persistent(env, &EventKey::Attestation(event_id))
+}
+
+/// Record the admin's attestation that an event took place
+pub fn set_attestation(env: &Env, event_id: u64, attested_at: u64) {
+    set_persistent(env, &EventKey::Attestation(event_id), &attested_at);
+}
+
+/// Get the start and late refund request count of an event's current
+/// refund spike window
+pub fn get_refund_window(env: &Env, event_id: u64) -> Option<(u64, u32)> {
+    get_persistent(env, &EventKey::RefundWindow(event_id))
+}
+
+/// Set the start and late refund request count of an event's refund spike
+/// window
+pub fn set_refund_window(env: &Env, event_id: u64, started_at: u64, requests: u32) {
+    set_persistent(env, &EventKey::RefundWindow(event_id), &(started_at, requests));
+}
+
+/// Remove an event's refund spike window, starting a fresh count
+pub fn remove_refund_window(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::RefundWindow(event_id));
+}
+
+/// Get when an event's payouts were frozen, if they are
+pub fn get_payout_freeze(env: &Env, event_id: u64) -> Option<u64> {
+    get_persistent(env, &EventKey::PayoutFreeze(event_id))
+}
+
+/// Freeze an event's payouts pending admin review
+pub fn set_payout_freeze(env: &Env, event_id: u64, frozen_at: u64) {
+    set_persistent(env, &EventKey::PayoutFreeze(event_id), &frozen_at);
+}
+
+/// Lift an event's payout freeze
+pub fn remove_payout_freeze(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::PayoutFreeze(event_id));
+}
+
+/// Check whether a yield vault is whitelisted for escrow deposits
+pub fn is_yield_vault(env: &Env, vault: &Address) -> bool {
+    get_persistent(env, &PlatformKey::YieldVault(vault.clone())).unwrap_or(false)
+}
+
+/// Whitelist or delist a yield vault
+pub fn set_yield_vault(env: &Env, vault: &Address, allowed: bool) {
+    let key = PlatformKey::YieldVault(vault.clone());
+    if allowed {
+        set_persistent(env, &key, &true);
+    } else {
+        remove_persistent(env, &key);
+    }
+}
+
+/// Get an event's escrow parked in a yield vault, if any
+pub fn get_yield_position(env: &Env, event_id: u64) -> Option<YieldPosition> {
+    get_persistent(env, &EventKey::YieldPosition(event_id))
+}
+
+/// Record an event's escrow parked in a yield vault
+pub fn set_yield_position(env: &Env, event_id: u64, position: &YieldPosition) {
+    set_persistent(env, &EventKey::YieldPosition(event_id), position);
+}
+
+/// Remove an event's yield position once redeemed
+pub fn remove_yield_position(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::YieldPosition(event_id));
+}
+
+/// Get the featured event placements, ordered by rank
+pub fn get_featured(env: &Env) -> Vec<FeaturedEvent> {
+    get_instance(env, &InstanceKey::Featured).unwrap_or(Vec::new(env))
+}
+
+/// Set the featured event placements
+pub fn set_featured(env: &Env, featured: &Vec<FeaturedEvent>) {
+    set_instance(env, &InstanceKey::Featured, featured);
+}
+
+/// Get the promotion last bought for a slot, which may have lapsed
+pub fn get_promotion(env: &Env, slot: u32) -> Option<Promotion> {
+    get_persistent(env, &PlatformKey::Promotion(slot))
+}
+
+/// Record a promotion occupying a slot
+pub fn set_promotion(env: &Env, promotion: &Promotion) {
+    set_persistent(env, &PlatformKey::Promotion(promotion.slot), promotion);
+}
+
+/// Get the share of an event's capacity that may be sold on standby, in
+/// basis points
+pub fn get_oversell_bps(env: &Env, event_id: u64) -> Option<u32> {
+    get_persistent(env, &EventKey::OversellBps(event_id))
+}
+
+/// Set or clear the share of an event's capacity sold on standby
+pub fn set_oversell_bps(env: &Env, event_id: u64, bps: u32) {
+    let key = EventKey::OversellBps(event_id);
+    if bps == 0 {
+        remove_persistent(env, &key);
+    } else {
+        set_persistent(env, &key, &bps);
+    }
+}
+
+/// Get the tickets sold on standby for an event, in purchase order
+pub fn get_standby(env: &Env, event_id: u64) -> Vec<u64> {
+    get_persistent(env, &EventKey::Standby(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Set the tickets sold on standby for an event
+pub fn set_standby(env: &Env, event_id: u64, tickets: &Vec<u64>) {
+    set_persistent(env, &EventKey::Standby(event_id), tickets);
+}
+
+/// Remove an event's standby pool once resolved
+pub fn remove_standby(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::Standby(event_id));
+}
+
+/// Get the accounts following an event, in address order
+pub fn get_followers(env: &Env, event_id: u64) -> Vec<Address> {
+    get_persistent(env, &EventKey::Followers(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Set the accounts following an event, which must be in address order
+pub fn set_followers(env: &Env, event_id: u64, followers: &Vec<Address>) {
+    set_persistent(env, &EventKey::Followers(event_id), followers);
+}
+
+/// Get an event's discovery tags
+pub fn get_event_tags(env: &Env, event_id: u64) -> Vec<Symbol> {
+    get_persistent(env, &EventKey::Tags(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Replace an event's discovery tags, keeping the tag index in step
+pub fn set_event_tags(env: &Env, event_id: u64, tags: &Vec<Symbol>) {
+    let old = get_event_tags(env, event_id);
+    for tag in old.iter() {
+        if !tags.contains(&tag) {
+            let mut events = get_tagged_events(env, &tag);
+            if let Some(index) = events.first_index_of(event_id) {
+                events.remove(index);
+            }
+            set_tagged_events(env, &tag, &events);
+        }
+    }
+    for tag in tags.iter() {
+        if !old.contains(&tag) {
+            let mut events = get_tagged_events(env, &tag);
+            if let Err(index) = events.binary_search(event_id) {
+                events.insert(index, event_id);
+            }
+            set_tagged_events(env, &tag, &events);
+        }
+    }
+    
+    if tags.is_empty() {
+        remove_persistent(env, &EventKey::Tags(event_id));
+    } else {
+        set_persistent(env, &EventKey::Tags(event_id), tags);
+    }
+}
+
+/// Get the IDs of events carrying a tag, in ID order
+pub fn get_tagged_events(env: &Env, tag: &Symbol) -> Vec<u64> {
+    get_persistent(env, &PlatformKey::TaggedEvents(tag.clone())).unwrap_or(Vec::new(env))
+}
+
+/// Set the IDs of events carrying a tag; an empty list removes the entry
+fn set_tagged_events(env: &Env, tag: &Symbol, events: &Vec<u64>) {
+    let key = PlatformKey::TaggedEvents(tag.clone());
+    if events.is_empty() {
+        remove_persistent(env, &key);
+    } else {
+        set_persistent(env, &key, events);
+    }
+}
+
+/// Get an event's resale split, if the organizer configured one
+pub fn get_resale_split(env: &Env, event_id: u64) -> Option<ResaleSplit> {
+    get_persistent(env, &EventKey::ResaleSplit(event_id))
+}
+
+/// Set an event's resale split
+pub fn set_resale_split(env: &Env, event_id: u64, split: &ResaleSplit) {
+    set_persistent(env, &EventKey::ResaleSplit(event_id), split);
+}
+
+/// Get an event's bonus capacity rule, if set
+pub fn get_bonus_capacity(env: &Env, event_id: u64) -> Option<BonusCapacity> {
+    get_persistent(env, &EventKey::BonusCapacity(event_id))
+}
+
+/// Set an event's bonus capacity rule
+pub fn set_bonus_capacity(env: &Env, event_id: u64, bonus: &BonusCapacity) {
+    set_persistent(env, &EventKey::BonusCapacity(event_id), bonus);
+}
+
+/// Remove an event's bonus capacity rule
+pub fn remove_bonus_capacity(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::BonusCapacity(event_id));
+}
+
+/// Get an event's per-tier sales window overrides, keyed by tier index
+pub fn get_tier_sales_windows(env: &Env, event_id: u64) -> Map<u32, SalesWindow> {
+    get_persistent(env, &EventKey::TierSalesWindows(event_id)).unwrap_or(Map::new(env))
+}
+
+/// Set an event's per-tier sales window overrides
+pub fn set_tier_sales_windows(env: &Env, event_id: u64, windows: &Map<u32, SalesWindow>) {
+    set_persistent(env, &EventKey::TierSalesWindows(event_id), windows);
+}
+
+/// Get how many seconds before its start an event's sales close, if set
+pub fn get_sales_cutoff(env: &Env, event_id: u64) -> Option<u64> {
+    get_persistent(env, &EventKey::SalesCutoff(event_id))
+}
+
+/// Set how many seconds before its start an event's sales close
+pub fn set_sales_cutoff(env: &Env, event_id: u64, seconds_before_start: u64) {
+    set_persistent(env, &EventKey::SalesCutoff(event_id), &seconds_before_start);
+}
+
+/// Remove an event's sales cutoff, keeping sales open until its end time
+pub fn remove_sales_cutoff(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::SalesCutoff(event_id));
+}
+
+/// Get the cancellation notice of an event, if it was cancelled
+pub fn get_cancellation(env: &Env, event_id: u64) -> Option<EventCancellation> {
+    get_persistent(env, &EventKey::Cancellation(event_id))
+}
+
+/// Record the cancellation notice of an event
+pub fn set_cancellation(env: &Env, event_id: u64, cancellation: &EventCancellation) {
+    set_persistent(env, &EventKey::Cancellation(event_id), cancellation);
+}
+
+/// Get the hash of an event's off-chain cancellation detail, if any
+pub fn get_cancellation_detail(env: &Env, event_id: u64) -> Option<BytesN<32>> {
+    get_persistent(env, &EventKey::CancellationDetail(event_id))
+}
+
+/// Record the hash of an event's off-chain cancellation detail
+pub fn set_cancellation_detail(env: &Env, event_id: u64, detail_hash: &BytesN<32>) {
+    set_persistent(env, &EventKey::CancellationDetail(event_id), detail_hash);
+}
+
+/// Get the pending recovery of an event's orphaned escrow, if any
+pub fn get_recovery(env: &Env, event_id: u64) -> Option<RecoveryRequest> {
+    get_persistent(env, &EventKey::Recovery(event_id))
+}
+
+/// Record a pending recovery of an event's orphaned escrow
+pub fn set_recovery(env: &Env, event_id: u64, request: &RecoveryRequest) {
+    set_persistent(env, &EventKey::Recovery(event_id), request);
+}
+
+/// Remove an executed recovery request
+pub fn remove_recovery(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::Recovery(event_id));
+}
+
+/// Get the add-ons bought with a ticket, if any
+pub fn get_ticket_add_ons(env: &Env, ticket_id: u64) -> Option<TicketAddOns> {
+    get_persistent(env, &DataKey::TicketAddOns(ticket_id))
+}
+
+/// Record the add-ons bought with a ticket
+pub fn set_ticket_add_ons(env: &Env, ticket_id: u64, add_ons: &TicketAddOns) {
+    set_persistent(env, &DataKey::TicketAddOns(ticket_id), add_ons);
+}
+
+/// Get what a buyer paid for a ticket, if recorded at purchase
+pub fn get_ticket_payment(env: &Env, ticket_id: u64) -> Option<TicketPayment> {
+    get_persistent(env, &TicketKey::Payment(ticket_id))
+}
+
+/// Record what a buyer paid for a ticket
+pub fn set_ticket_payment(env: &Env, ticket_id: u64, payment: &TicketPayment) {
+    set_persistent(env, &TicketKey::Payment(ticket_id), payment);
+}
+
+/// Get an event's bundle discounts
+pub fn get_bundle_discounts(env: &Env, event_id: u64) -> Vec<BundleDiscount> {
+    get_persistent(env, &EventKey::BundleDiscounts(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Replace an event's bundle discounts
+pub fn set_bundle_discounts(env: &Env, event_id: u64, discounts: &Vec<BundleDiscount>) {
+    set_persistent(env, &EventKey::BundleDiscounts(event_id), discounts);
+}
+
+/// Get the storage rent prepaid for an event, if any
+pub fn get_rent_deposit(env: &Env, event_id: u64) -> Option<RentDeposit> {
+    get_persistent(env, &EventKey::RentDeposit(event_id))
+}
+
+/// Store the storage rent prepaid for an event
+pub fn set_rent_deposit(env: &Env, event_id: u64, rent: &RentDeposit) {
+    set_persistent(env, &EventKey::RentDeposit(event_id), rent);
+}
+
+/// Extend the TTL of an event's core records: the event, its escrow and
+/// its statistics
+pub fn extend_event_ttl(env: &Env, event_id: u64) {
+    for key in [DataKey::Event(event_id), DataKey::Escrow(event_id), DataKey::Stats(event_id)] {
+        if env.storage().persistent().has(&key) {
+            extend_persistent_ttl(env, &key);
+        }
+    }
+}
+
+/// Get an event's member pricing
+pub fn get_member_pricing(env: &Env, event_id: u64) -> Option<MemberPricing> {
+    get_persistent(env, &EventKey::MemberPricing(event_id))
+}
+
+/// Set an event's member pricing
+pub fn set_member_pricing(env: &Env, event_id: u64, pricing: &MemberPricing) {
+    set_persistent(env, &EventKey::MemberPricing(event_id), pricing);
+}
+
+/// Remove an event's member pricing
+pub fn remove_member_pricing(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::MemberPricing(event_id));
+}
+
+/// Get the custom fields an event collects at purchase
+pub fn get_ticket_fields(env: &Env, event_id: u64) -> Vec<Symbol> {
+    get_persistent(env, &EventKey::TicketFields(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Set the custom fields an event collects at purchase
+pub fn set_ticket_fields(env: &Env, event_id: u64, fields: &Vec<Symbol>) {
+    set_persistent(env, &EventKey::TicketFields(event_id), fields);
+}
+
+/// Get the hashed custom field values collected with a ticket
+pub fn get_field_values(env: &Env, ticket_id: u64) -> Map<Symbol, BytesN<32>> {
+    get_persistent(env, &TicketKey::FieldValues(ticket_id)).unwrap_or(Map::new(env))
+}
+
+/// Store the hashed custom field values collected with a ticket
+pub fn set_field_values(env: &Env, ticket_id: u64, values: &Map<Symbol, BytesN<32>>) {
+    set_persistent(env, &TicketKey::FieldValues(ticket_id), values);
+}
+
+/// Get how long before an event starts its will-call pickup opens, if the
+/// event sells will-call only
+pub fn get_will_call(env: &Env, event_id: u64) -> Option<u64> {
+    get_persistent(env, &EventKey::WillCall(event_id))
+}
+
+/// Make an event sell will-call only, or with `None` sell normally again
+pub fn set_will_call(env: &Env, event_id: u64, opens_before: Option<u64>) {
+    match opens_before {
+        Some(secs) => set_persistent(env, &EventKey::WillCall(event_id), &secs),
+        None => remove_persistent(env, &EventKey::WillCall(event_id)),
+    }
+}
+
+/// Get who may pick up a will-call ticket
+pub fn get_pickup(env: &Env, ticket_id: u64) -> Option<WillCallPickup> {
+    get_persistent(env, &TicketKey::Pickup(ticket_id))
+}
+
+/// Name who may pick up a will-call ticket
+pub fn set_pickup(env: &Env, ticket_id: u64, pickup: &WillCallPickup) {
+    set_persistent(env, &TicketKey::Pickup(ticket_id), pickup);
+}
+
+/// Remove a will-call ticket's pickup once it is claimed or refunded
+pub fn remove_pickup(env: &Env, ticket_id: u64) {
+    remove_persistent(env, &TicketKey::Pickup(ticket_id));
+}
+
+/// Get an event's legacy ticket import allowance, if the admin approved one
+pub fn get_import_allowance(env: &Env, event_id: u64) -> Option<ImportAllowance> {
+    get_persistent(env, &EventKey::ImportAllowance(event_id))
+}
+
+/// Set an event's legacy ticket import allowance
+pub fn set_import_allowance(env: &Env, event_id: u64, allowance: &ImportAllowance) {
+    set_persistent(env, &EventKey::ImportAllowance(event_id), allowance);
+}
+
+/// Check whether a ticket was imported from a legacy system rather than sold
+pub fn is_imported(env: &Env, ticket_id: u64) -> bool {
+    env.storage().persistent().has(&TicketKey::Imported(ticket_id))
+}
+
+/// Flag a ticket as imported from a legacy system
+pub fn set_imported(env: &Env, ticket_id: u64) {
+    set_persistent(env, &TicketKey::Imported(ticket_id), &true);
+}
+
+/// Get the hash of a burned ticket's external redemption record
+pub fn get_redemption(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    get_persistent(env, &TicketKey::Redemption(ticket_id))
+}
+
+/// Record the hash of a burned ticket's external redemption record
+pub fn set_redemption(env: &Env, ticket_id: u64, redemption_hash: &BytesN<32>) {
+    set_persistent(env, &TicketKey::Redemption(ticket_id), redemption_hash);
+}
+
+/// Get a ticket's loan if it has not ended yet
+pub fn get_active_loan(env: &Env, ticket_id: u64) -> Option<TicketLoan> {
+    get_persistent::<_, TicketLoan>(env, &TicketKey::Loan(ticket_id))
+        .filter(|loan| env.ledger().timestamp() < loan.until)
+}
+
+/// Store a ticket's loan, replacing any ended one
+pub fn set_loan(env: &Env, ticket_id: u64, loan: &TicketLoan) {
+    set_persistent(env, &TicketKey::Loan(ticket_id), loan);
+}
+
+/// Remove a ticket's loan
+pub fn remove_loan(env: &Env, ticket_id: u64) {
+    remove_persistent(env, &TicketKey::Loan(ticket_id));
+}
+
+/// Get the failed check-in log for an event
+pub fn get_failed_check_ins(env: &Env, event_id: u64) -> Vec<FailedCheckIn> {
+    get_persistent(env, &DataKey::FailedCheckIns(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Append to the failed check-in log, dropping the oldest entry when full
+pub fn log_failed_check_in(env: &Env, event_id: u64, attempt: &FailedCheckIn) {
+    let mut log = get_failed_check_ins(env, event_id);
+    if log.len() >= MAX_FAILED_CHECK_INS {
+        log.pop_front();
+    }
+    log.push_back(attempt.clone());
+    set_persistent(env, &DataKey::FailedCheckIns(event_id), &log);
+}
+
+/// Get an event's registered gate devices, including lapsed ones
+pub fn get_gate_devices(env: &Env, event_id: u64) -> Vec<GateDevice> {
+    get_persistent(env, &EventKey::GateDevices(event_id)).unwrap_or(Vec::new(env))
+}
+
+/// Replace an event's registered gate devices
+pub fn set_gate_devices(env: &Env, event_id: u64, devices: &Vec<GateDevice>) {
+    if devices.is_empty() {
+        remove_persistent(env, &EventKey::GateDevices(event_id));
+    } else {
+        set_persistent(env, &EventKey::GateDevices(event_id), devices);
+    }
+}
+
+/// Add amount to escrow for an event
+pub fn add_escrow(env: &Env, event_id: u64, amount: i128) {
+    let key = DataKey::Escrow(event_id);
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+    set_persistent(env, &key, &(current + amount));
+    adjust_total(env, &DataKey::TotalEscrow, amount);
+}
+
+/// Get escrow balance for an event
+pub fn get_escrow(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
+    let key = DataKey::Escrow(event_id);
+    Ok(get_persistent(env, &key).unwrap_or(0))
+}
+
+/// Deduct amount from escrow
+pub fn deduct_escrow(env: &Env, event_id: u64, amount: i128) -> Result<(), LumentixError> {
+    let key = DataKey::Escrow(event_id);
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+    
+    if current < amount {
+        return Err(LumentixError::InsufficientEscrow);
+    }
+    
+    set_persistent(env, &key, &(current - amount));
+    adjust_total(env, &DataKey::TotalEscrow, -amount);
+    Ok(())
+}
+
+/// Clear escrow for an event
+pub fn clear_escrow(env: &Env, event_id: u64) {
+    let key = DataKey::Escrow(event_id);
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+    set_persistent(env, &key, &0i128);
+    adjust_total(env, &DataKey::TotalEscrow, -current);
+}
+
+/// Get the ticket proceeds an event's escrow holds for each tier
+pub fn get_tier_escrow(env: &Env, event_id: u64) -> Map<u32, i128> {
+    get_persistent(env, &EventKey::TierEscrow(event_id)).unwrap_or(Map::new(env))
+}
+
+/// Credit a tier's share of an event's escrow
+pub fn add_tier_escrow(env: &Env, event_id: u64, tier: u32, amount: i128) {
+    let mut balances = get_tier_escrow(env, event_id);
+    balances.set(tier, balances.get(tier).unwrap_or(0) + amount);
+    set_persistent(env, &EventKey::TierEscrow(event_id), &balances);
+}
+
+/// Debit a tier's share of an event's escrow, down to zero; once the
+/// organizer has been paid the tier holds nothing and payouts from the
+/// event's reserves leave it untouched
+pub fn deduct_tier_escrow(env: &Env, event_id: u64, tier: u32, amount: i128) {
+    let mut balances = get_tier_escrow(env, event_id);
+    let Some(current) = balances.get(tier) else {
+        return;
+    };
+    if current <= amount {
+        balances.remove(tier);
+    } else {
+        balances.set(tier, current - amount);
+    }
+    set_persistent(env, &EventKey::TierEscrow(event_id), &balances);
+}
+
+/// Drop an event's per-tier escrow once its proceeds are paid out
+pub fn clear_tier_escrow(env: &Env, event_id: u64) {
+    remove_persistent(env, &EventKey::TierEscrow(event_id));
+}
+
+/// Get running statistics for an event
+pub fn get_stats(env: &Env, event_id: u64) -> EventStats {
+    let key = DataKey::Stats(event_id);
+    get_persistent(env, &key).unwrap_or_default()
+}
+
+/// Set running statistics for an event
+pub fn set_stats(env: &Env, event_id: u64, stats: &EventStats) {
+    let key = DataKey::Stats(event_id);
+    set_persistent(env, &key, stats);
+}
+
+/// Record a ticket sale in the event statistics
+pub fn record_sale(env: &Env, event_id: u64, amount: i128) {
+    let mut stats = get_stats(env, event_id);
+    stats.tickets_sold += 1;
+    stats.revenue += amount;
+    set_stats(env, event_id, &stats);
+}
+
+/// Record tickets imported from a legacy system, which bring no revenue
+pub fn record_imports(env: &Env, event_id: u64, count: u32) {
+    let mut stats = get_stats(env, event_id);
+    stats.tickets_sold += count;
+    set_stats(env, event_id, &stats);
+}
+
+/// Record an unused ticket expiring in the event statistics
+pub fn record_expiry(env: &Env, event_id: u64) {
+    let mut stats = get_stats(env, event_id);
+    stats.tickets_expired += 1;
+    set_stats(env, event_id, &stats);
+}
+
+/// Record a ticket check-in in the event statistics
+pub fn record_check_in(env: &Env, event_id: u64) {
+    let mut stats = get_stats(env, event_id);
+    stats.tickets_used += 1;
+    set_stats(env, event_id, &stats);
+}
+
+/// Record an attendee entering the venue
+pub fn record_entry(env: &Env, event_id: u64) {
+    let mut stats = get_stats(env, event_id);
+    stats.occupancy += 1;
+    set_stats(env, event_id, &stats);
+}
+
+/// Record an attendee leaving the venue
+pub fn record_exit(env: &Env, event_id: u64) {
+    let mut stats = get_stats(env, event_id);
+    stats.occupancy = stats.occupancy.saturating_sub(1);
+    set_stats(env, event_id, &stats);
+}
+
+/// Record a ticket refund in the event statistics
+pub fn record_refund(env: &Env, event_id: u64, amount: i128, reason: RefundReason) {
+    let mut stats = get_stats(env, event_id);
+    stats.tickets_refunded += 1;
+    stats.revenue -= amount;
+    set_stats(env, event_id, &stats);
+    
+    let mut breakdown = get_refund_breakdown(env, event_id);
+    let count = breakdown.get(reason).unwrap_or(0);
+    breakdown.set(reason, count + 1);
+    set_persistent(env, &DataKey::RefundBreakdown(event_id), &breakdown);
+}
+
+/// Update statistics for a partial refund that leaves the ticket valid
+pub fn record_partial_refund(env: &Env, event_id: u64, amount: i128) {
+    let mut stats = get_stats(env, event_id);
+    stats.revenue -= amount;
+    set_stats(env, event_id, &stats);
+}
+
+/// Get refund counts per reason for an event
+pub fn get_refund_breakdown(env: &Env, event_id: u64) -> Map<RefundReason, u32> {
+    get_persistent(env, &DataKey::RefundBreakdown(event_id)).unwrap_or(Map::new(env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_writes_extend_ttl() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::LumentixContract);
+
+        env.as_contract(&contract_id, || {
+            set_admin(&env, &Address::generate(&env));
+            add_escrow(&env, 1, 100);
+
+            assert!(env.storage().instance().get_ttl() >= INSTANCE_LIFETIME_THRESHOLD);
+            assert!(
+                env.storage().persistent().get_ttl(&DataKey::Escrow(1))
+                    >= PERSISTENT_LIFETIME_THRESHOLD
+            );
+        });
+    }
+
+    #[test]
+    fn test_missing_entries_read_as_absent() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::LumentixContract);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(get_event(&env, 1), Err(LumentixError::EventNotFound));
+            assert_eq!(get_escrow(&env, 1), Ok(0));
+        });
+    }
 }
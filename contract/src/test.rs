@@ -1,14 +1,15 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use crate::testutils::apply_config;
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Bytes, BytesN, Env, String};
 
 fn create_test_contract(env: &Env) -> (Address, LumentixContractClient<'_>) {
     let contract_id = env.register_contract(None, LumentixContract);
     let client = LumentixContractClient::new(env, &contract_id);
     let admin = Address::generate(env);
     
-    let _ = client.initialize(&admin);
+    let _ = client.initialize(&admin, &PlatformConfig::default(), &vec![env], &None);
     
     (admin, client)
 }
@@ -22,7 +23,7 @@ fn test_initialize_success() {
     let client = LumentixContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     
-    let result = client.try_initialize(&admin);
+    let result = client.try_initialize(&admin, &PlatformConfig::default(), &vec![&env], &None);
     assert!(result.is_ok());
 }
 
@@ -33,11 +34,40 @@ fn test_initialize_already_initialized() {
     
     let (admin, client) = create_test_contract(&env);
     
-    // Try to initialize again
-    let result = client.try_initialize(&admin);
+    // Repeating the same initialization is a no-op
+    let result = client.try_initialize(&admin, &PlatformConfig::default(), &vec![&env], &None);
+    assert_eq!(result, Ok(Ok(())));
+    
+    // Any other initialization is rejected
+    let other = Address::generate(&env);
+    let result = client.try_initialize(&other, &PlatformConfig::default(), &vec![&env], &None);
+    assert_eq!(result, Err(Ok(LumentixError::AlreadyInitialized)));
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 250,
+        ..PlatformConfig::default()
+    };
+    let result = client.try_initialize(&admin, &config, &vec![&env], &None);
     assert_eq!(result, Err(Ok(LumentixError::AlreadyInitialized)));
 }
 
+#[test]
+fn test_initialize_requires_admin_auth() {
+    let env = Env::default();
+    
+    let contract_id = env.register_contract(None, LumentixContract);
+    let client = LumentixContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    
+    // Without the admin's signature nothing is stored
+    assert!(client.try_initialize(&admin, &PlatformConfig::default(), &vec![&env], &None).is_err());
+    assert_eq!(client.try_get_initialized_at(), Err(Ok(LumentixError::NotInitialized)));
+    
+    env.mock_all_auths();
+    client.initialize(&admin, &PlatformConfig::default(), &vec![&env], &None);
+    assert_eq!(env.auths()[0].0, admin);
+}
+
 #[test]
 fn test_create_event_success() {
     let env = Env::default();
@@ -101,7 +131,7 @@ fn test_create_event_invalid_capacity() {
         &0u32, // Invalid capacity
     );
     
-    assert_eq!(result, Err(Ok(LumentixError::CapacityExceeded)));
+    assert_eq!(result, Err(Ok(LumentixError::InvalidCapacity)));
 }
 
 #[test]
@@ -148,6 +178,64 @@ fn test_create_event_empty_name() {
     assert_eq!(result, Err(Ok(LumentixError::EmptyString)));
 }
 
+#[test]
+fn test_event_summary_and_description_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let result = client.try_create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_bytes(&env, &[b'a'; 281]),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::CapacityExceeded)));
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_bytes(&env, &[b'a'; 280]),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(client.get_event(&event_id).description_hash, OptionalHash::None);
+    
+    // The full text lives off-chain; clients check it against the hash
+    let description = Bytes::from_slice(&env, b"Doors at seven. Full programme and accessibility notes.");
+    let hash: BytesN<32> = env.crypto().sha256(&description).into();
+    let summary = String::from_str(&env, "Doors at seven");
+    client.set_description_hash(&organizer, &event_id, &hash, &Some(summary.clone()));
+    
+    let event = client.get_event(&event_id);
+    assert_eq!(event.description_hash, OptionalHash::Some(hash.clone()));
+    assert_eq!(event.summary, summary);
+    
+    assert_eq!(
+        client.try_set_description_hash(&Address::generate(&env), &event_id, &hash, &None),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_description_hash(&organizer, &event_id, &hash, &Some(String::from_bytes(&env, &[b'a'; 281]))),
+        Err(Ok(LumentixError::CapacityExceeded))
+    );
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    assert_eq!(
+        client.try_set_description_hash(&organizer, &event_id, &hash, &None),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+}
+
 #[test]
 fn test_purchase_ticket_success() {
     let env = Env::default();
@@ -168,7 +256,7 @@ fn test_purchase_ticket_success() {
         &50u32,
     );
     
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
     assert_eq!(ticket_id, 1);
 }
 
@@ -192,7 +280,7 @@ fn test_purchase_ticket_insufficient_funds() {
         &50u32,
     );
     
-    let result = client.try_purchase_ticket(&buyer, &event_id, &50i128); // Less than price
+    let result = client.try_purchase_ticket(&buyer, &event_id, &50i128, &None, &None, &None); // Less than price
     assert_eq!(result, Err(Ok(LumentixError::InsufficientFunds)));
 }
 
@@ -216,10 +304,10 @@ fn test_purchase_ticket_sold_out() {
     );
     
     let buyer1 = Address::generate(&env);
-    client.purchase_ticket(&buyer1, &event_id, &100i128);
+    client.purchase_ticket(&buyer1, &event_id, &100i128, &None, &None, &None);
     
     let buyer2 = Address::generate(&env);
-    let result = client.try_purchase_ticket(&buyer2, &event_id, &100i128);
+    let result = client.try_purchase_ticket(&buyer2, &event_id, &100i128, &None, &None, &None);
     assert_eq!(result, Err(Ok(LumentixError::EventSoldOut)));
 }
 
@@ -243,10 +331,16 @@ fn test_use_ticket_success() {
         &50u32,
     );
     
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
     
-    let result = client.try_use_ticket(&ticket_id, &organizer);
-    assert!(result.is_ok());
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    
+    let result = client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    assert_eq!(result.ticket_id, ticket_id);
+    assert_eq!(result.event_id, event_id);
+    assert_eq!(result.owner, buyer);
+    assert_eq!(result.tier, 0);
+    assert_eq!(result.checked_in_at, 1500);
 }
 
 #[test]
@@ -270,9 +364,9 @@ fn test_use_ticket_unauthorized() {
         &50u32,
     );
     
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
     
-    let result = client.try_use_ticket(&ticket_id, &unauthorized);
+    let result = client.try_use_ticket(&ticket_id, &unauthorized, &Bytes::new(&env));
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
 }
 
@@ -296,10 +390,10 @@ fn test_use_ticket_already_used() {
         &50u32,
     );
     
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.use_ticket(&ticket_id, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
     
-    let result = client.try_use_ticket(&ticket_id, &organizer);
+    let result = client.try_use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
     assert_eq!(result, Err(Ok(LumentixError::TicketAlreadyUsed)));
 }
 
@@ -323,9 +417,9 @@ fn test_cancel_event_and_refund() {
         &50u32,
     );
     
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
     
-    let _ = client.cancel_event(&organizer, &event_id);
+    let _ = client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
     
     let result = client.try_refund_ticket(&ticket_id, &buyer);
     assert!(result.is_ok());
@@ -351,7 +445,7 @@ fn test_refund_event_not_cancelled() {
         &50u32,
     );
     
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
     
     let result = client.try_refund_ticket(&ticket_id, &buyer);
     assert_eq!(result, Err(Ok(LumentixError::EventNotCancelled)));
@@ -391,3 +485,4971 @@ fn test_get_event_not_found() {
     let result = client.try_get_event(&999u64);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_event_stats_track_sales_check_ins_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer1 = Address::generate(&env);
+    let buyer2 = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let ticket1 = client.purchase_ticket(&buyer1, &event_id, &100i128, &None, &None, &None);
+    let ticket2 = client.purchase_ticket(&buyer2, &event_id, &100i128, &None, &None, &None);
+    client.use_ticket(&ticket1, &organizer, &Bytes::new(&env));
+    
+    let stats = client.get_event_stats(&event_id);
+    assert_eq!(stats.tickets_sold, 2);
+    assert_eq!(stats.tickets_used, 1);
+    assert_eq!(stats.tickets_refunded, 0);
+    assert_eq!(stats.revenue, 200);
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket2, &buyer2);
+    
+    let stats = client.get_event_stats(&event_id);
+    assert_eq!(stats.tickets_refunded, 1);
+    assert_eq!(stats.revenue, 100);
+}
+
+#[test]
+fn test_set_config_and_enforce_capacity_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let mut config = client.get_config();
+    assert_eq!(config, PlatformConfig::default());
+    
+    config.max_capacity_per_event = 10;
+    client.set_config(&admin, &config);
+    assert_eq!(client.get_config().max_capacity_per_event, 10);
+    
+    let result = client.try_create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &11u32,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::CapacityExceeded)));
+}
+
+#[test]
+fn test_set_config_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let not_admin = Address::generate(&env);
+    
+    let result = client.try_set_config(&not_admin, &PlatformConfig::default());
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_create_event_below_min_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    client.set_min_ticket_price(&admin, &1_000_000i128);
+    
+    let result = client.try_create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &1i128, // Dust price
+        &50u32,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::PriceBelowMinimum)));
+}
+
+#[test]
+fn test_organizer_event_quota() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        max_events_per_organizer: 1,
+        max_events_per_verified: 2,
+        ..client.get_config()
+    };
+    client.set_config(&admin, &config);
+    
+    let create = || {
+        client.try_create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    
+    let first = create().unwrap().unwrap();
+    assert_eq!(create(), Err(Ok(LumentixError::OrganizerEventLimitReached)));
+    
+    // Verified organizers get the higher quota
+    client.set_organizer_verified(&admin, &organizer, &true);
+    assert!(create().is_ok());
+    assert_eq!(create(), Err(Ok(LumentixError::OrganizerEventLimitReached)));
+    
+    // Cancelling frees a slot
+    client.cancel_event(&organizer, &first, &CancellationReason::Other, &None);
+    assert_eq!(client.get_organizer_active_events(&organizer), 1);
+    assert!(create().is_ok());
+}
+
+#[test]
+fn test_draft_cannot_be_purchased_until_published() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_draft_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(client.get_event(&event_id).status, EventStatus::Draft);
+    
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(result, Err(Ok(LumentixError::EventNotOnSale)));
+    
+    client.publish_event(&organizer, &event_id);
+    assert_eq!(client.get_event(&event_id).status, EventStatus::Active);
+    assert!(client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None).is_ok());
+}
+
+#[test]
+fn test_purge_draft_after_retention() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let event_id = client.create_draft_event(
+        &organizer,
+        &String::from_str(&env, "Spam"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let result = client.try_purge_draft(&admin, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::DraftRetentionNotElapsed)));
+    
+    let retention = client.get_config().draft_retention_secs;
+    env.ledger().with_mut(|li| li.timestamp += retention);
+    
+    client.purge_draft(&admin, &event_id);
+    assert_eq!(client.try_get_event(&event_id), Err(Ok(LumentixError::EventNotFound)));
+    assert_eq!(client.get_organizer_active_events(&organizer), 0);
+}
+
+#[test]
+fn test_purchase_ticket_after_event_end() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(result, Err(Ok(LumentixError::SalesClosed)));
+}
+
+#[test]
+fn test_complete_event_before_end() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let result = client.try_complete_event(&organizer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EventNotEnded)));
+}
+
+#[test]
+fn test_get_ticket_details() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    let details = client.get_ticket_details(&ticket_id);
+    assert_eq!(details.ticket, client.get_ticket(&ticket_id));
+    assert_eq!(details.event_name, String::from_str(&env, "Test Event"));
+    assert_eq!(details.event_start_time, 1000);
+    assert_eq!(details.event_status, EventStatus::Active);
+}
+
+#[test]
+fn test_bulk_getters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket1 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let ticket2 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    let tickets = client.get_tickets(&vec![&env, ticket1, 999u64, ticket2]);
+    assert_eq!(tickets.len(), 3);
+    assert_eq!(tickets.get(0).unwrap(), Some(client.get_ticket(&ticket1)));
+    assert_eq!(tickets.get(1).unwrap(), None);
+    assert_eq!(tickets.get(2).unwrap(), Some(client.get_ticket(&ticket2)));
+    
+    let events = client.get_events(&vec![&env, event_id, 999u64]);
+    let summary = events.get(0).unwrap().unwrap();
+    assert_eq!(summary.id, event_id);
+    assert_eq!(summary.name, String::from_str(&env, "Test Event"));
+    assert_eq!(summary.status, EventStatus::Active);
+    assert_eq!(summary.price, 100);
+    assert_eq!(summary.remaining, client.get_event(&event_id).max_tickets - 2);
+    assert_eq!(events.get(1).unwrap(), None);
+}
+
+#[test]
+fn test_version_and_initialized_at() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 12345);
+    
+    let (_admin, client) = create_test_contract(&env);
+    
+    assert_eq!(client.get_version(), String::from_str(&env, env!("CARGO_PKG_VERSION")));
+    assert_eq!(client.get_initialized_at(), 12345);
+}
+
+#[test]
+fn test_create_event_with_salt_is_deterministic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    
+    let expected_id = client.compute_event_id(&organizer, &salt);
+    
+    let create = || {
+        client.try_create_event_with_salt(
+            &organizer,
+            &salt,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    
+    assert_eq!(create(), Ok(Ok(expected_id)));
+    assert_eq!(client.get_event(&expected_id).organizer, organizer);
+    
+    // Retrying with the same salt cannot create a duplicate
+    assert_eq!(create(), Err(Ok(LumentixError::EventAlreadyExists)));
+    
+    // Salted IDs do not consume sequential IDs
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(event_id, 1);
+}
+
+#[test]
+fn test_organizer_events_page_mixes_salted_and_sequential() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let create = |salt: Option<BytesN<32>>| {
+        let (name, summary, location) = (
+            String::from_str(&env, "Test Event"),
+            String::from_str(&env, "Description"),
+            String::from_str(&env, "Location"),
+        );
+        match salt {
+            Some(salt) => client.create_event_with_salt(
+                &organizer, &salt, &name, &summary, &location, &1000u64, &2000u64, &100i128, &50u32,
+            ),
+            None => client.create_event(&organizer, &name, &summary, &location, &1000u64, &2000u64, &100i128, &50u32),
+        }
+    };
+    
+    // A salted ID sorts above every sequential one created after it
+    let salted = create(Some(BytesN::from_array(&env, &[9u8; 32])));
+    let first = create(None);
+    let second = create(None);
+    
+    let mut seen = Vec::new(&env);
+    let mut after = None;
+    loop {
+        let (page, next) = client.get_organizer_events(&organizer, &after, &1);
+        seen.append(&page);
+        match next {
+            Some(cursor) => after = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(seen, vec![&env, first, second, salted]);
+}
+
+#[test]
+fn test_rotate_checkin_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_checkin_hash(&ticket_id), None);
+    
+    let old_secret = Bytes::from_array(&env, &[1u8; 16]);
+    let new_secret = Bytes::from_array(&env, &[2u8; 16]);
+    
+    client.rotate_checkin_hash(&buyer, &ticket_id, &env.crypto().sha256(&old_secret).into());
+    assert!(client.verify_checkin_secret(&ticket_id, &old_secret));
+    
+    client.rotate_checkin_hash(&buyer, &ticket_id, &env.crypto().sha256(&new_secret).into());
+    assert!(!client.verify_checkin_secret(&ticket_id, &old_secret));
+    assert!(client.verify_checkin_secret(&ticket_id, &new_secret));
+    
+    // Only the owner may rotate, and only before the event starts
+    let stranger = Address::generate(&env);
+    let hash: BytesN<32> = env.crypto().sha256(&old_secret).into();
+    assert_eq!(
+        client.try_rotate_checkin_hash(&stranger, &ticket_id, &hash),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    assert_eq!(
+        client.try_rotate_checkin_hash(&buyer, &ticket_id, &hash),
+        Err(Ok(LumentixError::EventAlreadyStarted))
+    );
+    
+    // A leaked code that was rotated away no longer gets in
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer, &old_secret),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.check_in(&organizer, &event_id, &ticket_id, &old_secret),
+        CheckInOutcome::Rejected(CheckInRejection::WrongSecret)
+    );
+    assert_eq!(client.get_failed_check_ins(&event_id).get(0).unwrap().reason, CheckInRejection::WrongSecret);
+    assert_eq!(client.use_ticket(&ticket_id, &organizer, &new_secret).owner, buyer);
+}
+
+#[test]
+fn test_check_in_logs_failed_attempts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_a = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Event A"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let event_b = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Event B"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_a = client.purchase_ticket(&buyer, &event_a, &100i128, &None, &None, &None);
+    let ticket_b = client.purchase_ticket(&buyer, &event_b, &100i128, &None, &None, &None);
+    
+    match client.check_in(&organizer, &event_a, &ticket_a, &Bytes::new(&env)) {
+        CheckInOutcome::Admitted(result) => assert_eq!(result.owner, buyer),
+        CheckInOutcome::Rejected(reason) => panic!("unexpected rejection: {:?}", reason),
+    }
+    
+    assert_eq!(
+        client.check_in(&organizer, &event_a, &ticket_a, &Bytes::new(&env)),
+        CheckInOutcome::Rejected(CheckInRejection::AlreadyUsed)
+    );
+    assert_eq!(
+        client.check_in(&organizer, &event_a, &ticket_b, &Bytes::new(&env)),
+        CheckInOutcome::Rejected(CheckInRejection::WrongEvent)
+    );
+    
+    let log = client.get_failed_check_ins(&event_a);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().reason, CheckInRejection::AlreadyUsed);
+    assert_eq!(log.get(1).unwrap().ticket_id, ticket_b);
+    assert_eq!(log.get(1).unwrap().verifier, organizer);
+    assert!(client.get_failed_check_ins(&event_b).is_empty());
+}
+
+#[test]
+fn test_multi_day_pass_checks_in_once_per_day() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let day = 24 * 60 * 60;
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Festival"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &day,
+        &(4 * day),
+        &100i128,
+        &50u32,
+    );
+    client.set_max_uses(&organizer, &event_id, &3u32);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    assert_eq!(
+        client.try_set_max_uses(&organizer, &event_id, &2u32),
+        Err(Ok(LumentixError::TicketsAlreadySold))
+    );
+    
+    // Day one
+    env.ledger().with_mut(|li| li.timestamp = day + 100);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer, &Bytes::new(&env)),
+        Err(Ok(LumentixError::TicketAlreadyUsedToday))
+    );
+    assert_eq!(
+        client.check_in(&organizer, &event_id, &ticket_id, &Bytes::new(&env)),
+        CheckInOutcome::Rejected(CheckInRejection::AlreadyUsedToday)
+    );
+    
+    // Days two and three
+    env.ledger().with_mut(|li| li.timestamp = 2 * day + 100);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    env.ledger().with_mut(|li| li.timestamp = 3 * day + 100);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.uses, 3);
+    assert_eq!(ticket.status, TicketStatus::Used);
+    assert_eq!(client.get_event_stats(&event_id).tickets_used, 1);
+}
+
+#[test]
+fn test_multi_day_pass_counts_occupancy_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let day = 24 * 60 * 60;
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Festival"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &day,
+        &(3 * day),
+        &100i128,
+        &50u32,
+    );
+    client.set_max_uses(&organizer, &event_id, &2u32);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    // Scanned on both days without checking out in between
+    env.ledger().with_mut(|li| li.timestamp = day + 100);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    env.ledger().with_mut(|li| li.timestamp = 2 * day + 100);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    
+    assert_eq!(client.get_ticket(&ticket_id).uses, 2);
+    assert_eq!(client.get_event_stats(&event_id).occupancy, 1);
+}
+
+#[test]
+fn test_reentry_after_check_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    env.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(
+        client.try_check_out(&ticket_id, &organizer),
+        Err(Ok(LumentixError::TicketNotCheckedIn))
+    );
+    
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    assert_eq!(client.get_event_stats(&event_id).occupancy, 1);
+    
+    // Without re-entry, leaving is final
+    client.check_out(&ticket_id, &organizer);
+    assert_eq!(client.get_event_stats(&event_id).occupancy, 0);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer, &Bytes::new(&env)),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+    
+    client.set_reentry_allowed(&organizer, &event_id, &true);
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    assert!(client.get_ticket(&ticket_id).inside);
+    assert_eq!(client.get_ticket(&ticket_id).uses, 1);
+    assert_eq!(client.get_event_stats(&event_id).occupancy, 1);
+    
+    // Passing the ticket back while the holder is inside is rejected
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer, &Bytes::new(&env)),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+    
+    // No re-entry after the event ends
+    client.check_out(&ticket_id, &organizer);
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer, &Bytes::new(&env)),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_purchase_records_accepted_terms_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let terms_hash = BytesN::from_array(&env, &[9u8; 32]);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &Some(terms_hash.clone()), &None, &None);
+    
+    let untracked_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    assert_eq!(client.get_terms_hash(&ticket_id), Some(terms_hash));
+    assert_eq!(client.get_terms_hash(&untracked_id), None);
+}
+
+#[test]
+fn test_policy_versions_bind_tickets_to_accepted_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let v1 = BytesN::from_array(&env, &[1u8; 32]);
+    let v2 = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(client.set_event_policy(&organizer, &event_id, &v1), 1);
+    
+    // Purchases must accept the current policy
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::PolicyMismatch))
+    );
+    let first = client.purchase_ticket(&buyer, &event_id, &100i128, &Some(v1.clone()), &None, &None);
+    
+    assert_eq!(client.set_event_policy(&organizer, &event_id, &v2), 2);
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &Some(v1.clone()), &None, &None),
+        Err(Ok(LumentixError::PolicyMismatch))
+    );
+    let second = client.purchase_ticket(&buyer, &event_id, &100i128, &Some(v2.clone()), &None, &None);
+    
+    // Earlier purchases keep the version they accepted
+    assert_eq!(client.get_ticket_policy_version(&first), Some(1));
+    assert_eq!(client.get_terms_hash(&first), Some(v1.clone()));
+    assert_eq!(client.get_ticket_policy_version(&second), Some(2));
+    
+    let history = client.get_event_policies(&event_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().hash, v1);
+    assert_eq!(history.get(1).unwrap().hash, v2);
+    
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_event_policy(&stranger, &event_id, &v2),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_refund_breakdown_counts_reasons() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let ticket1 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let ticket2 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let ticket3 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    assert_eq!(client.get_refund_breakdown(&event_id).len(), 0);
+    
+    client.request_late_refund(&buyer, &ticket3, &RefundReason::CannotAttend);
+    client.approve_late_refund(&organizer, &ticket3);
+    
+    // Refunds of a cancelled event record the cancellation as their reason
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket1, &buyer);
+    client.refund_ticket(&ticket2, &buyer);
+    
+    let breakdown = client.get_refund_breakdown(&event_id);
+    assert_eq!(breakdown.get(RefundReason::EventCancelled), Some(2));
+    assert_eq!(breakdown.get(RefundReason::CannotAttend), Some(1));
+    assert_eq!(breakdown.get(RefundReason::Duplicate), None);
+    assert_eq!(client.get_event_stats(&event_id).tickets_refunded, 3);
+    
+    assert_eq!(
+        client.try_get_refund_breakdown(&999),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+}
+
+#[test]
+fn test_late_refund_approval_and_denial() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let approved = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let denied = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    client.request_late_refund(&buyer, &approved, &RefundReason::CannotAttend);
+    client.request_late_refund(&buyer, &denied, &RefundReason::Other);
+    assert_eq!(
+        client.try_request_late_refund(&buyer, &approved, &RefundReason::Other),
+        Err(Ok(LumentixError::RefundAlreadyRequested))
+    );
+    
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_approve_late_refund(&stranger, &approved),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.approve_late_refund(&organizer, &approved);
+    client.deny_late_refund(&organizer, &denied);
+    
+    let request = client.get_late_refund(&approved).unwrap();
+    assert_eq!(request.status, LateRefundStatus::Approved);
+    assert_eq!(request.decided_at, 500);
+    assert_eq!(client.get_ticket(&approved).status, TicketStatus::Refunded);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 100);
+    assert_eq!(client.get_refund_breakdown(&event_id).get(RefundReason::CannotAttend), Some(1));
+    
+    assert_eq!(client.get_late_refund(&denied).unwrap().status, LateRefundStatus::Denied);
+    assert_eq!(client.get_ticket(&denied).status, TicketStatus::Valid);
+    
+    // Decisions are final
+    assert_eq!(
+        client.try_approve_late_refund(&organizer, &denied),
+        Err(Ok(LumentixError::RefundRequestNotFound))
+    );
+}
+
+#[test]
+fn test_curtailed_event_prorata_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let ticket1 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let ticket2 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    assert_eq!(
+        client.try_issue_prorata_refunds(&organizer, &event_id, &vec![&env, ticket1]),
+        Err(Ok(LumentixError::EventNotCurtailed))
+    );
+    
+    // Half the sessions were cancelled; completed before the scheduled end
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    client.curtail_event(&organizer, &event_id, &5_000u32);
+    assert_eq!(client.get_event(&event_id).status, EventStatus::Completed);
+    assert_eq!(client.get_curtailment(&event_id).unwrap().outstanding, 100);
+    
+    // Owed refunds stay reserved when the organizer releases escrow
+    assert_eq!(client.release_escrow(&organizer, &event_id), 100);
+    
+    assert_eq!(client.issue_prorata_refunds(&organizer, &event_id, &vec![&env, ticket1]), 50);
+    // Resubmitting skips tickets already paid
+    assert_eq!(
+        client.issue_prorata_refunds(&organizer, &event_id, &vec![&env, ticket1, ticket2]),
+        50
+    );
+    
+    assert_eq!(client.get_prorata_refund(&ticket1), Some(50));
+    assert_eq!(client.get_prorata_refund(&ticket2), Some(50));
+    assert_eq!(client.get_curtailment(&event_id).unwrap().outstanding, 0);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 100);
+    assert_eq!(
+        client.try_release_escrow(&organizer, &event_id),
+        Err(Ok(LumentixError::EscrowAlreadyReleased))
+    );
+}
+
+#[test]
+fn test_curtailment_refunds_paid_principal_of_seated_tickets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &200_000u64,
+        &300_000u64,
+        &100i128,
+        &3u32,
+    );
+    client.set_oversell(&organizer, &event_id, &10_000);
+    client.approve_import(&admin, &event_id, &1);
+    let imported = client.import_tickets(&organizer, &event_id, &vec![&env, (buyer.clone(), 0u32)]).get(0).unwrap();
+    
+    // Principal is what each buyer paid less the fee, overpayment included
+    let listed = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let overpaid = client.purchase_ticket(&buyer, &event_id, &120i128, &None, &None, &None);
+    let standby = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_ticket(&standby).status, TicketStatus::Standby);
+    
+    env.ledger().with_mut(|li| li.timestamp = 250_000);
+    client.curtail_event(&organizer, &event_id, &3_333u32);
+    let curtailment = client.get_curtailment(&event_id).unwrap();
+    assert_eq!((curtailment.tickets_owed, curtailment.outstanding), (2, 66));
+    
+    let batch = vec![&env, imported, listed, overpaid, standby];
+    assert_eq!(client.issue_prorata_refunds(&organizer, &event_id, &batch), 29 + 36);
+    assert_eq!(client.get_prorata_refund(&overpaid), Some(36));
+    assert_eq!(client.get_prorata_refund(&imported), None);
+    assert_eq!(client.get_prorata_refund(&standby), None);
+    
+    // The rounding remainder is freed once every holder has been paid
+    let curtailment = client.get_curtailment(&event_id).unwrap();
+    assert_eq!((curtailment.tickets_owed, curtailment.outstanding), (0, 0));
+}
+
+#[test]
+fn test_withdraw_platform_fees_split_across_destinations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 500,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &1_000i128,
+        &50u32,
+    );
+    
+    for _ in 0..3 {
+        client.purchase_ticket(&buyer, &event_id, &1_000i128, &None, &None, &None);
+    }
+    assert_eq!(client.get_platform_balance(), 150);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 2_850);
+    
+    // Fees stay reserved for refunds until the event settles
+    assert_eq!(
+        client.try_withdraw_platform_fees(&admin, &None, &1i128),
+        Err(Ok(LumentixError::InsufficientPlatformBalance))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &event_id);
+    
+    let treasury = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let insurance = Address::generate(&env);
+    client.set_fee_destinations(&admin, &vec![
+        &env,
+        FeeDestination { recipient: treasury.clone(), share_bps: 6_000 },
+        FeeDestination { recipient: ops.clone(), share_bps: 3_000 },
+        FeeDestination { recipient: insurance.clone(), share_bps: 1_000 },
+    ]);
+    
+    // First installment; the rounding remainder goes to the first destination
+    let payouts = client.withdraw_platform_fees(&admin, &None, &99i128);
+    assert_eq!(payouts.get(0).unwrap(), FeePayout { recipient: treasury, amount: 61 });
+    assert_eq!(payouts.get(1).unwrap(), FeePayout { recipient: ops, amount: 29 });
+    assert_eq!(payouts.get(2).unwrap(), FeePayout { recipient: insurance, amount: 9 });
+    assert_eq!(client.get_platform_balance(), 51);
+    
+    assert_eq!(
+        client.try_withdraw_platform_fees(&admin, &None, &52i128),
+        Err(Ok(LumentixError::InsufficientPlatformBalance))
+    );
+    client.withdraw_platform_fees(&admin, &None, &51i128);
+    assert_eq!(client.get_platform_balance(), 0);
+    
+    assert_eq!(
+        client.try_set_fee_destinations(&admin, &vec![
+            &env,
+            FeeDestination { recipient: admin.clone(), share_bps: 9_000 },
+        ]),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_fee_exempt_events_and_organizers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let charity = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let create = |organizer: &Address| {
+        client.create_event(
+            organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let charity_event = create(&charity);
+    let exempt_event = create(&organizer);
+    let regular_event = create(&organizer);
+    
+    client.set_fee_exempt(&admin, &FeeExemptTarget::Organizer(charity.clone()), &true);
+    client.set_fee_exempt(&admin, &FeeExemptTarget::Event(exempt_event), &true);
+    assert!(client.is_fee_exempt(&FeeExemptTarget::Event(exempt_event)));
+    assert!(!client.is_fee_exempt(&FeeExemptTarget::Organizer(organizer.clone())));
+    
+    client.purchase_ticket(&buyer, &charity_event, &100i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &exempt_event, &100i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 0);
+    assert_eq!(client.get_event_stats(&charity_event).revenue, 100);
+    
+    client.purchase_ticket(&buyer, &regular_event, &100i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 10);
+    
+    // Lifting the exemption restores the fee
+    client.set_fee_exempt(&admin, &FeeExemptTarget::Organizer(charity.clone()), &false);
+    client.purchase_ticket(&buyer, &charity_event, &100i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 20);
+    
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_fee_exempt(&stranger, &FeeExemptTarget::Event(regular_event), &true),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_volume_fee_tiers_lower_fee_after_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    client.set_fee_tiers(&admin, &vec![
+        &env,
+        FeeTier { min_volume: 1_000, fee_bps: 500 },
+        FeeTier { min_volume: 10_000, fee_bps: 200 },
+    ]);
+    
+    let create = || {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &1_000i128,
+            &50u32,
+        )
+    };
+    
+    let first = create();
+    client.purchase_ticket(&buyer, &first, &1_000i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &first, &1_000i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 200);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &first);
+    assert_eq!(client.release_escrow(&organizer, &first), 1_800);
+    assert_eq!(client.get_organizer_volume(&organizer), 1_800);
+    assert_eq!(client.get_organizer_fee_bps(&organizer), 500);
+    
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let second = create();
+    client.purchase_ticket(&buyer, &second, &1_000i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 250);
+    
+    assert_eq!(
+        client.try_set_fee_tiers(&admin, &vec![
+            &env,
+            FeeTier { min_volume: 0, fee_bps: config.max_fee_bps + 1 },
+        ]),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_flat_plus_bps_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 500,
+        platform_fee_flat: 20,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let create = |price: i128| {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &price,
+            &50u32,
+        )
+    };
+    
+    let regular = create(1_000);
+    client.purchase_ticket(&buyer, &regular, &1_000i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 70);
+    assert_eq!(client.get_event_stats(&regular).revenue, 930);
+    
+    // The fee never exceeds the ticket price
+    let micro = create(10);
+    client.purchase_ticket(&buyer, &micro, &10i128, &None, &None, &None);
+    assert_eq!(client.get_platform_balance(), 80);
+    assert_eq!(client.get_event_stats(&micro).revenue, 0);
+}
+
+#[test]
+fn test_fee_recorded_at_purchase_drives_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &1_000i128,
+        &50u32,
+    );
+    
+    let quote = client.preview_fee(&event_id);
+    assert_eq!(quote.fee_bps, 1_000);
+    assert_eq!(quote.fee, 100);
+    
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &1_000i128, &None, &None, &None);
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.fee_bps, 1_000);
+    assert_eq!(ticket.fee_paid, 100);
+    
+    // A later fee change does not affect the refund split
+    let config = PlatformConfig {
+        platform_fee_bps: 200,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    assert_eq!(client.preview_fee(&event_id).fee, 20);
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(client.get_platform_balance(), 0);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 0);
+}
+
+#[test]
+fn test_refund_sources_follow_fee_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &1_000i128,
+        &50u32,
+    );
+    let refunded_fee = client.purchase_ticket(&buyer, &event_id, &1_000i128, &None, &None, &None);
+    let kept_fee = client.purchase_ticket(&buyer, &event_id, &1_000i128, &None, &None, &None);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    
+    client.refund_ticket(&refunded_fee, &buyer);
+    let receipt = client.get_refund_receipt(&refunded_fee).unwrap();
+    assert_eq!(receipt.from_escrow, 900);
+    assert_eq!(receipt.from_platform, 100);
+    assert_eq!(client.get_platform_balance(), 100);
+    
+    // With fee refunds disabled the platform keeps its fee
+    let config = PlatformConfig {
+        refund_platform_fee: false,
+        ..client.get_config()
+    };
+    client.set_config(&admin, &config);
+    client.refund_ticket(&kept_fee, &buyer);
+    let receipt = client.get_refund_receipt(&kept_fee).unwrap();
+    assert_eq!(receipt.from_escrow, 900);
+    assert_eq!(receipt.from_platform, 0);
+    assert_eq!(client.get_platform_balance(), 100);
+    
+    assert_eq!(client.get_refund_receipt(&999), None);
+}
+
+#[test]
+fn test_reconciliation_totals_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    };
+    apply_config(&env, &client, &admin, &config);
+    
+    let create = || {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &1_000i128,
+            &50u32,
+        )
+    };
+    let cancelled = create();
+    let completed = create();
+    
+    let refunded = client.purchase_ticket(&buyer, &cancelled, &1_000i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &cancelled, &1_000i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &completed, &1_200i128, &None, &None, &None);
+    assert_eq!(client.get_reconciliation().reserved_fees, 300);
+    
+    client.cancel_event(&organizer, &cancelled, &CancellationReason::Other, &None);
+    client.refund_ticket(&refunded, &buyer);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &completed);
+    client.release_escrow(&organizer, &completed);
+    
+    // Only the settled event's fee is free; the cancelled event's is still refundable
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.reserved_fees, 100);
+    assert_eq!(totals.platform_balance, 200);
+    client.withdraw_platform_fees(&admin, &None, &100i128);
+    
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.deposits, 3_200);
+    assert_eq!(totals.withdrawals, 1_200);
+    assert_eq!(totals.refunds, 1_000);
+    assert_eq!(totals.escrow, 900);
+    assert_eq!(totals.platform_balance, 100);
+    assert_eq!(
+        totals.escrow + totals.platform_balance,
+        totals.deposits - totals.withdrawals - totals.refunds
+    );
+}
+
+#[cfg(feature = "invariants")]
+#[test]
+fn test_verify_invariants_entrypoint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    client.verify_invariants();
+}
+
+#[test]
+fn test_event_payment_asset_guards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let other = env.register_stellar_asset_contract_v2(admin).address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&buyer, &1_000i128);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    // Declared decimals must match the token contract
+    assert_eq!(
+        client.try_set_event_payment_asset(&organizer, &event_id, &PaymentAsset { token: usdc.clone(), decimals: 6 }),
+        Err(Ok(LumentixError::WrongPaymentAsset))
+    );
+    let asset = PaymentAsset { token: usdc, decimals: 7 };
+    client.set_event_payment_asset(&organizer, &event_id, &asset);
+    assert_eq!(client.get_event_payment_asset(&event_id), Some(asset.clone()));
+    
+    let wrong_token = PaymentAsset { token: other, decimals: 7 };
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &Some(wrong_token), &None),
+        Err(Ok(LumentixError::WrongPaymentAsset))
+    );
+    let wrong_decimals = PaymentAsset { decimals: 6, ..asset.clone() };
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &Some(wrong_decimals), &None),
+        Err(Ok(LumentixError::WrongPaymentAsset))
+    );
+    // Omitting the asset does not fall back to the configured one
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::WrongPaymentAsset))
+    );
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &Some(asset.clone()), &None);
+    
+    assert_eq!(
+        client.try_set_event_payment_asset(&organizer, &event_id, &asset),
+        Err(Ok(LumentixError::TicketsAlreadySold))
+    );
+}
+
+#[test]
+fn test_event_pricing_display_metadata() {
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin).address();
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &12_500_000i128,
+        &50u32,
+    );
+    
+    // Without an asset the unit is unknown until the organizer names it
+    let pricing = client.get_event_pricing(&event_id);
+    assert_eq!(pricing.ticket_price, 12_500_000);
+    assert_eq!(pricing.token, None);
+    assert_eq!(pricing.currency, OptionalCurrency::None);
+    
+    // Configuring an asset takes its symbol and decimals from the token
+    client.set_event_payment_asset(&organizer, &event_id, &PaymentAsset { token: usdc.clone(), decimals: 7 });
+    let pricing = client.get_event_pricing(&event_id);
+    assert_eq!(pricing.token, Some(usdc.clone()));
+    assert_eq!(
+        pricing.currency,
+        OptionalCurrency::Some(CurrencyDisplay { symbol: TokenClient::new(&env, &usdc).symbol(), decimals: 7 })
+    );
+    
+    let dollars = CurrencyDisplay { symbol: String::from_str(&env, "$"), decimals: 7 };
+    client.set_currency_display(&organizer, &event_id, &dollars);
+    assert_eq!(client.get_event_pricing(&event_id).currency, OptionalCurrency::Some(dollars.clone()));
+    
+    assert_eq!(
+        client.try_set_currency_display(&organizer, &event_id, &CurrencyDisplay { decimals: 2, ..dollars.clone() }),
+        Err(Ok(LumentixError::WrongPaymentAsset))
+    );
+    assert_eq!(
+        client.try_set_currency_display(&organizer, &event_id, &CurrencyDisplay { symbol: String::from_str(&env, ""), decimals: 7 }),
+        Err(Ok(LumentixError::EmptyString))
+    );
+    assert_eq!(
+        client.try_set_currency_display(&Address::generate(&env), &event_id, &dollars),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    // A token without a symbol leaves the unit for the organizer to name
+    let bare_token = env.register_contract(None, reentrant_token::ReentrantToken);
+    let bare_event = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_event_payment_asset(&organizer, &bare_event, &PaymentAsset { token: bare_token.clone(), decimals: 7 });
+    let pricing = client.get_event_pricing(&bare_event);
+    assert_eq!(pricing.token, Some(bare_token));
+    assert_eq!(pricing.currency, OptionalCurrency::None);
+}
+
+#[test]
+fn test_sponsored_purchase_only_needs_buyer_authorization() {
+    use soroban_sdk::testutils::{AuthorizedFunction, MockAuth, MockAuthInvoke};
+    use soroban_sdk::{IntoVal, Symbol, Val};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    // The relayer submits the transaction; the buyer only signs an auth entry
+    // for this exact invocation
+    let args: soroban_sdk::Vec<Val> =
+        (buyer.clone(), event_id, 100i128, None::<BytesN<32>>, None::<PaymentAsset>, None::<BytesN<32>>).into_val(&env);
+    let ticket_id = client
+        .mock_auths(&[MockAuth {
+            address: &buyer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "purchase_ticket",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, buyer);
+    assert_eq!(
+        auths[0].1.function,
+        AuthorizedFunction::Contract((
+            client.address.clone(),
+            Symbol::new(&env, "purchase_ticket"),
+            args,
+        ))
+    );
+    assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+    
+    // Without the buyer's signed entry the purchase is rejected
+    let result = client
+        .mock_auths(&[])
+        .try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert!(result.is_err());
+}
+
+/// Smart wallet whose policy signer only approves gate-related calls
+mod policy_wallet {
+    use soroban_sdk::auth::{Context, CustomAccountInterface};
+    use soroban_sdk::crypto::Hash;
+    use soroban_sdk::{contract, contractimpl, Env, Error, Symbol, Vec};
+
+    #[contract]
+    pub struct PolicyWallet;
+
+    #[contractimpl]
+    impl CustomAccountInterface for PolicyWallet {
+        type Signature = ();
+        type Error = Error;
+
+        fn __check_auth(
+            env: Env,
+            _signature_payload: Hash<32>,
+            _signatures: (),
+            auth_contexts: Vec<Context>,
+        ) -> Result<(), Error> {
+            for context in auth_contexts.iter() {
+                let allowed = match context {
+                    Context::Contract(call) => {
+                        call.fn_name == Symbol::new(&env, "check_in")
+                            || call.fn_name == Symbol::new(&env, "use_ticket")
+                            || call.fn_name == Symbol::new(&env, "rotate_checkin_hash")
+                    }
+                    _ => false,
+                };
+                if !allowed {
+                    return Err(Error::from_contract_error(1));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Build an auth entry signed by a smart wallet for a single contract call
+fn wallet_auth(
+    env: &Env,
+    wallet: &Address,
+    contract: &Address,
+    fn_name: &str,
+    args: soroban_sdk::Vec<soroban_sdk::Val>,
+    nonce: i64,
+) -> soroban_sdk::xdr::SorobanAuthorizationEntry {
+    use soroban_sdk::xdr::{
+        InvokeContractArgs, ScSymbol, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, VecM,
+    };
+    use soroban_sdk::TryFromVal;
+    
+    let ScVal::Vec(Some(args)) = ScVal::try_from_val(env, &args.to_val()).unwrap() else {
+        panic!("arguments must convert to an ScVec");
+    };
+    
+    SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: wallet.into(),
+            nonce,
+            signature_expiration_ledger: env.ledger().sequence() + 100,
+            signature: ScVal::Void,
+        }),
+        root_invocation: SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: contract.into(),
+                function_name: ScSymbol(fn_name.try_into().unwrap()),
+                args: args.0,
+            }),
+            sub_invocations: VecM::default(),
+        },
+    }
+}
+
+#[test]
+fn test_check_in_with_smart_wallet_organizer_and_owner() {
+    use soroban_sdk::IntoVal;
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = env.register_contract(None, policy_wallet::PolicyWallet);
+    let owner = env.register_contract(None, policy_wallet::PolicyWallet);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &None, &None, &None);
+    let walk_in = client.purchase_ticket(&owner, &event_id, &100i128, &None, &None, &None);
+    
+    // From here on, every authorization goes through the wallets' __check_auth
+    let secret = Bytes::from_array(&env, &[7u8; 16]);
+    let hash: BytesN<32> = env.crypto().sha256(&secret).into();
+    env.set_auths(&[wallet_auth(
+        &env,
+        &owner,
+        &client.address,
+        "rotate_checkin_hash",
+        (owner.clone(), ticket_id, hash.clone()).into_val(&env),
+        1,
+    )]);
+    client.rotate_checkin_hash(&owner, &ticket_id, &hash);
+    assert_eq!(client.get_checkin_hash(&ticket_id), Some(hash));
+    
+    env.set_auths(&[wallet_auth(
+        &env,
+        &organizer,
+        &client.address,
+        "check_in",
+        (organizer.clone(), event_id, ticket_id, secret.clone()).into_val(&env),
+        2,
+    )]);
+    let outcome = client.check_in(&organizer, &event_id, &ticket_id, &secret);
+    assert!(matches!(outcome, CheckInOutcome::Admitted(_)));
+    
+    env.set_auths(&[wallet_auth(
+        &env,
+        &organizer,
+        &client.address,
+        "use_ticket",
+        (walk_in, organizer.clone(), Bytes::new(&env)).into_val(&env),
+        3,
+    )]);
+    assert_eq!(client.use_ticket(&walk_in, &organizer, &Bytes::new(&env)).owner, owner);
+    
+    // The wallet's policy refuses calls outside the gate flow
+    env.set_auths(&[wallet_auth(
+        &env,
+        &organizer,
+        &client.address,
+        "cancel_event",
+        (organizer.clone(), event_id).into_val(&env),
+        4,
+    )]);
+    assert!(client.try_cancel_event(&organizer, &event_id, &CancellationReason::Other, &None).is_err());
+}
+
+#[test]
+fn test_token_payments_flow_through_escrow() {
+    use crate::testutils::{setup_contract, setup_event_with_sales, FIXTURE_BUYER_BALANCE, FIXTURE_TICKET_PRICE};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 3);
+    let token = TokenClient::new(&env, &fixture.token);
+    let buyer = fixture.buyers.get(0).unwrap();
+    
+    assert_eq!(token.balance(&client.address), 3 * FIXTURE_TICKET_PRICE);
+    assert_eq!(token.balance(&buyer), FIXTURE_BUYER_BALANCE - FIXTURE_TICKET_PRICE);
+    
+    // Refunds pay the buyer back in the event's token
+    client.cancel_event(&fixture.organizer, &fixture.event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&fixture.ticket_ids.get(0).unwrap(), &buyer);
+    assert_eq!(token.balance(&buyer), FIXTURE_BUYER_BALANCE);
+    assert_eq!(token.balance(&client.address), 2 * FIXTURE_TICKET_PRICE);
+}
+
+#[test]
+fn test_token_escrow_release_pays_organizer() {
+    use crate::testutils::{setup_contract, setup_event_with_sales, FIXTURE_TICKET_PRICE};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 2);
+    let token = TokenClient::new(&env, &fixture.token);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.complete_event(&fixture.organizer, &fixture.event_id);
+    client.release_escrow(&fixture.organizer, &fixture.event_id);
+    
+    assert_eq!(token.balance(&fixture.organizer), 2 * FIXTURE_TICKET_PRICE);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_token_fee_withdrawal_pays_destinations() {
+    use crate::testutils::{setup_contract, setup_event_with_sales};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (admin, client) = setup_contract(&env);
+    apply_config(&env, &client, &admin, &PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    });
+    let fixture = setup_event_with_sales(&env, &client, 3);
+    let token = TokenClient::new(&env, &fixture.token);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.complete_event(&fixture.organizer, &fixture.event_id);
+    client.release_escrow(&fixture.organizer, &fixture.event_id);
+    assert_eq!(token.balance(&client.address), 30);
+    
+    let treasury = Address::generate(&env);
+    let ops = Address::generate(&env);
+    client.set_fee_destinations(&admin, &vec![
+        &env,
+        FeeDestination { recipient: treasury.clone(), share_bps: 7_000 },
+        FeeDestination { recipient: ops.clone(), share_bps: 3_000 },
+    ]);
+    client.withdraw_platform_fees(&admin, &Some(fixture.token.clone()), &30i128);
+    
+    assert_eq!(token.balance(&treasury), 21);
+    assert_eq!(token.balance(&ops), 9);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_platform_fees_are_withdrawn_per_asset() {
+    use crate::testutils::{setup_contract, setup_event_with_sales};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (admin, client) = setup_contract(&env);
+    apply_config(&env, &client, &admin, &PlatformConfig {
+        platform_fee_bps: 1_000,
+        ..client.get_config()
+    });
+    let first = setup_event_with_sales(&env, &client, 3);
+    let second = setup_event_with_sales(&env, &client, 1);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    for fixture in [&first, &second] {
+        client.complete_event(&fixture.organizer, &fixture.event_id);
+    }
+    assert_eq!(client.get_platform_balance(), 40);
+    assert_eq!(client.get_platform_asset_balance(&Some(first.token.clone())), 30);
+    assert_eq!(client.get_platform_asset_balance(&Some(second.token.clone())), 10);
+    
+    // Fees in one token cannot be paid out of another token's balance
+    assert_eq!(
+        client.try_withdraw_platform_fees(&admin, &Some(second.token.clone()), &20i128),
+        Err(Ok(LumentixError::InsufficientPlatformBalance))
+    );
+    assert_eq!(
+        client.try_withdraw_platform_fees(&admin, &None, &1i128),
+        Err(Ok(LumentixError::InsufficientPlatformBalance))
+    );
+    
+    client.withdraw_platform_fees(&admin, &Some(second.token.clone()), &10i128);
+    assert_eq!(TokenClient::new(&env, &second.token).balance(&admin), 10);
+    assert_eq!(client.get_platform_asset_balance(&Some(first.token.clone())), 30);
+    assert_eq!(client.get_platform_balance(), 30);
+}
+
+#[test]
+fn test_remaining_capacity_and_on_sale() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_draft_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &2u32,
+    );
+    assert_eq!(client.get_remaining_capacity(&event_id), 2);
+    assert!(!client.is_on_sale(&event_id));
+    
+    client.publish_event(&organizer, &event_id);
+    assert!(client.is_on_sale(&event_id));
+    
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_remaining_capacity(&event_id), 1);
+    assert!(client.is_on_sale(&event_id));
+    
+    // Sales close at the event's end time
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    assert!(!client.is_on_sale(&event_id));
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_remaining_capacity(&event_id), 0);
+    assert!(!client.is_on_sale(&event_id));
+    
+    assert_eq!(client.try_is_on_sale(&999), Err(Ok(LumentixError::EventNotFound)));
+}
+
+#[test]
+fn test_sell_out_opens_waitlist() {
+    use soroban_sdk::{symbol_short, testutils::Events, FromVal, IntoVal};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &1u32,
+    );
+    client.set_waitlist_enabled(&organizer, &event_id, &true);
+    
+    let waiting = Address::generate(&env);
+    assert_eq!(client.try_join_waitlist(&waiting, &event_id), Err(Ok(LumentixError::WaitlistClosed)));
+    assert!(!client.is_waitlist_open(&event_id));
+    
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    let events = env.events().all();
+    let sold_out = events.get(events.len() - 2).unwrap();
+    assert_eq!(sold_out.1, (symbol_short!("event"), symbol_short!("sold_out"), event_id).into_val(&env));
+    assert_eq!(u32::from_val(&env, &sold_out.2), 1);
+    let opened = events.get(events.len() - 1).unwrap();
+    assert_eq!(opened.1, (symbol_short!("event"), symbol_short!("wl_open"), event_id).into_val(&env));
+    
+    assert!(client.is_waitlist_open(&event_id));
+    assert_eq!(client.join_waitlist(&waiting, &event_id), 1);
+    assert_eq!(client.try_join_waitlist(&waiting, &event_id), Err(Ok(LumentixError::AlreadyOnWaitlist)));
+    assert_eq!(
+        client.try_join_waitlist(&Address::generate(&env), &event_id),
+        Err(Ok(LumentixError::WaitlistFull))
+    );
+    assert_eq!(client.get_waitlist(&event_id), vec![&env, waiting]);
+}
+
+#[test]
+fn test_deposit_tickets_return_deposit_to_attendees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let attendee = Address::generate(&env);
+    let no_show = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_deposit_terms(&organizer, &event_id, &6_000);
+    assert_eq!(client.get_deposit_terms(&event_id), Some(6_000));
+    
+    let attended = client.purchase_ticket(&attendee, &event_id, &100i128, &None, &None, &None);
+    let missed = client.purchase_ticket(&no_show, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(
+        client.try_set_deposit_terms(&organizer, &event_id, &5_000),
+        Err(Ok(LumentixError::TicketsAlreadySold))
+    );
+    
+    client.use_ticket(&attended, &organizer, &Bytes::new(&env));
+    
+    assert_eq!(
+        client.try_settle_deposits(&event_id, &vec![&env, attended]),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &event_id);
+    
+    // The attendee's deposit stays in escrow; the no-show's is kept by the organizer
+    assert_eq!(client.release_escrow(&organizer, &event_id), 140);
+    
+    assert_eq!(client.settle_deposits(&event_id, &vec![&env, attended, missed]), 60);
+    assert_eq!(client.get_deposit_return(&attended), Some(60));
+    assert_eq!(client.get_deposit_return(&missed), Some(0));
+    
+    // Settling again pays nothing
+    assert_eq!(client.settle_deposits(&event_id, &vec![&env, attended]), 0);
+    
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.escrow, 0);
+    assert_eq!(totals.refunds, 60);
+}
+
+#[test]
+fn test_deposit_return_follows_discounted_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let bundled = Address::generate(&env);
+    let full_price = Address::generate(&env);
+    
+    let mut event_ids = Vec::new(&env);
+    for _ in 0..2 {
+        event_ids.push_back(client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        ));
+    }
+    let (partner_id, event_id) = (event_ids.get(0).unwrap(), event_ids.get(1).unwrap());
+    client.set_deposit_terms(&organizer, &event_id, &6_000);
+    client.set_bundle_discount(&organizer, &event_id, &partner_id, &5_000);
+    
+    client.purchase_ticket(&bundled, &partner_id, &100i128, &None, &None, &None);
+    let discounted = client.purchase_ticket(&bundled, &event_id, &50i128, &None, &None, &None);
+    let listed = client.purchase_ticket(&full_price, &event_id, &100i128, &None, &None, &None);
+    client.use_ticket(&discounted, &organizer, &Bytes::new(&env));
+    client.use_ticket(&listed, &organizer, &Bytes::new(&env));
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &event_id);
+    
+    // 60% of what each attendee actually paid stays in escrow for them
+    assert_eq!(client.release_escrow(&organizer, &event_id), 60);
+    assert_eq!(client.settle_deposits(&event_id, &vec![&env, discounted, listed]), 90);
+    assert_eq!(client.get_deposit_return(&discounted), Some(30));
+    assert_eq!(client.get_deposit_return(&listed), Some(60));
+    
+    // Only the partner event's sale is left in escrow
+    assert_eq!(client.get_reconciliation().escrow, 100);
+}
+
+#[test]
+fn test_no_show_rate_gates_purchases() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let meetup = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_deposit_terms(&organizer, &meetup, &10_000);
+    let attended = client.purchase_ticket(&buyer, &meetup, &100i128, &None, &None, &None);
+    let missed = client.purchase_ticket(&buyer, &meetup, &100i128, &None, &None, &None);
+    client.use_ticket(&attended, &organizer, &Bytes::new(&env));
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &meetup);
+    client.settle_deposits(&meetup, &vec![&env, attended, missed]);
+    
+    let record = client.get_attendance_record(&buyer);
+    assert_eq!(record.settled, 2);
+    assert_eq!(record.no_shows, 1);
+    
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let gated = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    // A 50% no-show rate is rejected below the limit and accepted at it
+    client.set_max_no_show_rate(&organizer, &gated, &4_999);
+    assert_eq!(client.get_max_no_show_rate(&gated), Some(4_999));
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &gated, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::NoShowRateTooHigh))
+    );
+    client.purchase_ticket(&Address::generate(&env), &gated, &100i128, &None, &None, &None);
+    
+    client.set_max_no_show_rate(&organizer, &gated, &5_000);
+    client.purchase_ticket(&buyer, &gated, &100i128, &None, &None, &None);
+}
+
+#[test]
+fn test_attendee_reviews_aggregate_ratings() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let absent = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket1 = client.purchase_ticket(&first, &event_id, &100i128, &None, &None, &None);
+    let ticket2 = client.purchase_ticket(&second, &event_id, &100i128, &None, &None, &None);
+    let ticket3 = client.purchase_ticket(&absent, &event_id, &100i128, &None, &None, &None);
+    client.use_ticket(&ticket1, &organizer, &Bytes::new(&env));
+    client.use_ticket(&ticket2, &organizer, &Bytes::new(&env));
+    
+    // Reviews open only after the event ends
+    assert_eq!(
+        client.try_submit_review(&ticket1, &first, &5, &None),
+        Err(Ok(LumentixError::ReviewNotAllowed))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    let comment = BytesN::from_array(&env, &[7u8; 32]);
+    client.submit_review(&ticket1, &first, &5, &Some(comment.clone()));
+    client.submit_review(&ticket2, &second, &4, &None);
+    
+    assert_eq!(client.get_review(&ticket1).unwrap().rating, 5);
+    assert_eq!(client.get_review_comment(&ticket1), Some(comment));
+    assert_eq!(client.get_review_comment(&ticket2), None);
+    
+    assert_eq!(
+        client.try_submit_review(&ticket1, &first, &1, &None),
+        Err(Ok(LumentixError::AlreadyReviewed))
+    );
+    assert_eq!(
+        client.try_submit_review(&ticket3, &absent, &1, &None),
+        Err(Ok(LumentixError::ReviewNotAllowed))
+    );
+    assert_eq!(
+        client.try_submit_review(&ticket3, &absent, &6, &None),
+        Err(Ok(LumentixError::InvalidRating))
+    );
+    
+    let rating = client.get_event_rating(&event_id);
+    assert_eq!((rating.count, rating.total, rating.average_x100), (2, 9, 450));
+    assert_eq!(client.get_organizer_rating(&organizer), rating);
+}
+
+#[test]
+fn test_sponsorship_escrow_and_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let benefits = BytesN::from_array(&env, &[3u8; 32]);
+    let gold = client.create_sponsorship(&organizer, &event_id, &5_000, &benefits);
+    assert_eq!(client.get_event_sponsorships(&event_id), vec![&env, gold]);
+    
+    assert_eq!(
+        client.try_purchase_sponsorship(&sponsor, &gold, &4_999),
+        Err(Ok(LumentixError::InsufficientFunds))
+    );
+    client.purchase_sponsorship(&sponsor, &gold, &5_000);
+    assert_eq!(
+        client.try_purchase_sponsorship(&Address::generate(&env), &gold, &5_000),
+        Err(Ok(LumentixError::SponsorshipTaken))
+    );
+    
+    let slot = client.get_sponsorship(&gold);
+    assert_eq!(slot.sponsor, Some(sponsor.clone()));
+    assert_eq!(slot.benefits_hash, benefits);
+    assert_eq!(client.get_reconciliation().escrow, 5_000);
+    
+    // Sponsors get the same cancellation protection as ticket holders
+    assert_eq!(
+        client.try_refund_sponsorship(&gold, &sponsor),
+        Err(Ok(LumentixError::EventNotCancelled))
+    );
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    client.refund_sponsorship(&gold, &sponsor);
+    assert!(client.get_sponsorship(&gold).refunded);
+    assert_eq!(
+        client.try_refund_sponsorship(&gold, &sponsor),
+        Err(Ok(LumentixError::RefundNotAllowed))
+    );
+    
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.escrow, 0);
+    assert_eq!(totals.refunds, 5_000);
+}
+
+#[test]
+fn test_add_ons_sold_with_ticket_and_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let parking = client.add_add_on(&organizer, &event_id, &String::from_str(&env, "Parking"), &20, &1);
+    let shirt = client.add_add_on(&organizer, &event_id, &String::from_str(&env, "T-shirt"), &30, &10);
+    
+    // Two shirts and a parking spot cost 80 on top of the ticket
+    let add_ons = vec![&env, shirt, shirt, parking];
+    assert_eq!(
+        client.try_purchase_ticket_with_add_ons(&buyer, &event_id, &179, &None, &None, &add_ons, &None),
+        Err(Ok(LumentixError::InsufficientFunds))
+    );
+    let ticket_id = client.purchase_ticket_with_add_ons(&buyer, &event_id, &180, &None, &None, &add_ons, &None);
+    
+    let bought = client.get_ticket_add_ons(&ticket_id).unwrap();
+    assert_eq!(bought.items, add_ons);
+    assert_eq!(bought.paid, 80);
+    assert_eq!(client.get_add_ons(&event_id).get(shirt).unwrap().sold, 2);
+    assert_eq!(client.get_reconciliation().escrow, 180);
+    
+    assert_eq!(
+        client.try_purchase_ticket_with_add_ons(&buyer, &event_id, &120, &None, &None, &vec![&env, parking], &None),
+        Err(Ok(LumentixError::AddOnUnavailable))
+    );
+    assert_eq!(
+        client.try_purchase_ticket_with_add_ons(&buyer, &event_id, &200, &None, &None, &vec![&env, 9], &None),
+        Err(Ok(LumentixError::AddOnUnavailable))
+    );
+    
+    // Refunds return the add-ons' price and restock them
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(client.get_refund_receipt(&ticket_id).unwrap().from_escrow, 180);
+    assert_eq!(client.get_add_ons(&event_id).get(parking).unwrap().sold, 0);
+    assert_eq!(client.get_reconciliation().escrow, 0);
+}
+
+#[test]
+fn test_purchase_memo_recorded_on_ticket() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let po_number = BytesN::from_array(&env, &[7u8; 32]);
+    let with_memo = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &Some(po_number.clone()));
+    let without_memo = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    assert_eq!(client.get_purchase_memo(&with_memo), Some(po_number));
+    assert_eq!(client.get_purchase_memo(&without_memo), None);
+}
+
+#[test]
+fn test_tax_withheld_and_paid_to_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let tax_office = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(
+        client.try_set_tax_terms(&organizer, &event_id, &10_001, &tax_office),
+        Err(Ok(LumentixError::InvalidAmount))
+    );
+    client.set_tax_terms(&organizer, &event_id, &1_000, &tax_office);
+    assert_eq!(client.get_tax_terms(&event_id).unwrap().recipient, tax_office);
+    
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_tax_collected(&event_id), 20);
+    assert_eq!(
+        client.try_withdraw_tax(&event_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &event_id);
+    
+    // The organizer's release leaves the withheld tax in escrow
+    assert_eq!(client.release_escrow(&organizer, &event_id), 180);
+    assert_eq!(client.withdraw_tax(&event_id), 20);
+    assert_eq!(
+        client.try_withdraw_tax(&event_id),
+        Err(Ok(LumentixError::EscrowAlreadyReleased))
+    );
+    assert_eq!(client.get_reconciliation().escrow, 0);
+    
+    // Refunded tickets give back their withheld tax
+    let cancelled = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &3000u64,
+        &4000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_tax_terms(&organizer, &cancelled, &1_000, &tax_office);
+    let ticket_id = client.purchase_ticket(&buyer, &cancelled, &100i128, &None, &None, &None);
+    client.cancel_event(&organizer, &cancelled, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(client.get_tax_collected(&cancelled), 0);
+}
+
+#[test]
+fn test_capacity_waves_release_tickets_over_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &3u32,
+    );
+    client.release_wave(&organizer, &event_id, &1, &0);
+    client.release_wave(&organizer, &event_id, &2, &500);
+    assert_eq!(
+        client.try_release_wave(&organizer, &event_id, &1, &600),
+        Err(Ok(LumentixError::CapacityExceeded))
+    );
+    assert_eq!(client.get_capacity_waves(&event_id).len(), 2);
+    assert_eq!(client.get_released_capacity(&event_id), 1);
+    
+    // Only the first wave is on sale
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert!(!client.is_on_sale(&event_id));
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::EventSoldOut))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.get_released_capacity(&event_id), 3);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_remaining_capacity(&event_id), 0);
+}
+
+#[test]
+fn test_sales_cutoff_closes_sales_before_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_sales_cutoff(&organizer, &event_id, &100);
+    assert_eq!(client.get_sales_cutoff(&event_id), Some(100));
+    
+    env.ledger().with_mut(|li| li.timestamp = 899);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    env.ledger().with_mut(|li| li.timestamp = 900);
+    assert!(!client.is_on_sale(&event_id));
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::SalesClosed))
+    );
+    
+    // Removing the cutoff reopens sales until the end time
+    client.set_sales_cutoff(&organizer, &event_id, &0);
+    assert_eq!(client.get_sales_cutoff(&event_id), None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+}
+
+#[test]
+fn test_refund_deadline_follows_event_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_refund_deadline(&ticket_id), RefundDeadline::NotRefundable);
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    assert_eq!(client.get_refund_deadline(&ticket_id), RefundDeadline::NoDeadline);
+    
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(client.get_refund_deadline(&ticket_id), RefundDeadline::NotRefundable);
+}
+
+#[test]
+fn test_cancel_event_records_reason() {
+    use soroban_sdk::{symbol_short, testutils::Events, FromVal, IntoVal};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(client.get_event_cancellation(&event_id), None);
+    
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    let notice = BytesN::from_array(&env, &[3u8; 32]);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Weather, &Some(notice.clone()));
+    
+    let events = env.events().all();
+    let cancelled = events.get(events.len() - 1).unwrap();
+    assert_eq!(cancelled.1, (symbol_short!("event"), symbol_short!("cancel"), event_id).into_val(&env));
+    assert_eq!(CancellationReason::from_val(&env, &cancelled.2), CancellationReason::Weather);
+    
+    assert_eq!(
+        client.get_event_cancellation(&event_id),
+        Some(EventCancellation {
+            reason: CancellationReason::Weather,
+            cancelled_at: 500,
+        })
+    );
+    assert_eq!(client.get_cancellation_detail(&event_id), Some(notice));
+}
+
+#[test]
+fn test_recover_orphaned_escrow_after_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    let bucket = RecoveryBucket::OrphanedEscrow(event_id);
+    assert_eq!(
+        client.try_propose_recovery(&admin, &bucket, &treasury),
+        Err(Ok(LumentixError::EventAlreadyExists))
+    );
+    
+    // Lose the event record, as a faulty migration might, stranding its escrow
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&storage::DataKey::Event(event_id));
+    });
+    
+    let unlock_at = client.propose_recovery(&admin, &bucket, &treasury);
+    assert_eq!(unlock_at, RECOVERY_TIMELOCK_SECS);
+    assert_eq!(client.get_recovery_request(&bucket).unwrap().amount, 100);
+    assert_eq!(
+        client.try_recover_stuck_funds(&admin, &bucket, &treasury),
+        Err(Ok(LumentixError::DraftRetentionNotElapsed))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = unlock_at);
+    assert_eq!(
+        client.try_recover_stuck_funds(&admin, &bucket, &buyer),
+        Err(Ok(LumentixError::RefundRequestNotFound))
+    );
+    assert_eq!(client.recover_stuck_funds(&admin, &bucket, &treasury), 100);
+    assert_eq!(client.get_recovery_request(&bucket), None);
+    assert_eq!(client.get_reconciliation().escrow, 0);
+}
+
+#[test]
+fn test_recovery_pays_out_in_the_proposed_token() {
+    use crate::testutils::{setup_contract, setup_event_with_sales, FIXTURE_TICKET_PRICE};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 2);
+    let treasury = Address::generate(&env);
+    
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&storage::DataKey::Event(fixture.event_id));
+    });
+    let bucket = RecoveryBucket::OrphanedEscrow(fixture.event_id);
+    let unlock_at = client.propose_recovery(&admin, &bucket, &treasury);
+    assert_eq!(client.get_recovery_request(&bucket).unwrap().token, Some(fixture.token.clone()));
+    
+    // The payment asset record may be cleaned up before the recovery runs
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&storage::DataKey::PaymentAsset(fixture.event_id));
+    });
+    env.ledger().with_mut(|li| li.timestamp = unlock_at);
+    client.recover_stuck_funds(&admin, &bucket, &treasury);
+    
+    let token = TokenClient::new(&env, &fixture.token);
+    assert_eq!(token.balance(&treasury), 2 * FIXTURE_TICKET_PRICE);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+/// Token that tries to call back into the ticketing contract from a transfer
+mod reentrant_token {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Error, Symbol, Val, Vec};
+
+    #[contract]
+    pub struct ReentrantToken;
+
+    #[contractimpl]
+    impl ReentrantToken {
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+
+        /// Make the next transfer invoke `fn_name(args)` on `target`
+        pub fn arm(env: Env, target: Address, fn_name: Symbol, args: Vec<Val>) {
+            env.storage().instance().set(&symbol_short!("armed"), &(target, fn_name, args));
+        }
+
+        pub fn transfer(env: Env, _from: Address, _to: Address, _amount: i128) {
+            let armed: Option<(Address, Symbol, Vec<Val>)> = env.storage().instance().get(&symbol_short!("armed"));
+            if let Some((target, fn_name, args)) = armed {
+                env.storage().instance().remove(&symbol_short!("armed"));
+                let result = env.try_invoke_contract::<Val, Error>(&target, &fn_name, args);
+                env.storage().instance().set(&symbol_short!("reentered"), &result.is_ok());
+            }
+        }
+
+        /// Whether the last armed call back into the target succeeded
+        pub fn reentered(env: Env) -> Option<bool> {
+            env.storage().instance().get(&symbol_short!("reentered"))
+        }
+    }
+}
+
+#[test]
+fn test_host_rejects_reentry_from_payment_token() {
+    use soroban_sdk::{IntoVal, Symbol, Val};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let token_id = env.register_contract(None, reentrant_token::ReentrantToken);
+    let token = reentrant_token::ReentrantTokenClient::new(&env, &token_id);
+    let asset = PaymentAsset { token: token_id.clone(), decimals: 7 };
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_event_payment_asset(&organizer, &event_id, &asset);
+    
+    // The host rejects any call back into the contract from a transfer, so
+    // the token cannot even read state mid-purchase, let alone buy again
+    let args: soroban_sdk::Vec<Val> = (event_id,).into_val(&env);
+    token.arm(&client.address, &Symbol::new(&env, "get_event"), &args);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &Some(asset.clone()), &None);
+    assert_eq!(token.reentered(), Some(false));
+    
+    let args: soroban_sdk::Vec<Val> =
+        (buyer.clone(), event_id, 100i128, None::<BytesN<32>>, Some(asset.clone()), None::<BytesN<32>>).into_val(&env);
+    token.arm(&client.address, &Symbol::new(&env, "purchase_ticket"), &args);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &Some(asset.clone()), &None);
+    assert_eq!(token.reentered(), Some(false));
+    assert_eq!(client.get_event(&event_id).tickets_sold, 2);
+    
+    // Nor can it refund a ticket a second time from the refund payout
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    let args: soroban_sdk::Vec<Val> = (ticket_id, buyer.clone()).into_val(&env);
+    token.arm(&client.address, &Symbol::new(&env, "refund_ticket"), &args);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(token.reentered(), Some(false));
+    
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.escrow, 100);
+    assert_eq!(totals.refunds, 100);
+}
+
+#[test]
+fn test_initialize_with_full_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, LumentixContract);
+    let client = LumentixContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let asset = PaymentAsset { token: token.clone(), decimals: 7 };
+    let config = PlatformConfig {
+        platform_fee_bps: 500,
+        max_fee_bps: 800,
+        ..PlatformConfig::default()
+    };
+    let destinations = vec![&env, FeeDestination { recipient: treasury, share_bps: 10_000 }];
+    
+    // A token with other decimals is rejected before anything is stored
+    let wrong_decimals = PaymentAsset { token, decimals: 6 };
+    assert_eq!(
+        client.try_initialize(&admin, &config, &destinations, &Some(wrong_decimals)),
+        Err(Ok(LumentixError::WrongPaymentAsset))
+    );
+    
+    client.initialize(&admin, &config, &destinations, &Some(asset.clone()));
+    assert_eq!(client.get_config(), config);
+    assert_eq!(client.get_fee_destinations(), destinations);
+    assert_eq!(client.get_default_payment_asset(), Some(asset.clone()));
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(client.get_event_payment_asset(&event_id), Some(asset));
+}
+
+#[test]
+fn test_simulate_purchase_previews_without_buying() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &3u32,
+    );
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    let simulation = client.simulate_purchase(&buyer, &event_id, &2, &None);
+    assert_eq!(simulation.ticket_ids, vec![&env, 2, 3]);
+    assert_eq!(simulation.total_cost, 200);
+    assert_eq!(client.get_event(&event_id).tickets_sold, 1);
+    
+    // The same failures a real purchase would hit are reported up front
+    assert_eq!(
+        client.try_simulate_purchase(&buyer, &event_id, &3, &None),
+        Err(Ok(LumentixError::EventSoldOut))
+    );
+    assert_eq!(
+        client.try_simulate_purchase(&buyer, &event_id, &0, &None),
+        Err(Ok(LumentixError::InvalidCapacity))
+    );
+    
+    // Buyer-supplied inputs are checked too, and add-ons priced per ticket
+    let other_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &10u32,
+    );
+    let parking = client.add_add_on(&organizer, &other_id, &String::from_str(&env, "Parking"), &20i128, &3u32);
+    let diet = soroban_sdk::symbol_short!("diet");
+    client.set_ticket_fields(&organizer, &other_id, &vec![&env, diet.clone()]);
+    assert_eq!(
+        client.try_simulate_purchase(&buyer, &other_id, &1, &None),
+        Err(Ok(LumentixError::PolicyMismatch))
+    );
+    let mut fields = soroban_sdk::Map::new(&env);
+    fields.set(diet, BytesN::from_array(&env, &[1u8; 32]));
+    let request = PurchaseRequest {
+        terms_hash: OptionalHash::None,
+        payment_asset: OptionalAsset::None,
+        add_ons: vec![&env, parking],
+        fields,
+        memo: OptionalHash::None,
+    };
+    assert_eq!(client.simulate_purchase(&buyer, &other_id, &2, &Some(request.clone())).total_cost, 240);
+    assert_eq!(
+        client.try_simulate_purchase(&buyer, &other_id, &4, &Some(request)),
+        Err(Ok(LumentixError::AddOnUnavailable))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    assert_eq!(
+        client.try_simulate_purchase(&buyer, &event_id, &1, &None),
+        Err(Ok(LumentixError::SalesClosed))
+    );
+}
+
+#[test]
+fn test_event_schedule_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        min_event_duration_secs: 600,
+        max_event_duration_secs: 86_400,
+        max_schedule_horizon_secs: 100_000,
+        ..client.get_config()
+    };
+    client.set_config(&admin, &config);
+    
+    let create = |start: u64, end: u64| {
+        client.try_create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &start,
+            &end,
+            &100i128,
+            &50u32,
+        )
+    };
+    
+    assert!(create(1000, 1600).is_ok());
+    assert_eq!(create(1000, 1599), Err(Ok(LumentixError::InvalidTimeRange)));
+    assert_eq!(create(1000, 87_401), Err(Ok(LumentixError::InvalidTimeRange)));
+    assert_eq!(create(100_001, 101_000), Err(Ok(LumentixError::InvalidTimeRange)));
+    
+    // The horizon moves with the ledger
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    assert!(create(100_001, 101_000).is_ok());
+}
+
+#[test]
+fn test_update_events_status_reports_per_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let other = Address::generate(&env);
+    
+    let draft = |owner: &Address| {
+        client.create_draft_event(
+            owner,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let first = draft(&organizer);
+    let second = draft(&organizer);
+    let foreign = draft(&other);
+    client.publish_event(&organizer, &second);
+    
+    let ids = vec![&env, first, second, foreign, 999];
+    let results = client.update_events_status(&organizer, &ids, &EventStatus::Active);
+    
+    let result = |event_id, error_code| StatusUpdateResult { event_id, error_code };
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            result(first, 0),
+            result(second, LumentixError::InvalidStatusTransition as u32),
+            result(foreign, LumentixError::Unauthorized as u32),
+            result(999, LumentixError::EventNotFound as u32),
+        ]
+    );
+    assert_eq!(client.get_event(&first).status, EventStatus::Active);
+    assert_eq!(client.get_event(&foreign).status, EventStatus::Draft);
+    
+    // Completion still waits for each event's end time
+    let ids = vec![&env, first, second];
+    let results = client.update_events_status(&organizer, &ids, &EventStatus::Completed);
+    assert_eq!(results.get(0).unwrap().error_code, LumentixError::EventNotEnded as u32);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    let results = client.update_events_status(&organizer, &ids, &EventStatus::Completed);
+    assert!(results.iter().all(|r| r.error_code == 0));
+    assert_eq!(client.get_event(&second).status, EventStatus::Completed);
+    
+    let results = client.update_events_status(&organizer, &ids, &EventStatus::Cancelled);
+    assert!(results.iter().all(|r| r.error_code == LumentixError::InvalidStatusTransition as u32));
+}
+
+#[test]
+fn test_tier_sales_window_narrows_event_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let window = SalesWindow { opens_at: 500, closes_at: 800 };
+    client.set_tier_sales_window(&organizer, &event_id, &0, &Some(window));
+    assert_eq!(client.get_tier_sales_window(&event_id, &0), Some(window));
+    
+    let buy = || client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    // Not open yet, then open, then closed although the event still sells
+    assert_eq!(buy(), Err(Ok(LumentixError::SalesClosed)));
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert!(buy().is_ok());
+    env.ledger().with_mut(|li| li.timestamp = 800);
+    assert_eq!(buy(), Err(Ok(LumentixError::SalesClosed)));
+    
+    // Tiers are bounded by the platform's max_tiers
+    let max_tiers = client.get_config().max_tiers;
+    assert_eq!(
+        client.try_set_tier_sales_window(&organizer, &event_id, &max_tiers, &Some(window)),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+    
+    client.set_tier_sales_window(&organizer, &event_id, &0, &None);
+    assert!(buy().is_ok());
+}
+
+#[test]
+fn test_bonus_capacity_unlocks_on_revenue() {
+    use soroban_sdk::{symbol_short, testutils::Events, IntoVal};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &2u32,
+    );
+    
+    let buy = || {
+        let buyer = Address::generate(&env);
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None)
+    };
+    
+    // Unlock once the event holds the net revenue of both base tickets
+    let fee = client.preview_fee(&event_id).fee;
+    let unlock = BonusUnlock::Revenue(2 * (100 - fee));
+    client.set_bonus_capacity(&organizer, &event_id, &3, &unlock);
+    
+    assert!(buy().is_ok());
+    assert_eq!(client.get_event(&event_id).max_tickets, 2);
+    assert!(buy().is_ok());
+    
+    let event = client.get_event(&event_id);
+    assert_eq!(event.max_tickets, 5);
+    assert!(client.get_bonus_capacity(&event_id).unwrap().unlocked);
+    assert!(env.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("event"), symbol_short!("bonus"), event_id).into_val(&env)
+    }));
+    
+    for _ in 0..3 {
+        assert!(buy().is_ok());
+    }
+    assert_eq!(buy(), Err(Ok(LumentixError::EventSoldOut)));
+    
+    // An unlocked rule is final
+    assert_eq!(
+        client.try_set_bonus_capacity(&organizer, &event_id, &0, &unlock),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+}
+
+#[test]
+fn test_resale_split_configuration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    // Default: platform base fee, remainder to the seller
+    let platform_bps = client.get_config().platform_fee_bps;
+    let split = client.get_resale_split(&event_id);
+    assert_eq!(split.platform_bps, platform_bps);
+    assert_eq!(split.organizer_bps, 0);
+    assert_eq!(split.seller_bps + split.platform_bps, 10_000);
+    
+    let split = ResaleSplit {
+        seller_bps: 9_000 - platform_bps,
+        organizer_bps: 1_000,
+        platform_bps,
+    };
+    client.set_resale_split(&organizer, &event_id, &split);
+    assert_eq!(client.get_resale_split(&event_id), split);
+    
+    let unbalanced = ResaleSplit { seller_bps: split.seller_bps + 1, ..split };
+    assert_eq!(
+        client.try_set_resale_split(&organizer, &event_id, &unbalanced),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_resale_split(&Address::generate(&env), &event_id, &split),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_resale_offers_escrow_and_split_proceeds() {
+    use crate::testutils::{funded_account, setup_contract, setup_event_with_sales, FIXTURE_BUYER_BALANCE};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 1);
+    let token = TokenClient::new(&env, &fixture.token);
+    let seller = fixture.buyers.get(0).unwrap();
+    let ticket_id = fixture.ticket_ids.get(0).unwrap();
+    let held = token.balance(&client.address);
+    let offers_held = || {
+        let totals = client.get_reconciliation();
+        assert_eq!(
+            totals.escrow + totals.platform_balance + totals.offers_held,
+            totals.deposits - totals.withdrawals - totals.refunds
+        );
+        totals.offers_held
+    };
+    
+    client.set_resale_split(&fixture.organizer, &fixture.event_id, &ResaleSplit {
+        seller_bps: 8_000,
+        organizer_bps: 1_500,
+        platform_bps: 500,
+    });
+    
+    let bidder = funded_account(&env, &fixture.token, FIXTURE_BUYER_BALANCE);
+    let offer_id = client.place_offer(&bidder, &OfferTarget::Event(fixture.event_id), &200);
+    let outbid = client.place_offer(&bidder, &OfferTarget::Ticket(ticket_id), &150);
+    assert_eq!(token.balance(&bidder), FIXTURE_BUYER_BALANCE - 350);
+    assert_eq!(offers_held(), 350);
+    
+    // Only the ticket's owner can accept
+    assert_eq!(
+        client.try_accept_offer(&fixture.organizer, &offer_id, &ticket_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    let seller_before = token.balance(&seller);
+    client.accept_offer(&seller, &offer_id, &ticket_id);
+    
+    assert_eq!(client.get_ticket(&ticket_id).owner, bidder);
+    assert_eq!(token.balance(&seller), seller_before + 160);
+    assert_eq!(token.balance(&fixture.organizer), 30);
+    assert_eq!(client.get_offer(&offer_id), None);
+    assert_eq!(offers_held(), 150);
+    
+    client.cancel_offer(&bidder, &outbid);
+    assert_eq!(offers_held(), 0);
+    assert_eq!(token.balance(&bidder), FIXTURE_BUYER_BALANCE - 200);
+    assert_eq!(token.balance(&client.address), held + 10);
+    assert_eq!(
+        client.try_cancel_offer(&bidder, &outbid),
+        Err(Ok(LumentixError::TicketNotFound))
+    );
+}
+
+#[test]
+fn test_rounding_policy_for_fees_and_resale() {
+    use crate::testutils::{funded_account, setup_contract, setup_event_with_sales, FIXTURE_BUYER_BALANCE};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (admin, client) = setup_contract(&env);
+    assert_eq!(client.get_config().rounding, RoundingPolicy::TowardOrganizer);
+    
+    // (policy, fee on 999 at 2.5%, platform share and royalty of a 199 offer)
+    let cases = [
+        (RoundingPolicy::TowardOrganizer, 24, 9, 30),
+        (RoundingPolicy::TowardPlatform, 25, 10, 29),
+    ];
+    for (rounding, fee, platform_share, royalty) in cases {
+        client.set_config(&admin, &PlatformConfig { rounding, ..client.get_config() });
+        
+        let fixture = setup_event_with_sales(&env, &client, 1);
+        let token = TokenClient::new(&env, &fixture.token);
+        let seller = fixture.buyers.get(0).unwrap();
+        let ticket_id = fixture.ticket_ids.get(0).unwrap();
+        let held = token.balance(&client.address);
+        
+        let event_id = client.create_event(
+            &fixture.organizer,
+            &String::from_str(&env, "Odd Price"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &(env.ledger().timestamp() + 1_000),
+            &(env.ledger().timestamp() + 2_000),
+            &999i128,
+            &10u32,
+        );
+        apply_config(&env, &client, &admin, &PlatformConfig { platform_fee_bps: 250, ..client.get_config() });
+        assert_eq!(client.preview_fee(&event_id).fee, fee);
+        apply_config(&env, &client, &admin, &PlatformConfig { platform_fee_bps: 0, ..client.get_config() });
+        
+        client.set_resale_split(&fixture.organizer, &fixture.event_id, &ResaleSplit {
+            seller_bps: 8_000,
+            organizer_bps: 1_500,
+            platform_bps: 500,
+        });
+        let bidder = funded_account(&env, &fixture.token, FIXTURE_BUYER_BALANCE);
+        let offer_id = client.place_offer(&bidder, &OfferTarget::Ticket(ticket_id), &199);
+        let seller_before = token.balance(&seller);
+        client.accept_offer(&seller, &offer_id, &ticket_id);
+        
+        // The seller's remainder is the same either way; only the platform
+        // and the organizer trade the fractional unit
+        assert_eq!(token.balance(&fixture.organizer), royalty);
+        assert_eq!(token.balance(&seller), seller_before + 160);
+        assert_eq!(token.balance(&client.address), held + platform_share);
+    }
+}
+
+#[test]
+fn test_ticket_swap_with_top_up() {
+    use crate::testutils::{setup_contract, setup_event_with_sales};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 2);
+    let token = TokenClient::new(&env, &fixture.token);
+    let (alice, bob) = (fixture.buyers.get(0).unwrap(), fixture.buyers.get(1).unwrap());
+    let (ticket_a, ticket_b) = (fixture.ticket_ids.get(0).unwrap(), fixture.ticket_ids.get(1).unwrap());
+    let (alice_balance, bob_balance) = (token.balance(&alice), token.balance(&bob));
+    
+    let swap_id = client.propose_swap(&alice, &ticket_a, &ticket_b, &50);
+    assert_eq!(token.balance(&alice), alice_balance - 50);
+    
+    // Only the holder of the requested ticket can accept
+    assert_eq!(client.try_accept_swap(&alice, &swap_id), Err(Ok(LumentixError::Unauthorized)));
+    
+    client.accept_swap(&bob, &swap_id);
+    assert_eq!(client.get_ticket(&ticket_a).owner, bob);
+    assert_eq!(client.get_ticket(&ticket_b).owner, alice);
+    assert_eq!(token.balance(&bob), bob_balance + 50);
+    assert_eq!(client.try_accept_swap(&bob, &swap_id), Err(Ok(LumentixError::TicketNotFound)));
+    
+    // Cancelling returns the top-up
+    let swap_id = client.propose_swap(&alice, &ticket_b, &ticket_a, &25);
+    client.cancel_swap(&alice, &swap_id);
+    assert_eq!(token.balance(&alice), alice_balance - 50);
+    assert_eq!(client.get_swap(&swap_id), None);
+}
+
+#[test]
+fn test_event_creation_rate_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let config = PlatformConfig {
+        creation_window_ledgers: 10,
+        max_creations_per_window: 3,
+        max_creations_per_organizer: 2,
+        ..client.get_config()
+    };
+    client.set_config(&admin, &config);
+    
+    let create = |organizer: &Address| {
+        client.try_create_event(
+            organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let (alice, bob, carol) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    
+    assert!(create(&alice).is_ok());
+    assert!(create(&alice).is_ok());
+    assert_eq!(create(&alice), Err(Ok(LumentixError::OrganizerEventLimitReached)));
+    
+    // The platform-wide limit caps every organizer together
+    assert!(create(&bob).is_ok());
+    assert_eq!(create(&carol), Err(Ok(LumentixError::OrganizerEventLimitReached)));
+    
+    // Both limits reset with the next window
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert!(create(&alice).is_ok());
+    assert!(create(&carol).is_ok());
+}
+
+#[test]
+fn test_ticket_status_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_a = client.purchase_ticket(&alice, &event_id, &100i128, &None, &None, &None);
+    let ticket_b = client.purchase_ticket(&bob, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_ticket(&ticket_a).status, TicketStatus::Valid);
+    
+    // A ticket in a pending swap is locked at the gate and for resale
+    let swap_id = client.propose_swap(&alice, &ticket_a, &ticket_b, &0);
+    assert_eq!(client.get_ticket(&ticket_a).status, TicketStatus::PendingTransfer);
+    assert_eq!(
+        client.check_in(&organizer, &event_id, &ticket_a, &Bytes::new(&env)),
+        CheckInOutcome::Rejected(CheckInRejection::Locked)
+    );
+    assert_eq!(
+        client.try_propose_swap(&alice, &ticket_a, &ticket_b, &0),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    client.cancel_swap(&alice, &swap_id);
+    assert_eq!(client.get_ticket(&ticket_a).status, TicketStatus::Valid);
+    
+    client.use_ticket(&ticket_a, &organizer, &Bytes::new(&env));
+    assert_eq!(client.get_ticket(&ticket_a).status, TicketStatus::Used);
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    assert_eq!(
+        client.try_refund_ticket(&ticket_a, &alice),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+    client.refund_ticket(&ticket_b, &bob);
+    assert_eq!(client.get_ticket(&ticket_b).status, TicketStatus::Refunded);
+}
+
+#[test]
+fn test_expire_unused_tickets_after_grace() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let attended = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let no_show = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.use_ticket(&attended, &organizer, &Bytes::new(&env));
+    let ids = vec![&env, attended, no_show, 999];
+    
+    // Nothing expires within the grace period
+    let grace = client.get_config().ticket_expiry_grace_secs;
+    env.ledger().with_mut(|li| li.timestamp = 2000 + grace - 1);
+    assert_eq!(client.expire_tickets(&ids), 0);
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000 + grace);
+    assert_eq!(client.expire_tickets(&ids), 1);
+    assert_eq!(client.get_ticket(&no_show).status, TicketStatus::Expired);
+    assert_eq!(client.get_ticket(&attended).status, TicketStatus::Used);
+    assert_eq!(client.get_event_stats(&event_id).tickets_expired, 1);
+    
+    // Expiry is idempotent and the ticket is void at the gate
+    assert_eq!(client.expire_tickets(&ids), 0);
+    assert_eq!(
+        client.check_in(&organizer, &event_id, &no_show, &Bytes::new(&env)),
+        CheckInOutcome::Rejected(CheckInRejection::Expired)
+    );
+}
+
+#[test]
+fn test_escrow_release_requires_check_in_quorum_or_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let config = PlatformConfig {
+        min_completion_check_ins: 2,
+        ..client.get_config()
+    };
+    client.set_config(&admin, &config);
+    
+    let create = || {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let checked_in = create();
+    let attested = create();
+    let buy = |event_id: u64| client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let (first, second) = (buy(checked_in), buy(checked_in));
+    for _ in 0..3 {
+        buy(attested);
+    }
+    client.use_ticket(&first, &organizer, &Bytes::new(&env));
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &checked_in);
+    client.complete_event(&organizer, &attested);
+    
+    assert_eq!(
+        client.try_release_escrow(&organizer, &checked_in),
+        Err(Ok(LumentixError::TicketNotCheckedIn))
+    );
+    client.use_ticket(&second, &organizer, &Bytes::new(&env));
+    assert_eq!(client.release_escrow(&organizer, &checked_in), 200);
+    
+    // An admin attestation stands in for the missing check-ins
+    assert_eq!(
+        client.try_release_escrow(&organizer, &attested),
+        Err(Ok(LumentixError::TicketNotCheckedIn))
+    );
+    assert_eq!(
+        client.try_attest_event(&organizer, &attested),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.attest_event(&admin, &attested);
+    assert_eq!(client.get_event_attestation(&attested), Some(2000));
+    assert_eq!(client.release_escrow(&organizer, &attested), 300);
+}
+
+#[test]
+fn test_refund_spike_freezes_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let buy = || client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let tickets = [buy(), buy(), buy(), buy()];
+    
+    // One of four tickets is exactly the default 25% threshold
+    client.request_late_refund(&buyer, &tickets[0], &RefundReason::CannotAttend);
+    assert_eq!(client.get_payout_freeze(&event_id), None);
+    
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.request_late_refund(&buyer, &tickets[1], &RefundReason::Other);
+    assert_eq!(client.get_payout_freeze(&event_id), Some(500));
+    
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.complete_event(&organizer, &event_id);
+    assert_eq!(
+        client.try_release_escrow(&organizer, &event_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    assert_eq!(
+        client.try_unfreeze_payouts(&organizer, &event_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.unfreeze_payouts(&admin, &event_id);
+    assert_eq!(client.get_payout_freeze(&event_id), None);
+    assert_eq!(
+        client.try_unfreeze_payouts(&admin, &event_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    assert_eq!(client.release_escrow(&organizer, &event_id), 400);
+}
+
+/// Yield vault that pays a fixed bonus on top of the principal
+mod mock_vault {
+    use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+    pub const BONUS: i128 = 20;
+
+    #[contract]
+    pub struct MockVault;
+
+    #[contractimpl]
+    impl MockVault {
+        pub fn deposit(_env: Env, depositor: Address, _token: Address, _amount: i128) {
+            depositor.require_auth();
+        }
+
+        pub fn withdraw(env: Env, depositor: Address, token: Address, principal: i128) -> i128 {
+            depositor.require_auth();
+            let amount = principal + BONUS;
+            token::Client::new(&env, &token).transfer(&env.current_contract_address(), &depositor, &amount);
+            amount
+        }
+    }
+}
+
+#[test]
+fn test_escrow_yield_redeemed_at_settlement() {
+    use crate::testutils::{setup_contract, setup_event_with_sales, FIXTURE_TICKET_PRICE};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    
+    let env = Env::default();
+    let (admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 3);
+    let token = TokenClient::new(&env, &fixture.token);
+    let vault = env.register_contract(None, mock_vault::MockVault);
+    StellarAssetClient::new(&env, &fixture.token).mint(&vault, &mock_vault::BONUS);
+    
+    assert_eq!(
+        client.try_deposit_escrow_yield(&fixture.organizer, &fixture.event_id, &vault, &200),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.set_yield_vault(&admin, &vault, &true);
+    assert_eq!(
+        client.try_deposit_escrow_yield(&fixture.organizer, &fixture.event_id, &vault, &301),
+        Err(Ok(LumentixError::InsufficientEscrow))
+    );
+    
+    client.deposit_escrow_yield(&fixture.organizer, &fixture.event_id, &vault, &200);
+    assert_eq!(client.get_yield_position(&fixture.event_id).unwrap().principal, 200);
+    assert_eq!(token.balance(&client.address), 3 * FIXTURE_TICKET_PRICE - 200);
+    
+    // Release redeems the position, the platform keeping 20% of the yield
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.complete_event(&fixture.organizer, &fixture.event_id);
+    assert_eq!(client.release_escrow(&fixture.organizer, &fixture.event_id), 316);
+    assert_eq!(client.get_yield_position(&fixture.event_id), None);
+    assert_eq!(client.get_platform_balance(), 4);
+    assert_eq!(token.balance(&fixture.organizer), 316);
+    assert_eq!(token.balance(&client.address), 4);
+}
+
+#[test]
+fn test_organizer_defaults_apply_to_new_events() {
+    use soroban_sdk::InvokeError;
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let policy = BytesN::from_array(&env, &[7u8; 32]);
+    let split = ResaleSplit { seller_bps: 9_000, organizer_bps: 1_000, platform_bps: 0 };
+    
+    assert_eq!(
+        client.try_set_organizer_defaults(&organizer, &vec![&env, OrganizerDefault::MaxUses(0)]),
+        Err(Ok(LumentixError::InvalidCapacity))
+    );
+    let vip = TierWindowTemplate { opens_before_start: 900, closes_before_start: 100 };
+    assert_eq!(
+        client.try_set_organizer_defaults(&organizer, &vec![&env, OrganizerDefault::TierWindow(10, vip)]),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+    let inverted = TierWindowTemplate { opens_before_start: 100, closes_before_start: 900 };
+    assert_eq!(
+        client.try_set_organizer_defaults(&organizer, &vec![&env, OrganizerDefault::TierWindow(1, inverted)]),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+    let defaults = vec![
+        &env,
+        OrganizerDefault::RefundPolicy(policy.clone()),
+        OrganizerDefault::DepositTerms(5_000),
+        OrganizerDefault::ResaleSplit(split),
+        OrganizerDefault::MaxUses(3),
+        OrganizerDefault::AllowReentry(true),
+        OrganizerDefault::Transferable(false),
+        OrganizerDefault::TierWindow(1, vip),
+    ];
+    client.set_organizer_defaults(&organizer, &defaults);
+    assert_eq!(client.get_organizer_defaults(&organizer), defaults);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let event = client.get_event(&event_id);
+    assert_eq!(event.max_uses, 3);
+    assert!(event.allow_reentry);
+    assert_eq!(client.get_event_policies(&event_id).get(0).unwrap().hash, policy);
+    assert_eq!(client.get_deposit_terms(&event_id), Some(5_000));
+    assert_eq!(client.get_resale_split(&event_id), split);
+    assert!(!client.is_transferable(&event_id));
+    assert_eq!(
+        client.get_tier_sales_window(&event_id, &1),
+        Some(SalesWindow { opens_at: 100, closes_at: 900 })
+    );
+    assert_eq!(
+        client.try_place_offer(&Address::generate(&env), &OfferTarget::Event(event_id), &50),
+        Err(Err(InvokeError::Contract(TicketError::NotTransferable as u32)))
+    );
+    
+    // Clearing the profile leaves later events on the platform defaults
+    client.set_organizer_defaults(&organizer, &Vec::new(&env));
+    let plain = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    assert_eq!(client.get_event(&plain).max_uses, 1);
+    assert_eq!(client.get_deposit_terms(&plain), None);
+    assert!(client.is_transferable(&plain));
+    assert_eq!(client.get_tier_sales_window(&plain, &1), None);
+}
+
+#[test]
+fn test_event_tags_and_tag_queries() {
+    use soroban_sdk::symbol_short;
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let create = || {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let (jazz_night, jazz_brunch) = (create(), create());
+    let (jazz, live, food) = (symbol_short!("jazz"), symbol_short!("live"), symbol_short!("food"));
+    
+    client.set_event_tags(&organizer, &jazz_night, &vec![&env, jazz.clone(), live.clone(), jazz.clone()]);
+    client.set_event_tags(&organizer, &jazz_brunch, &vec![&env, jazz.clone(), food.clone()]);
+    assert_eq!(client.get_event_tags(&jazz_night), vec![&env, jazz.clone(), live.clone()]);
+    
+    assert_eq!(client.list_events_by_tag(&jazz, &None, &10), (vec![&env, jazz_night, jazz_brunch], None));
+    assert_eq!(
+        client.list_events_by_tag(&jazz, &None, &1),
+        (vec![&env, jazz_night], Some(jazz_night))
+    );
+    assert_eq!(client.list_events_by_tag(&jazz, &Some(jazz_night), &10), (vec![&env, jazz_brunch], None));
+    assert_eq!(
+        client.try_list_events_by_tag(&jazz, &None, &51),
+        Err(Ok(LumentixError::BatchTooLarge))
+    );
+    
+    // Retagging drops the event from tags it no longer carries
+    client.set_event_tags(&organizer, &jazz_night, &vec![&env, live.clone()]);
+    assert_eq!(client.list_events_by_tag(&jazz, &None, &10), (vec![&env, jazz_brunch], None));
+    assert_eq!(client.list_events_by_tag(&live, &None, &10), (vec![&env, jazz_night], None));
+    
+    // An indexer resuming from the last ID it saw picks up newly tagged events
+    let (first, cursor) = client.list_events_by_tag(&live, &None, &1);
+    assert_eq!((first, cursor), (vec![&env, jazz_night], None));
+    client.set_event_tags(&organizer, &jazz_brunch, &vec![&env, live.clone()]);
+    let (rest, _) = client.list_events_by_tag(&live, &Some(jazz_night), &10);
+    assert_eq!(rest, vec![&env, jazz_brunch]);
+    
+    let too_many = vec![
+        &env,
+        symbol_short!("a"),
+        symbol_short!("b"),
+        symbol_short!("c"),
+        symbol_short!("d"),
+        symbol_short!("e"),
+        symbol_short!("f"),
+    ];
+    assert_eq!(
+        client.try_set_event_tags(&organizer, &jazz_night, &too_many),
+        Err(Ok(LumentixError::CapacityExceeded))
+    );
+    assert_eq!(
+        client.try_set_event_tags(&Address::generate(&env), &jazz_night, &vec![&env, food]),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_featured_events_ranking() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let create = || {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let (first, second, third) = (create(), create(), create());
+    
+    assert_eq!(
+        client.try_set_featured(&organizer, &first, &1, &500),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_featured(&admin, &999, &1, &500),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+    
+    client.set_featured(&admin, &second, &2, &500);
+    client.set_featured(&admin, &first, &1, &500);
+    client.set_featured(&admin, &third, &3, &100);
+    let ids = |featured: Vec<FeaturedEvent>| {
+        let mut ids = Vec::new(&env);
+        for placement in featured.iter() {
+            ids.push_back(placement.event_id);
+        }
+        ids
+    };
+    assert_eq!(ids(client.list_featured_events()), vec![&env, first, second, third]);
+    
+    // Placements lapse at their `until` and can be re-ranked or removed
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(ids(client.list_featured_events()), vec![&env, first, second]);
+    client.set_featured(&admin, &first, &5, &500);
+    assert_eq!(ids(client.list_featured_events()), vec![&env, second, first]);
+    client.set_featured(&admin, &second, &0, &0);
+    assert_eq!(ids(client.list_featured_events()), vec![&env, first]);
+}
+
+#[test]
+fn test_promotion_slots_pay_platform() {
+    use crate::testutils::{setup_contract, setup_event_with_sales};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 1);
+    let token = TokenClient::new(&env, &fixture.token);
+    StellarAssetClient::new(&env, &fixture.token).mint(&fixture.organizer, &1_000);
+    
+    // A day and a half is charged as two started days
+    assert_eq!(client.buy_promotion(&fixture.organizer, &fixture.event_id, &0, &129_600), 200);
+    assert_eq!(token.balance(&fixture.organizer), 800);
+    assert_eq!(client.get_platform_balance(), 200);
+    assert_eq!(client.list_promotions().get(0).unwrap().until, 129_600);
+    
+    assert_eq!(
+        client.try_buy_promotion(&fixture.organizer, &fixture.event_id, &0, &3_600),
+        Err(Ok(LumentixError::SponsorshipTaken))
+    );
+    assert_eq!(
+        client.try_buy_promotion(&fixture.organizer, &fixture.event_id, &3, &3_600),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 129_600);
+    assert_eq!(client.list_promotions().len(), 0);
+    assert_eq!(client.buy_promotion(&fixture.organizer, &fixture.event_id, &0, &3_600), 100);
+    assert_eq!(
+        client.try_buy_promotion(&fixture.organizer, &fixture.event_id, &1, &u64::MAX),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+}
+
+#[test]
+fn test_promotion_without_payment_asset_credits_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Promoted"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &10u32,
+    );
+    
+    // No tokens move for an accounting-only event, so the fee is not held
+    assert_eq!(client.buy_promotion(&organizer, &event_id, &0, &3_600), 100);
+    let totals = client.get_reconciliation();
+    assert_eq!(totals.deposits, 0);
+    assert_eq!(totals.platform_balance, 0);
+}
+
+#[test]
+fn test_follow_draft_event_and_notify_on_publish() {
+    use soroban_sdk::{symbol_short, testutils::Events, FromVal, IntoVal};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let (fan, other_fan) = (Address::generate(&env), Address::generate(&env));
+    
+    let event_id = client.create_draft_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    assert_eq!(client.follow_event(&fan, &event_id), 1);
+    assert_eq!(client.follow_event(&fan, &event_id), 1);
+    assert_eq!(client.follow_event(&other_fan, &event_id), 2);
+    let (first, cursor) = client.get_followers(&event_id, &None, &1);
+    let (rest, end) = client.get_followers(&event_id, &cursor, &10);
+    assert_eq!(cursor, first.last());
+    assert_eq!(end, None);
+    assert_eq!(rest.len(), 1);
+    assert!(first.first() < rest.first());
+    
+    client.unfollow_event(&fan, &event_id);
+    assert_eq!(client.get_follower_count(&event_id), 1);
+    assert_eq!(client.get_followers(&event_id, &None, &10), (vec![&env, other_fan.clone()], None));
+    
+    client.publish_event(&organizer, &event_id);
+    let events = env.events().all();
+    let opened = events.get(events.len() - 1).unwrap();
+    assert_eq!(opened.1, (symbol_short!("event"), symbol_short!("opened"), event_id).into_val(&env));
+    assert_eq!(u32::from_val(&env, &opened.2), 1);
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    assert_eq!(
+        client.try_follow_event(&fan, &event_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+}
+
+#[test]
+fn test_oversold_standby_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &200_000u64,
+        &300_000u64,
+        &100i128,
+        &2u32,
+    );
+    client.set_oversell(&organizer, &event_id, &10_000);
+    
+    let buy = || client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let (seated, _) = (buy(), buy());
+    let (first_standby, second_standby) = (buy(), buy());
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::EventSoldOut))
+    );
+    assert_eq!(client.get_ticket(&first_standby).status, TicketStatus::Standby);
+    assert_eq!(client.get_standby_tickets(&event_id), vec![&env, first_standby, second_standby]);
+    assert_eq!(
+        client.try_use_ticket(&first_standby, &organizer, &Bytes::new(&env)),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    // One refunded seat confirms the earliest standby ticket
+    client.request_late_refund(&buyer, &seated, &RefundReason::CannotAttend);
+    client.approve_late_refund(&organizer, &seated);
+    assert_eq!(
+        client.try_resolve_standby(&event_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 200_000 - 86_400);
+    assert_eq!(client.resolve_standby(&event_id), 1);
+    assert_eq!(client.get_ticket(&first_standby).status, TicketStatus::Valid);
+    assert_eq!(client.get_ticket(&second_standby).status, TicketStatus::Refunded);
+    assert_eq!(client.get_refund_breakdown(&event_id).get(RefundReason::Oversold), Some(1));
+    assert_eq!(client.get_standby_tickets(&event_id).len(), 0);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 200);
+}
+
+#[test]
+fn test_standby_and_imported_sales_keep_ticket_counters_in_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &200_000u64,
+        &300_000u64,
+        &100i128,
+        &3u32,
+    );
+    client.set_oversell(&organizer, &event_id, &10_000);
+    client.approve_import(&admin, &event_id, &1);
+    client.import_tickets(&organizer, &event_id, &vec![&env, (buyer.clone(), 0u32)]);
+    
+    let sold = || (client.get_event(&event_id).tickets_sold, client.get_event_stats(&event_id).tickets_sold);
+    let buy = || client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let (seated, _) = (buy(), buy());
+    let (first_standby, second_standby, third_standby) = (buy(), buy(), buy());
+    assert_eq!(sold(), (6, 6));
+    assert_eq!(client.get_remaining_capacity(&event_id), 0);
+    
+    // A withdrawn standby ticket frees no seat; a refunded seated one does
+    for ticket_id in [third_standby, seated] {
+        client.request_late_refund(&buyer, &ticket_id, &RefundReason::CannotAttend);
+        client.approve_late_refund(&organizer, &ticket_id);
+    }
+    
+    env.ledger().with_mut(|li| li.timestamp = 200_000 - 86_400);
+    assert_eq!(client.resolve_standby(&event_id), 1);
+    assert_eq!(client.get_ticket(&first_standby).status, TicketStatus::Valid);
+    assert_eq!(client.get_ticket(&second_standby).status, TicketStatus::Refunded);
+    assert_eq!(sold(), (6, 6));
+    assert_eq!(client.get_event_stats(&event_id).tickets_refunded, 3);
+}
+
+#[test]
+fn test_checkin_hash_matches_shared_types() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let owner = Address::generate(&env);
+    
+    let hash = client.compute_checkin_hash(&7, &owner, &1);
+    assert_eq!(hash, lumentix_types::compute_checkin_hash(&env, 7, &owner, 1));
+    assert_ne!(hash, client.compute_checkin_hash(&7, &owner, &2));
+    assert_ne!(hash, client.compute_checkin_hash(&8, &owner, &1));
+    assert_ne!(hash, client.compute_checkin_hash(&7, &Address::generate(&env), &1));
+}
+
+#[test]
+fn test_admin_refund_ticket_override() {
+    use soroban_sdk::{symbol_short, testutils::Events, IntoVal};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.request_late_refund(&buyer, &ticket_id, &RefundReason::CannotAttend);
+    
+    assert_eq!(
+        client.try_admin_refund_ticket(&organizer, &ticket_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    client.admin_refund_ticket(&admin, &ticket_id);
+    let events = env.events().all();
+    assert!(events.iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("ticket"), symbol_short!("adm_rfnd"), event_id).into_val(&env)
+    }));
+    assert_eq!(client.get_ticket(&ticket_id).status, TicketStatus::Refunded);
+    assert_eq!(client.get_late_refund(&ticket_id).unwrap().status, LateRefundStatus::Approved);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 100);
+    assert_eq!(
+        client.try_admin_refund_ticket(&admin, &ticket_id),
+        Err(Ok(LumentixError::RefundNotAllowed))
+    );
+}
+
+#[test]
+fn test_rotate_organizer_key() {
+    use soroban_sdk::symbol_short;
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let new_key = Address::generate(&env);
+    let other = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let mut event_ids = Vec::new(&env);
+    for _ in 0..2 {
+        event_ids.push_back(client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        ));
+    }
+    let event_id = event_ids.get(0).unwrap();
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.create_event(
+        &other,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    assert_eq!(
+        client.try_rotate_organizer_key(&organizer, &other),
+        Err(Ok(LumentixError::InvalidAddress))
+    );
+    assert_eq!(client.rotate_organizer_key(&organizer, &new_key), 2);
+    
+    assert_eq!(client.get_organizer_events(&new_key, &None, &10), (event_ids, None));
+    assert_eq!(client.get_organizer_events(&organizer, &None, &10).0.len(), 0);
+    assert_eq!(client.get_organizer_active_events(&new_key), 2);
+    assert_eq!(client.get_organizer_active_events(&organizer), 0);
+    assert_eq!(client.get_event(&event_id).organizer, new_key);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 100);
+    
+    let tags = vec![&env, symbol_short!("music")];
+    assert_eq!(
+        client.try_set_event_tags(&organizer, &event_id, &tags),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.set_event_tags(&new_key, &event_id, &tags);
+}
+
+#[test]
+fn test_tier_escrow_accounting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let ticket1 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let ticket2 = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_tier_escrow(&event_id, &0), 300);
+    assert_eq!(client.get_tier_escrow(&event_id, &1), 0);
+    
+    client.admin_refund_ticket(&admin, &ticket1);
+    assert_eq!(client.get_tier_escrow(&event_id, &0), 200);
+    
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    client.curtail_event(&organizer, &event_id, &5_000u32);
+    client.issue_prorata_refunds(&organizer, &event_id, &vec![&env, ticket2]);
+    assert_eq!(client.get_tier_escrow(&event_id, &0), 150);
+    
+    // Paying the organizer empties every tier; reserved refunds still settle
+    client.release_escrow(&organizer, &event_id);
+    assert_eq!(client.get_tier_escrows(&event_id).len(), 0);
+    assert_eq!(client.issue_prorata_refunds(&organizer, &event_id, &vec![&env, ticket2 + 1]), 50);
+    assert_eq!(client.get_tier_escrow(&event_id, &0), 0);
+}
+
+#[test]
+fn test_scheduled_fee_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    // The fee cannot be changed without notice through the config
+    assert_eq!(
+        client.try_set_config(&admin, &PlatformConfig { platform_fee_bps: 500, ..client.get_config() }),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+    
+    let notice = client.get_config().fee_change_notice_secs;
+    assert_eq!(
+        client.try_schedule_fee_change(&admin, &500u32, &(notice - 1)),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+    assert_eq!(
+        client.try_schedule_fee_change(&admin, &1_001u32, &notice),
+        Err(Ok(LumentixError::InvalidConfig))
+    );
+    
+    client.schedule_fee_change(&admin, &500u32, &notice);
+    assert_eq!(client.get_pending_fee_change().unwrap().fee_bps, 500);
+    assert_eq!(client.preview_fee(&event_id).fee_bps, 0);
+    
+    env.ledger().with_mut(|li| li.timestamp = notice);
+    assert_eq!(client.get_pending_fee_change(), None);
+    assert_eq!(client.get_config().platform_fee_bps, 500);
+    assert_eq!(client.preview_fee(&event_id).fee, 5);
+    assert_eq!(
+        client.try_cancel_fee_change(&admin),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    // A new announcement keeps the rate already in effect until it lands
+    client.schedule_fee_change(&admin, &100u32, &(2 * notice));
+    assert_eq!(client.get_config().platform_fee_bps, 500);
+    client.cancel_fee_change(&admin);
+    assert_eq!(client.get_pending_fee_change(), None);
+    assert_eq!(client.get_config().platform_fee_bps, 500);
+}
+
+#[test]
+fn test_refund_returns_recorded_payment() {
+    use crate::testutils::{funded_account, setup_contract, setup_event_with_sales, FIXTURE_BUYER_BALANCE};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 0);
+    let token = TokenClient::new(&env, &fixture.token);
+    let buyer = funded_account(&env, &fixture.token, FIXTURE_BUYER_BALANCE);
+    
+    // An overpayment is recorded with the token and refunded in full
+    let asset = client.get_event_payment_asset(&fixture.event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &fixture.event_id, &120i128, &None, &asset, &None);
+    assert_eq!(
+        client.get_ticket_payment(&ticket_id),
+        Some(TicketPayment { token: Some(fixture.token.clone()), amount: 120 })
+    );
+    assert_eq!(client.get_tier_escrow(&fixture.event_id, &0), 120);
+    
+    client.cancel_event(&fixture.organizer, &fixture.event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(token.balance(&buyer), FIXTURE_BUYER_BALANCE);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_refund_receipt(&ticket_id).unwrap().from_escrow, 120);
+}
+
+#[test]
+fn test_buyer_history() {
+    use crate::testutils::{setup_contract, setup_event_with_sales, FIXTURE_TICKET_PRICE};
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 2);
+    let seller = fixture.buyers.get(0).unwrap();
+    let buyer = fixture.buyers.get(1).unwrap();
+    let ticket_a = fixture.ticket_ids.get(0).unwrap();
+    let ticket_b = fixture.ticket_ids.get(1).unwrap();
+    
+    let offer_id = client.place_offer(&buyer, &OfferTarget::Ticket(ticket_a), &150i128);
+    client.accept_offer(&seller, &offer_id, &ticket_a);
+    client.cancel_event(&fixture.organizer, &fixture.event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket_b, &buyer);
+    
+    let (history, cursor) = client.get_buyer_history(&seller, &None, &10);
+    assert_eq!(cursor, None);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(1).unwrap().id, 1);
+    assert_eq!(history.get(0).unwrap().action, HistoryAction::Purchased);
+    assert_eq!(history.get(1).unwrap().action, HistoryAction::TransferredOut);
+    assert_eq!(history.get(1).unwrap().amount, 150);
+    
+    let (first, cursor) = client.get_buyer_history(&buyer, &None, &1);
+    assert_eq!(first.get(0).unwrap().action, HistoryAction::Purchased);
+    assert_eq!(cursor, Some(0));
+    let (history, _) = client.get_buyer_history(&buyer, &cursor, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().action, HistoryAction::TransferredIn);
+    assert_eq!(history.get(0).unwrap().ticket_id, ticket_a);
+    assert_eq!(history.get(1).unwrap().action, HistoryAction::Refunded);
+    assert_eq!(history.get(1).unwrap().amount, FIXTURE_TICKET_PRICE);
+    
+    assert_eq!(
+        client.try_get_buyer_history(&buyer, &None, &51),
+        Err(Ok(LumentixError::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_event_announcements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let other = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let doors = BytesN::from_array(&env, &[1u8; 32]);
+    assert_eq!(
+        client.try_post_announcement(&other, &event_id, &doors),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.post_announcement(&organizer, &event_id, &doors), 1);
+    let parking = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(client.post_announcement(&organizer, &event_id, &parking), 2);
+    
+    let (announcements, cursor) = client.get_announcements(&event_id, &None, &10);
+    assert_eq!(cursor, None);
+    assert_eq!(announcements.len(), 2);
+    assert_eq!(
+        announcements.get(0).unwrap(),
+        Announcement { number: 1, content_hash: doors, posted_at: 500 }
+    );
+    let (first, cursor) = client.get_announcements(&event_id, &None, &1);
+    assert_eq!((first.len(), cursor), (1, Some(1)));
+    assert_eq!(client.get_announcements(&event_id, &cursor, &1).0.get(0).unwrap().content_hash, parking);
+}
+
+#[test]
+fn test_ticket_lending() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let employee = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &None, &None, &None);
+    let owner_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.rotate_checkin_hash(&owner, &ticket_id, &owner_hash);
+    
+    assert_eq!(
+        client.try_lend_ticket(&owner, &ticket_id, &employee, &0u64),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+    client.lend_ticket(&owner, &ticket_id, &employee, &500u64);
+    assert_eq!(client.get_ticket_holder(&ticket_id), employee);
+    assert_eq!(client.get_checkin_hash(&ticket_id), None);
+    
+    // Only the borrower sets the commitment during the loan
+    let employee_hash = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(
+        client.try_rotate_checkin_hash(&owner, &ticket_id, &employee_hash),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.rotate_checkin_hash(&employee, &ticket_id, &employee_hash);
+    assert_eq!(client.get_checkin_hash(&ticket_id), Some(employee_hash));
+    assert_eq!(
+        client.try_lend_ticket(&owner, &ticket_id, &organizer, &600u64),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    // The loan lapses at its deadline
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.get_ticket_loan(&ticket_id), None);
+    assert_eq!(client.get_ticket_holder(&ticket_id), owner);
+    assert_eq!(client.get_checkin_hash(&ticket_id), Some(owner_hash));
+    assert_eq!(
+        client.try_end_loan(&owner, &ticket_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    client.lend_ticket(&owner, &ticket_id, &employee, &900u64);
+    assert_eq!(
+        client.try_admin_refund_ticket(&admin, &ticket_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    client.request_late_refund(&owner, &ticket_id, &RefundReason::CannotAttend);
+    assert_eq!(
+        client.try_approve_late_refund(&organizer, &ticket_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    assert_eq!(
+        client.try_refund_ticket(&ticket_id, &owner),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    client.end_loan(&owner, &ticket_id);
+    client.refund_ticket(&ticket_id, &owner);
+}
+
+#[test]
+fn test_custom_ticket_fields() {
+    use soroban_sdk::{symbol_short, Map};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let diet = symbol_short!("diet");
+    let shirt = symbol_short!("shirt");
+    client.set_ticket_fields(&organizer, &event_id, &vec![&env, diet.clone(), shirt.clone(), diet.clone()]);
+    assert_eq!(client.get_ticket_fields(&event_id), vec![&env, diet.clone(), shirt.clone()]);
+    
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None),
+        Err(Ok(LumentixError::PolicyMismatch))
+    );
+    let mut values = Map::new(&env);
+    values.set(diet.clone(), BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(
+        client.try_purchase_ticket_with_fields(&buyer, &event_id, &100i128, &None, &None, &values, &None),
+        Err(Ok(LumentixError::PolicyMismatch))
+    );
+    
+    values.set(shirt, BytesN::from_array(&env, &[2u8; 32]));
+    let ticket_id = client.purchase_ticket_with_fields(&buyer, &event_id, &100i128, &None, &None, &values, &None);
+    assert_eq!(client.get_ticket_field_values(&ticket_id), values);
+    assert_eq!(
+        client.try_set_ticket_fields(&organizer, &event_id, &vec![&env, diet]),
+        Err(Ok(LumentixError::TicketsAlreadySold))
+    );
+}
+
+#[test]
+fn test_purchase_request_with_add_ons_and_fields() {
+    use soroban_sdk::{symbol_short, Map};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let parking = client.add_add_on(&organizer, &event_id, &String::from_str(&env, "Parking"), &20i128, &5u32);
+    let diet = symbol_short!("diet");
+    client.set_ticket_fields(&organizer, &event_id, &vec![&env, diet.clone()]);
+    let mut fields = Map::new(&env);
+    fields.set(diet, BytesN::from_array(&env, &[1u8; 32]));
+    
+    // Neither narrower entrypoint can sell to an event with both
+    assert_eq!(
+        client.try_purchase_ticket_with_add_ons(&buyer, &event_id, &120, &None, &None, &vec![&env, parking], &None),
+        Err(Ok(LumentixError::PolicyMismatch))
+    );
+    
+    let memo = BytesN::from_array(&env, &[7u8; 32]);
+    let request = PurchaseRequest {
+        terms_hash: OptionalHash::None,
+        payment_asset: OptionalAsset::None,
+        add_ons: vec![&env, parking],
+        fields: fields.clone(),
+        memo: OptionalHash::Some(memo.clone()),
+    };
+    assert_eq!(
+        client.try_purchase_ticket_with_request(&buyer, &event_id, &119, &request),
+        Err(Ok(LumentixError::InsufficientFunds))
+    );
+    let ticket_id = client.purchase_ticket_with_request(&buyer, &event_id, &120, &request);
+    assert_eq!(client.get_ticket_field_values(&ticket_id), fields);
+    assert_eq!(client.get_ticket_add_ons(&ticket_id).unwrap().paid, 20);
+    assert_eq!(client.get_purchase_memo(&ticket_id), Some(memo));
+}
+
+#[test]
+fn test_bundle_discounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer_a = Address::generate(&env);
+    let organizer_b = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    
+    let create = |organizer: &Address| {
+        client.create_event(
+            organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        )
+    };
+    let event_a = create(&organizer_a);
+    let event_b = create(&organizer_b);
+    
+    assert_eq!(
+        client.try_set_bundle_discount(&organizer_a, &event_b, &event_a, &2_000u32),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_bundle_discount(&organizer_b, &event_b, &event_b, &2_000u32),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+    client.set_bundle_discount(&organizer_b, &event_b, &event_a, &2_000u32);
+    
+    client.purchase_ticket(&holder, &event_a, &100i128, &None, &None, &None);
+    assert_eq!(client.get_holdings(&holder, &event_a), 1);
+    assert_eq!(client.simulate_purchase(&holder, &event_b, &1, &None).total_cost, 80);
+    
+    // Only holders of a partner event's ticket get the discount
+    assert_eq!(
+        client.try_purchase_ticket(&stranger, &event_b, &80i128, &None, &None, &None),
+        Err(Ok(LumentixError::InsufficientFunds))
+    );
+    let ticket_id = client.purchase_ticket(&holder, &event_b, &80i128, &None, &None, &None);
+    assert_eq!(client.get_ticket_payment(&ticket_id).unwrap().amount, 80);
+    
+    client.set_bundle_discount(&organizer_b, &event_b, &event_a, &0u32);
+    assert_eq!(client.get_bundle_discounts(&event_b).len(), 0);
+    assert_eq!(client.simulate_purchase(&holder, &event_b, &1, &None).total_cost, 100);
+}
+
+mod mock_membership {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct MockMembership;
+
+    #[contractimpl]
+    impl MockMembership {
+        pub fn set_tier(env: Env, member: Address, tier: u32) {
+            env.storage().persistent().set(&member, &tier);
+        }
+
+        pub fn tier_of(env: Env, member: Address) -> Option<u32> {
+            env.storage().persistent().get(&member)
+        }
+    }
+}
+
+#[test]
+fn test_member_pricing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let member = Address::generate(&env);
+    let guest = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    
+    let membership_id = env.register_contract(None, mock_membership::MockMembership);
+    mock_membership::MockMembershipClient::new(&env, &membership_id).set_tier(&member, &2);
+    
+    let mut prices = Map::new(&env);
+    prices.set(2u32, 0i128);
+    let mut pricing = MemberPricing { contract: membership_id.clone(), prices };
+    assert_eq!(
+        client.try_set_member_pricing(&organizer, &event_id, &Some(pricing.clone())),
+        Err(Ok(LumentixError::InvalidAmount))
+    );
+    pricing.prices.set(2u32, 60i128);
+    client.set_member_pricing(&organizer, &event_id, &Some(pricing.clone()));
+    assert_eq!(client.get_member_pricing(&event_id), Some(pricing));
+    
+    assert_eq!(client.simulate_purchase(&member, &event_id, &1, &None).total_cost, 60);
+    assert_eq!(client.simulate_purchase(&guest, &event_id, &1, &None).total_cost, 100);
+    client.purchase_ticket(&member, &event_id, &60i128, &None, &None, &None);
+    assert_eq!(
+        client.try_purchase_ticket(&guest, &event_id, &60i128, &None, &None, &None),
+        Err(Ok(LumentixError::InsufficientFunds))
+    );
+    
+    // A membership contract that fails never blocks a regular sale
+    client.set_member_pricing(
+        &organizer,
+        &event_id,
+        &Some(MemberPricing { contract: organizer.clone(), prices: Map::new(&env) }),
+    );
+    client.purchase_ticket(&guest, &event_id, &100i128, &None, &None, &None);
+    
+    client.set_member_pricing(&organizer, &event_id, &None);
+    assert_eq!(client.get_member_pricing(&event_id), None);
+}
+
+#[test]
+fn test_storage_rent_prefunding() {
+    use crate::testutils::{create_token, funded_account};
+    use soroban_sdk::token::TokenClient;
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, LumentixContract);
+    let client = LumentixContractClient::new(&env, &contract_id);
+    let token = create_token(&env);
+    let organizer = funded_account(&env, &token, 10_000);
+    client.initialize(
+        &Address::generate(&env),
+        &PlatformConfig { rent_per_ticket_day: 2, ..PlatformConfig::default() },
+        &vec![&env],
+        &Some(PaymentAsset { token: token.clone(), decimals: 7 }),
+    );
+    
+    // 50 tickets for 4 started days
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &345_600u64,
+        &100i128,
+        &50u32,
+    );
+    let rent = client.get_rent_deposit(&event_id).unwrap();
+    assert_eq!(rent.amount, 400);
+    assert_eq!(TokenClient::new(&env, &token).balance(&organizer), 9_600);
+    assert_eq!(client.get_reconciliation().reserved_fees, 400);
+    
+    // A quarter of the funded period has passed
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    assert_eq!(client.extend_event_storage(&event_id), 100);
+    assert_eq!(client.extend_event_storage(&event_id), 0);
+    assert_eq!(client.get_reconciliation().reserved_fees, 300);
+    
+    env.ledger().with_mut(|li| li.timestamp = 172_800);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    let rent = client.get_rent_deposit(&event_id).unwrap();
+    assert_eq!((rent.consumed, rent.refunded), (200, 200));
+    assert_eq!(TokenClient::new(&env, &token).balance(&organizer), 9_800);
+    assert_eq!(client.get_reconciliation().reserved_fees, 0);
+    assert_eq!(client.get_platform_balance(), 200);
+}
+
+#[test]
+fn test_event_state_digest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let empty = client.get_event_state_digest(&event_id);
+    
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let sold = client.get_event_state_digest(&event_id);
+    assert_ne!(sold, empty);
+    
+    // The digest can be rebuilt off-chain from the ticket writes
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let ticket_digest = lumentix_types::fold_ticket_digest(&env, &zero, &client.get_ticket(&ticket_id));
+    assert_eq!(
+        sold,
+        lumentix_types::compute_state_digest(&env, &ticket_digest, 100, &client.get_event_stats(&event_id))
+    );
+    
+    client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env));
+    assert_ne!(client.get_event_state_digest(&event_id), sold);
+    assert_eq!(
+        client.try_get_event_state_digest(&(event_id + 1)),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+}
+
+#[test]
+fn test_granular_event_topics() {
+    use soroban_sdk::{symbol_short, testutils::Events, FromVal, IntoVal, Val};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    client.refund_ticket(&ticket_id, &buyer);
+    
+    let status_topic: Vec<Val> = (symbol_short!("event"), symbol_short!("status"), event_id).into_val(&env);
+    let mut statuses = Vec::new(&env);
+    for (_, topics, data) in env.events().all().iter() {
+        if topics == status_topic {
+            statuses.push_back(EventStatus::from_val(&env, &data));
+        }
+    }
+    assert_eq!(statuses, vec![&env, EventStatus::Active, EventStatus::Cancelled]);
+    
+    for action in [symbol_short!("purchased"), symbol_short!("refunded")] {
+        let topic: Vec<Val> = (symbol_short!("ticket"), action, event_id).into_val(&env);
+        let (_, _, data) = env.events().all().iter().find(|(_, topics, _)| *topics == topic).unwrap();
+        assert_eq!(u64::from_val(&env, &data), ticket_id);
+    }
+}
+
+#[test]
+fn test_signed_check_in() {
+    use soroban_sdk::testutils::AuthorizedFunction;
+    use soroban_sdk::{IntoVal, InvokeError, Symbol};
+    
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    let late_ticket = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    
+    // The gate scanned both passes offline and submits them later
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    let result = client.check_in_signed(&organizer, &ticket_id, &1600, &7, &Bytes::new(&env));
+    assert!(env.auths().iter().any(|(address, invocation)| {
+        *address == buyer
+            && invocation.function
+                == AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "check_in_signed"),
+                    (ticket_id, 1600u64, 7u64).into_val(&env),
+                ))
+    }));
+    assert_eq!(result.owner, buyer);
+    assert_eq!(client.get_ticket(&ticket_id).status, TicketStatus::Used);
+    assert!(client.is_checkin_nonce_used(&buyer, &7));
+    
+    // A replayed or expired pass is told apart from a used ticket
+    assert_eq!(
+        client.try_check_in_signed(&organizer, &late_ticket, &1600, &7, &Bytes::new(&env)),
+        Err(Err(InvokeError::Contract(TicketError::NonceUsed as u32)))
+    );
+    assert_eq!(
+        client.try_check_in_signed(&organizer, &late_ticket, &1400, &8, &Bytes::new(&env)),
+        Err(Err(InvokeError::Contract(TicketError::PassExpired as u32)))
+    );
+    assert_eq!(
+        client.try_check_in_signed(&buyer, &late_ticket, &1600, &8, &Bytes::new(&env)),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    client.check_in_signed(&organizer, &late_ticket, &1600, &8, &Bytes::new(&env));
+    assert_eq!(client.get_ticket(&late_ticket).status, TicketStatus::Used);
+}
+
+#[test]
+fn test_gate_devices_check_in_until_revoked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    
+    let mut event_ids = Vec::new(&env);
+    for _ in 0..2 {
+        event_ids.push_back(client.create_event(
+            &organizer,
+            &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+        ));
+    }
+    let (event_id, other_event) = (event_ids.get(0).unwrap(), event_ids.get(1).unwrap());
+    let mut ticket_ids = Vec::new(&env);
+    for _ in 0..3 {
+        ticket_ids.push_back(client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None));
+    }
+    let other_ticket = client.purchase_ticket(&buyer, &other_event, &100i128, &None, &None, &None);
+    
+    assert_eq!(
+        client.try_register_gate_device(&buyer, &event_id, &scanner, &1800),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_register_gate_device(&organizer, &event_id, &scanner, &0),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+    client.register_gate_device(&organizer, &event_id, &scanner, &1800);
+    assert_eq!(
+        client.get_gate_devices(&event_id),
+        vec![&env, GateDevice { device: scanner.clone(), expires_at: 1800 }]
+    );
+    
+    // The device checks tickets in at its own event only
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    client.use_ticket(&ticket_ids.get(0).unwrap(), &scanner, &Bytes::new(&env));
+    assert_eq!(client.get_ticket(&ticket_ids.get(0).unwrap()).status, TicketStatus::Used);
+    assert!(matches!(
+        client.check_in(&scanner, &event_id, &ticket_ids.get(1).unwrap(), &Bytes::new(&env)),
+        CheckInOutcome::Admitted(_)
+    ));
+    assert_eq!(
+        client.try_use_ticket(&other_ticket, &scanner, &Bytes::new(&env)),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    // Check-in is all it can do
+    assert_eq!(
+        client.try_check_out(&ticket_ids.get(0).unwrap(), &scanner),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_register_gate_device(&scanner, &event_id, &buyer, &1800),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    // Revocation applies at once
+    client.revoke_gate_device(&organizer, &event_id, &scanner);
+    assert_eq!(client.get_gate_devices(&event_id).len(), 0);
+    assert_eq!(
+        client.try_use_ticket(&ticket_ids.get(2).unwrap(), &scanner, &Bytes::new(&env)),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    // So does expiry
+    client.register_gate_device(&organizer, &event_id, &scanner, &1600);
+    env.ledger().with_mut(|li| li.timestamp = 1600);
+    assert_eq!(
+        client.try_use_ticket(&ticket_ids.get(2).unwrap(), &scanner, &Bytes::new(&env)),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.use_ticket(&ticket_ids.get(2).unwrap(), &organizer, &Bytes::new(&env));
+}
+
+#[test]
+fn test_will_call_pickup() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let friend = Address::generate(&env);
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+    );
+    client.set_will_call(&organizer, &event_id, &Some(200));
+    
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &None, &None, &None);
+    assert_eq!(client.get_ticket(&ticket_id).status, TicketStatus::WillCall);
+    assert_eq!(
+        client.try_set_will_call(&organizer, &event_id, &None),
+        Err(Ok(LumentixError::TicketsAlreadySold))
+    );
+    
+    // Held tickets can neither be resold nor admitted
+    assert_eq!(
+        client.try_lend_ticket(&buyer, &ticket_id, &friend, &500),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer, &Bytes::new(&env)),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+    
+    let code = Bytes::from_slice(&env, b"pickup-code");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    client.set_pickup(&buyer, &ticket_id, &friend, &code_hash);
+    assert_eq!(client.get_pickup(&ticket_id).unwrap().pickup, friend);
+    
+    assert_eq!(
+        client.try_claim_will_call(&friend, &ticket_id, &code),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 800);
+    assert_eq!(
+        client.try_claim_will_call(&friend, &ticket_id, &Bytes::from_slice(&env, b"guess")),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_claim_will_call(&organizer, &ticket_id, &code),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    client.claim_will_call(&friend, &ticket_id, &code);
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!((ticket.status, ticket.owner), (TicketStatus::Valid, friend.clone()));
+    assert_eq!(client.get_pickup(&ticket_id), None);
+    assert_eq!(client.get_holdings(&friend, &event_id), 1);
+    
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    assert_eq!(client.use_ticket(&ticket_id, &organizer, &Bytes::new(&env)).owner, friend);
+}
+
+#[test]
+fn test_import_legacy_tickets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &3u32,
+    );
+    let legacy = vec![&env, (alice.clone(), 0u32), (bob.clone(), 1u32)];
+    
+    assert_eq!(
+        client.try_import_tickets(&organizer, &event_id, &legacy),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_approve_import(&organizer, &event_id, &2),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.approve_import(&admin, &event_id, &1);
+    assert_eq!(
+        client.try_import_tickets(&organizer, &event_id, &legacy),
+        Err(Ok(LumentixError::CapacityExceeded))
+    );
+    client.approve_import(&admin, &event_id, &2);
+    
+    let ids = client.import_tickets(&organizer, &event_id, &legacy);
+    assert_eq!(ids.len(), 2);
+    let imported = client.get_ticket(&ids.get(1).unwrap());
+    assert_eq!((imported.owner, imported.tier, imported.fee_paid), (bob.clone(), 1, 0));
+    assert!(client.is_imported_ticket(&ids.get(0).unwrap()));
+    assert_eq!(client.get_import_allowance(&event_id), Some(ImportAllowance { approved: 2, imported: 2 }));
+    assert_eq!(
+        client.try_approve_import(&admin, &event_id, &1),
+        Err(Ok(LumentixError::InvalidCapacity))
+    );
+    
+    // Imported tickets take seats but bring no escrow
+    assert_eq!(client.get_event(&event_id).tickets_sold, 2);
+    assert_eq!(client.get_event_stats(&event_id).revenue, 0);
+    let sold = client.purchase_ticket(&alice, &event_id, &100i128, &None, &None, &None);
+    assert!(!client.is_imported_ticket(&sold));
+    
+    client.cancel_event(&organizer, &event_id, &CancellationReason::Other, &None);
+    assert_eq!(client.get_refund_deadline(&ids.get(0).unwrap()), RefundDeadline::NotRefundable);
+    assert_eq!(
+        client.try_refund_ticket(&ids.get(0).unwrap(), &alice),
+        Err(Ok(LumentixError::RefundNotAllowed))
+    );
+    client.refund_ticket(&sold, &alice);
+}
+
+#[test]
+fn test_burn_for_external_redemption() {
+    use crate::testutils::{setup_contract, setup_event_with_sales};
+    use soroban_sdk::InvokeError;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 1);
+    let owner = fixture.buyers.get(0).unwrap();
+    let ticket_id = fixture.ticket_ids.get(0).unwrap();
+    let redemption = BytesN::from_array(&env, &[9u8; 32]);
+    
+    assert_eq!(
+        client.try_burn_for_external(&fixture.organizer, &ticket_id, &redemption),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    
+    let escrow = client.get_tier_escrow(&fixture.event_id, &0);
+    client.burn_for_external(&owner, &ticket_id, &redemption);
+    assert_eq!(client.get_ticket(&ticket_id).status, TicketStatus::Redeemed);
+    assert_eq!(client.get_external_redemption(&ticket_id), Some(redemption.clone()));
+    assert_eq!(client.get_tier_escrow(&fixture.event_id, &0), escrow);
+    assert_eq!(client.get_holdings(&owner, &fixture.event_id), 0);
+    let (history, _) = client.get_buyer_history(&owner, &None, &10);
+    assert_eq!(history.last().unwrap().action, HistoryAction::Redeemed);
+    
+    assert_eq!(
+        client.try_burn_for_external(&owner, &ticket_id, &redemption),
+        Err(Err(InvokeError::Contract(TicketError::TicketRedeemed as u32)))
+    );
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &fixture.organizer, &Bytes::new(&env)),
+        Err(Err(InvokeError::Contract(TicketError::TicketRedeemed as u32)))
+    );
+}
+
+#[test]
+fn test_spent_tickets_report_their_status() {
+    use crate::testutils::{setup_contract, setup_event_with_sales};
+    use soroban_sdk::InvokeError;
+    
+    let env = Env::default();
+    let (_admin, client) = setup_contract(&env);
+    let fixture = setup_event_with_sales(&env, &client, 2);
+    let (refunded, expired) = (fixture.ticket_ids.get(0).unwrap(), fixture.ticket_ids.get(1).unwrap());
+    let holder = fixture.buyers.get(0).unwrap();
+    
+    assert_eq!(
+        client.try_propose_swap(&holder, &refunded, &refunded, &0),
+        Err(Err(InvokeError::Contract(TicketError::SameTicket as u32)))
+    );
+    
+    client.request_late_refund(&holder, &refunded, &RefundReason::CannotAttend);
+    client.approve_late_refund(&fixture.organizer, &refunded);
+    assert_eq!(
+        client.try_use_ticket(&refunded, &fixture.organizer, &Bytes::new(&env)),
+        Err(Err(InvokeError::Contract(TicketError::TicketRefunded as u32)))
+    );
+    assert_eq!(
+        client.try_burn_for_external(&holder, &refunded, &BytesN::from_array(&env, &[1u8; 32])),
+        Err(Err(InvokeError::Contract(TicketError::TicketRefunded as u32)))
+    );
+    
+    let grace = client.get_config().ticket_expiry_grace_secs;
+    env.ledger().with_mut(|li| li.timestamp = 2_000 + grace);
+    assert_eq!(client.expire_tickets(&vec![&env, expired]), 1);
+    assert_eq!(
+        client.try_use_ticket(&expired, &fixture.organizer, &Bytes::new(&env)),
+        Err(Err(InvokeError::Contract(TicketError::TicketExpired as u32)))
+    );
+}
@@ -0,0 +1,121 @@
+//! Test harness for integrators
+//!
+//! Enabled with the `testutils` feature. Provides a mock Stellar Asset
+//! Contract token, funded accounts and fixtures for common payment flows.
+//! All helpers call `env.mock_all_auths()`.
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String, Vec};
+use crate::types::{PaymentAsset, PlatformConfig};
+use crate::{LumentixContract, LumentixContractClient};
+
+/// Price of each ticket sold by `setup_event_with_sales`
+pub const FIXTURE_TICKET_PRICE: i128 = 100;
+
+/// Balance given to each buyer created by `setup_event_with_sales`
+pub const FIXTURE_BUYER_BALANCE: i128 = 1_000;
+
+/// An event with tickets sold through a mock token
+pub struct EventFixture {
+    pub organizer: Address,
+    pub event_id: u64,
+    /// Mock SAC token the event is paid in
+    pub token: Address,
+    pub buyers: Vec<Address>,
+    pub ticket_ids: Vec<u64>,
+}
+
+/// Register and initialize the contract, returning the admin and a client
+pub fn setup_contract(env: &Env) -> (Address, LumentixContractClient<'_>) {
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, LumentixContract);
+    let client = LumentixContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &PlatformConfig::default(), &Vec::new(env), &None);
+    
+    (admin, client)
+}
+
+/// Apply `config` at once, moving `platform_fee_bps` through a fee change
+/// scheduled with no notice, since `set_config` cannot change it
+pub fn apply_config(env: &Env, client: &LumentixContractClient, admin: &Address, config: &PlatformConfig) {
+    let current_bps = client.get_config().platform_fee_bps;
+    client.set_config(admin, &PlatformConfig {
+        platform_fee_bps: current_bps,
+        fee_change_notice_secs: 0,
+        ..config.clone()
+    });
+    if config.platform_fee_bps != current_bps {
+        client.schedule_fee_change(admin, &config.platform_fee_bps, &env.ledger().timestamp());
+    }
+    client.set_config(admin, config);
+}
+
+/// Register a mock Stellar Asset Contract token
+pub fn create_token(env: &Env) -> Address {
+    let issuer = Address::generate(env);
+    env.register_stellar_asset_contract_v2(issuer).address()
+}
+
+/// Create an account holding `amount` of a mock token
+pub fn funded_account(env: &Env, token: &Address, amount: i128) -> Address {
+    env.mock_all_auths();
+    
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&account, &amount);
+    account
+}
+
+/// Create an event paid in a fresh mock token and sell one ticket to each of
+/// `sales` funded buyers
+pub fn setup_event_with_sales(
+    env: &Env,
+    client: &LumentixContractClient,
+    sales: u32,
+) -> EventFixture {
+    env.mock_all_auths();
+    
+    let organizer = Address::generate(env);
+    let token = create_token(env);
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(env, "Fixture Event"),
+        &String::from_str(env, "Description"),
+        &String::from_str(env, "Location"),
+        &(env.ledger().timestamp() + 1_000),
+        &(env.ledger().timestamp() + 2_000),
+        &FIXTURE_TICKET_PRICE,
+        &(sales + 10),
+    );
+    
+    let asset = PaymentAsset {
+        token: token.clone(),
+        decimals: token::Client::new(env, &token).decimals(),
+    };
+    client.set_event_payment_asset(&organizer, &event_id, &asset);
+    
+    let mut buyers = Vec::new(env);
+    let mut ticket_ids = Vec::new(env);
+    for _ in 0..sales {
+        let buyer = funded_account(env, &token, FIXTURE_BUYER_BALANCE);
+        let ticket_id = client.purchase_ticket(
+            &buyer,
+            &event_id,
+            &FIXTURE_TICKET_PRICE,
+            &None,
+            &Some(asset.clone()),
+            &None,
+        );
+        buyers.push_back(buyer);
+        ticket_ids.push_back(ticket_id);
+    }
+    
+    EventFixture {
+        organizer,
+        event_id,
+        token,
+        buyers,
+        ticket_ids,
+    }
+}
@@ -1,5 +1,54 @@
-use soroban_sdk::{Address, String};
+use soroban_sdk::{Address, String, Vec};
 use crate::error::LumentixError;
+use crate::types::{FeeDestination, FeeTier, OrganizerDefault, PlatformConfig, ResaleSplit};
+
+/// Maximum number of items accepted by batch entrypoints
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Maximum number of platform fee destinations
+pub const MAX_FEE_DESTINATIONS: u32 = 10;
+
+/// Maximum number of volume-based fee tiers
+pub const MAX_FEE_TIERS: u32 = 10;
+
+/// Maximum number of sponsorship slots per event
+pub const MAX_SPONSORSHIPS_PER_EVENT: u32 = 20;
+
+/// Maximum number of add-on items per event
+pub const MAX_ADD_ONS_PER_EVENT: u32 = 10;
+
+/// Maximum number of capacity waves per event
+pub const MAX_CAPACITY_WAVES: u32 = 10;
+
+/// Maximum number of featured event placements
+pub const MAX_FEATURED_EVENTS: u32 = 20;
+
+/// Maximum number of followers per event
+pub const MAX_FOLLOWERS_PER_EVENT: u32 = 1_000;
+
+/// Maximum number of discovery tags per event
+pub const MAX_EVENT_TAGS: u32 = 5;
+
+/// Maximum number of custom fields collected per ticket
+pub const MAX_TICKET_FIELDS: u32 = 8;
+
+/// Maximum number of bundle discounts per event
+pub const MAX_BUNDLE_DISCOUNTS: u32 = 5;
+
+/// Maximum number of membership tiers with a member price per event
+pub const MAX_MEMBER_TIERS: u32 = 10;
+
+/// Maximum number of announcements per event
+pub const MAX_ANNOUNCEMENTS_PER_EVENT: u32 = 100;
+
+/// Maximum number of gate devices registered per event
+pub const MAX_GATE_DEVICES_PER_EVENT: u32 = 20;
+
+/// Maximum length of an event's on-chain summary, in bytes
+pub const MAX_SUMMARY_LEN: u32 = 280;
+
+/// Maximum length of a currency display symbol, in bytes
+pub const MAX_CURRENCY_SYMBOL_LEN: u32 = 12;
 
 /// Validate that an address is not invalid
 /// In Soroban, addresses are validated by the SDK, but we keep this for consistency
@@ -20,7 +69,7 @@ pub fn validate_positive_amount(amount: i128) -> Result<(), LumentixError> {
 /// Validate that capacity is positive (greater than 0)
 pub fn validate_positive_capacity(capacity: u32) -> Result<(), LumentixError> {
     if capacity == 0 {
-        return Err(LumentixError::CapacityExceeded);
+        return Err(LumentixError::InvalidCapacity);
     }
     Ok(())
 }
@@ -41,6 +90,161 @@ pub fn validate_string_not_empty(s: &String) -> Result<(), LumentixError> {
     Ok(())
 }
 
+/// Validate that an event summary fits in `MAX_SUMMARY_LEN` bytes
+pub fn validate_summary(summary: &String) -> Result<(), LumentixError> {
+    if summary.len() > MAX_SUMMARY_LEN {
+        return Err(LumentixError::CapacityExceeded);
+    }
+    Ok(())
+}
+
+/// Validate that a currency display symbol is set and short
+pub fn validate_currency_symbol(symbol: &String) -> Result<(), LumentixError> {
+    validate_string_not_empty(symbol)?;
+    if symbol.len() > MAX_CURRENCY_SYMBOL_LEN {
+        return Err(LumentixError::CapacityExceeded);
+    }
+    Ok(())
+}
+
+/// Validate that a batch request does not exceed `MAX_BATCH_SIZE`
+pub fn validate_batch_size(len: u32) -> Result<(), LumentixError> {
+    if len > MAX_BATCH_SIZE {
+        return Err(LumentixError::BatchTooLarge);
+    }
+    Ok(())
+}
+
+/// Validate that platform configuration values are within sane bounds
+pub fn validate_config(config: &PlatformConfig) -> Result<(), LumentixError> {
+    if config.max_capacity_per_event == 0
+        || config.max_fee_bps > 10_000
+        || config.platform_fee_bps > config.max_fee_bps
+        || config.platform_fee_flat < 0
+        || config.min_ticket_price <= 0
+        || config.max_tiers == 0
+        || config.max_events_per_organizer == 0
+        || config.max_events_per_verified < config.max_events_per_organizer
+        || config.min_event_duration_secs == 0
+        || config.max_event_duration_secs < config.min_event_duration_secs
+        || config.max_schedule_horizon_secs == 0
+        || config.creation_window_ledgers == 0
+        || config.max_creations_per_organizer == 0
+        || config.max_creations_per_window < config.max_creations_per_organizer
+        || config.refund_spike_bps > 10_000
+        || config.refund_spike_window_secs == 0
+        || config.yield_platform_bps > 10_000
+        || config.promotion_fee_per_day < 0
+        || config.rent_per_ticket_day < 0
+    {
+        return Err(LumentixError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Validate platform fee destinations: non-empty, bounded, shares summing to 100%
+pub fn validate_fee_destinations(destinations: &Vec<FeeDestination>) -> Result<(), LumentixError> {
+    if destinations.is_empty() || destinations.len() > MAX_FEE_DESTINATIONS {
+        return Err(LumentixError::InvalidConfig);
+    }
+    
+    let mut total: u32 = 0;
+    for destination in destinations.iter() {
+        if destination.share_bps == 0 {
+            return Err(LumentixError::InvalidConfig);
+        }
+        total += destination.share_bps;
+    }
+    
+    if total != 10_000 {
+        return Err(LumentixError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Validate a volume fee schedule: bounded, strictly increasing thresholds,
+/// and every rate within the platform's maximum fee
+pub fn validate_fee_tiers(config: &PlatformConfig, tiers: &Vec<FeeTier>) -> Result<(), LumentixError> {
+    if tiers.len() > MAX_FEE_TIERS {
+        return Err(LumentixError::InvalidConfig);
+    }
+    
+    let mut previous: Option<i128> = None;
+    for tier in tiers.iter() {
+        if tier.min_volume < 0 || tier.fee_bps > config.max_fee_bps {
+            return Err(LumentixError::InvalidConfig);
+        }
+        if previous.is_some_and(|min_volume| tier.min_volume <= min_volume) {
+            return Err(LumentixError::InvalidConfig);
+        }
+        previous = Some(tier.min_volume);
+    }
+    Ok(())
+}
+
+/// Validate a resale split: shares sum to 100% and the platform's share is
+/// between its base fee and its maximum fee
+pub fn validate_resale_split(config: &PlatformConfig, split: &ResaleSplit) -> Result<(), LumentixError> {
+    let total = split.seller_bps as u64 + split.organizer_bps as u64 + split.platform_bps as u64;
+    if total != 10_000
+        || split.platform_bps < config.platform_fee_bps
+        || split.platform_bps > config.max_fee_bps
+    {
+        return Err(LumentixError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Validate an organizer default with the same rules as its per-event setter
+pub fn validate_organizer_default(
+    config: &PlatformConfig,
+    default: &OrganizerDefault,
+) -> Result<(), LumentixError> {
+    match default {
+        OrganizerDefault::DepositTerms(bps) if *bps == 0 || *bps > 10_000 => Err(LumentixError::InvalidAmount),
+        OrganizerDefault::ResaleSplit(split) => validate_resale_split(config, split),
+        OrganizerDefault::MaxUses(max_uses) => validate_positive_capacity(*max_uses),
+        OrganizerDefault::TierWindow(tier, _) if *tier >= config.max_tiers => Err(LumentixError::InvalidConfig),
+        OrganizerDefault::TierWindow(_, template) => {
+            validate_time_range(template.closes_before_start, template.opens_before_start)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validate an event's capacity and price against platform limits
+pub fn validate_event_limits(
+    config: &PlatformConfig,
+    ticket_price: i128,
+    max_tickets: u32,
+) -> Result<(), LumentixError> {
+    if max_tickets > config.max_capacity_per_event {
+        return Err(LumentixError::CapacityExceeded);
+    }
+    if ticket_price < config.min_ticket_price {
+        return Err(LumentixError::PriceBelowMinimum);
+    }
+    Ok(())
+}
+
+/// Validate an event's duration and how far ahead of `now` it starts
+/// against platform limits
+pub fn validate_event_schedule(
+    config: &PlatformConfig,
+    now: u64,
+    start_time: u64,
+    end_time: u64,
+) -> Result<(), LumentixError> {
+    let duration = end_time.saturating_sub(start_time);
+    if duration < config.min_event_duration_secs
+        || duration > config.max_event_duration_secs
+        || start_time.saturating_sub(now) > config.max_schedule_horizon_secs
+    {
+        return Err(LumentixError::InvalidTimeRange);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,7 +270,7 @@ mod tests {
         assert!(validate_positive_capacity(1).is_ok());
         assert_eq!(
             validate_positive_capacity(0),
-            Err(LumentixError::CapacityExceeded)
+            Err(LumentixError::InvalidCapacity)
         );
     }
 
@@ -84,6 +288,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_batch_size() {
+        assert!(validate_batch_size(0).is_ok());
+        assert!(validate_batch_size(MAX_BATCH_SIZE).is_ok());
+        assert_eq!(
+            validate_batch_size(MAX_BATCH_SIZE + 1),
+            Err(LumentixError::BatchTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(validate_config(&PlatformConfig::default()).is_ok());
+        
+        let config = PlatformConfig {
+            max_fee_bps: 10_001,
+            ..PlatformConfig::default()
+        };
+        assert_eq!(validate_config(&config), Err(LumentixError::InvalidConfig));
+        
+        let config = PlatformConfig {
+            max_capacity_per_event: 0,
+            ..PlatformConfig::default()
+        };
+        assert_eq!(validate_config(&config), Err(LumentixError::InvalidConfig));
+        
+        let config = PlatformConfig {
+            platform_fee_bps: 1_001,
+            ..PlatformConfig::default()
+        };
+        assert_eq!(validate_config(&config), Err(LumentixError::InvalidConfig));
+        
+        let config = PlatformConfig {
+            platform_fee_flat: -1,
+            ..PlatformConfig::default()
+        };
+        assert_eq!(validate_config(&config), Err(LumentixError::InvalidConfig));
+    }
+
+    #[test]
+    fn test_validate_event_schedule() {
+        let config = PlatformConfig {
+            min_event_duration_secs: 60,
+            max_event_duration_secs: 3_600,
+            max_schedule_horizon_secs: 10_000,
+            ..PlatformConfig::default()
+        };
+        
+        assert!(validate_event_schedule(&config, 0, 1_000, 1_060).is_ok());
+        assert!(validate_event_schedule(&config, 0, 10_000, 13_600).is_ok());
+        assert_eq!(
+            validate_event_schedule(&config, 0, 1_000, 1_059),
+            Err(LumentixError::InvalidTimeRange)
+        );
+        assert_eq!(
+            validate_event_schedule(&config, 0, 1_000, 4_601),
+            Err(LumentixError::InvalidTimeRange)
+        );
+        assert_eq!(
+            validate_event_schedule(&config, 0, 10_001, 10_100),
+            Err(LumentixError::InvalidTimeRange)
+        );
+        assert!(validate_event_schedule(&config, 5_000, 10_001, 10_100).is_ok());
+    }
+
+    #[test]
+    fn test_validate_fee_destinations() {
+        use soroban_sdk::testutils::Address as _;
+        
+        let env = Env::default();
+        let destination = |share_bps| FeeDestination {
+            recipient: Address::generate(&env),
+            share_bps,
+        };
+        
+        assert!(validate_fee_destinations(&Vec::from_array(&env, [destination(10_000)])).is_ok());
+        assert!(
+            validate_fee_destinations(&Vec::from_array(&env, [destination(7_000), destination(3_000)]))
+                .is_ok()
+        );
+        assert_eq!(
+            validate_fee_destinations(&Vec::new(&env)),
+            Err(LumentixError::InvalidConfig)
+        );
+        assert_eq!(
+            validate_fee_destinations(&Vec::from_array(&env, [destination(7_000), destination(2_000)])),
+            Err(LumentixError::InvalidConfig)
+        );
+        assert_eq!(
+            validate_fee_destinations(&Vec::from_array(&env, [destination(10_000), destination(0)])),
+            Err(LumentixError::InvalidConfig)
+        );
+    }
+
+    #[test]
+    fn test_validate_fee_tiers() {
+        let env = Env::default();
+        let config = PlatformConfig::default();
+        let tier = |min_volume, fee_bps| FeeTier { min_volume, fee_bps };
+        
+        assert!(validate_fee_tiers(&config, &Vec::new(&env)).is_ok());
+        assert!(
+            validate_fee_tiers(&config, &Vec::from_array(&env, [tier(0, 500), tier(1_000, 250)]))
+                .is_ok()
+        );
+        assert_eq!(
+            validate_fee_tiers(&config, &Vec::from_array(&env, [tier(1_000, 500), tier(1_000, 250)])),
+            Err(LumentixError::InvalidConfig)
+        );
+        assert_eq!(
+            validate_fee_tiers(&config, &Vec::from_array(&env, [tier(0, config.max_fee_bps + 1)])),
+            Err(LumentixError::InvalidConfig)
+        );
+    }
+
+    #[test]
+    fn test_validate_resale_split() {
+        let config = PlatformConfig {
+            platform_fee_bps: 250,
+            max_fee_bps: 1_000,
+            ..PlatformConfig::default()
+        };
+        let split = |seller_bps, organizer_bps, platform_bps| ResaleSplit {
+            seller_bps,
+            organizer_bps,
+            platform_bps,
+        };
+        
+        assert!(validate_resale_split(&config, &split(8_750, 1_000, 250)).is_ok());
+        assert!(validate_resale_split(&config, &split(9_000, 0, 1_000)).is_ok());
+        assert_eq!(
+            validate_resale_split(&config, &split(8_750, 1_000, 200)),
+            Err(LumentixError::InvalidConfig)
+        );
+        assert_eq!(
+            validate_resale_split(&config, &split(8_000, 1_000, 900)),
+            Err(LumentixError::InvalidConfig)
+        );
+        assert_eq!(
+            validate_resale_split(&config, &split(8_000, 500, 1_001)),
+            Err(LumentixError::InvalidConfig)
+        );
+    }
+
+    #[test]
+    fn test_validate_event_limits() {
+        let config = PlatformConfig {
+            min_ticket_price: 1_000,
+            ..PlatformConfig::default()
+        };
+        assert!(validate_event_limits(&config, 1_000, 10).is_ok());
+        assert_eq!(
+            validate_event_limits(&config, 999, 10),
+            Err(LumentixError::PriceBelowMinimum)
+        );
+        assert_eq!(
+            validate_event_limits(&config, 1_000, config.max_capacity_per_event + 1),
+            Err(LumentixError::CapacityExceeded)
+        );
+    }
+
     #[test]
     fn test_validate_string_not_empty() {
         let env = Env::default();
@@ -0,0 +1,193 @@
+use soroban_sdk::contracterror;
+
+/// Comprehensive error types for the Lumentix contract
+/// Each error has a unique code for debugging and clear feedback to callers
+///
+/// The contract spec allows at most 50 error cases per enum, and all 50
+/// are used; further failure modes go in `TicketError`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum LumentixError {
+    /// Contract has not been initialized yet
+    NotInitialized = 1,
+    
+    /// Contract has already been initialized
+    AlreadyInitialized = 2,
+    
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    
+    /// Event with the specified ID does not exist
+    EventNotFound = 4,
+    
+    /// Ticket with the specified ID does not exist
+    TicketNotFound = 5,
+    
+    /// Event has reached maximum ticket capacity
+    EventSoldOut = 6,
+    
+    /// Ticket has already been used/validated
+    TicketAlreadyUsed = 7,
+    
+    /// Invalid status transition for event or ticket
+    InvalidStatusTransition = 8,
+    
+    /// Payment amount is less than required
+    InsufficientFunds = 9,
+    
+    /// Refund is not allowed for this ticket
+    RefundNotAllowed = 10,
+    
+    /// Event must be cancelled before refunds can be issued
+    EventNotCancelled = 11,
+    
+    /// Escrow funds have already been released
+    EscrowAlreadyReleased = 12,
+    
+    /// Amount must be greater than zero
+    InvalidAmount = 13,
+    
+    /// Capacity exceeds the platform maximum per event
+    CapacityExceeded = 14,
+    
+    /// Invalid time range (start time must be before end time)
+    InvalidTimeRange = 15,
+    
+    /// String field cannot be empty
+    EmptyString = 16,
+    
+    /// Invalid address provided
+    InvalidAddress = 17,
+    
+    /// Escrow balance insufficient for operation
+    InsufficientEscrow = 18,
+    
+    /// Platform configuration values are out of range
+    InvalidConfig = 19,
+    
+    /// Ticket price is below the platform minimum
+    PriceBelowMinimum = 20,
+    
+    /// Organizer has reached their active event quota
+    OrganizerEventLimitReached = 21,
+    
+    /// Draft is not yet old enough to be purged
+    DraftRetentionNotElapsed = 22,
+    
+    /// Event is not open for ticket sales (draft, cancelled or completed)
+    EventNotOnSale = 23,
+    
+    /// Capacity must be greater than zero
+    InvalidCapacity = 24,
+    
+    /// Ticket sales for the event have closed
+    SalesClosed = 25,
+    
+    /// Event end time has not been reached yet
+    EventNotEnded = 26,
+    
+    /// Too many items requested in a single batch call
+    BatchTooLarge = 27,
+    
+    /// An event with the derived ID already exists
+    EventAlreadyExists = 28,
+    
+    /// Operation is only allowed before the event starts
+    EventAlreadyStarted = 29,
+    
+    /// Setting cannot change once tickets have been sold
+    TicketsAlreadySold = 30,
+    
+    /// Multi-use ticket has already been checked in today
+    TicketAlreadyUsedToday = 31,
+    
+    /// Ticket holder is not currently checked in
+    TicketNotCheckedIn = 32,
+    
+    /// Accepted terms hash does not match the event's current policy
+    PolicyMismatch = 33,
+    
+    /// A late refund has already been requested for this ticket
+    RefundAlreadyRequested = 34,
+    
+    /// No pending late refund request exists for this ticket
+    RefundRequestNotFound = 35,
+    
+    /// Event was not curtailed, so no pro-rata refund applies
+    EventNotCurtailed = 36,
+    
+    /// Platform fee balance too low for the requested operation
+    InsufficientPlatformBalance = 37,
+    
+    /// Contract money totals do not reconcile
+    InvariantViolation = 38,
+    
+    /// Payment asset or its decimals do not match the event's payment asset
+    WrongPaymentAsset = 39,
+    
+    /// Event's waitlist is not open for registration
+    WaitlistClosed = 40,
+    
+    /// Account is already on the event's waitlist
+    AlreadyOnWaitlist = 41,
+    
+    /// Waitlist already holds as many entries as the event has tickets
+    WaitlistFull = 42,
+    
+    /// Event does not sell deposit tickets
+    NotDepositEvent = 43,
+    
+    /// Buyer's no-show rate exceeds the event's maximum
+    NoShowRateTooHigh = 44,
+    
+    /// Rating is outside the 1-5 range
+    InvalidRating = 45,
+    
+    /// Ticket has already been used to review its event
+    AlreadyReviewed = 46,
+    
+    /// Ticket was not checked in, was refunded, or its event has not ended
+    ReviewNotAllowed = 47,
+    
+    /// Sponsorship slot does not exist
+    SponsorshipNotFound = 48,
+    
+    /// Sponsorship slot has already been purchased
+    SponsorshipTaken = 49,
+    
+    /// Add-on does not exist for this event or has no inventory left
+    AddOnUnavailable = 50,
+}
+
+/// Errors past the 50 cases `LumentixError` can hold, numbered on from it
+///
+/// Entrypoints returning `LumentixError` raise these with
+/// `panic_with_error!`, so callers receive the code as a contract error.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum TicketError {
+    /// Holder has already spent this signed check-in nonce
+    NonceUsed = 51,
+    
+    /// Signed check-in pass is past its expiry
+    PassExpired = 52,
+    
+    /// Ticket was refunded and can no longer be admitted or change hands
+    TicketRefunded = 53,
+    
+    /// Ticket expired unused after its event ended
+    TicketExpired = 54,
+    
+    /// Ticket was burned for redemption outside the contract
+    TicketRedeemed = 55,
+    
+    /// Both sides of a swap name the same ticket
+    SameTicket = 56,
+    
+    /// Event's organizer has disabled resale and swaps of its tickets
+    NotTransferable = 57,
+}
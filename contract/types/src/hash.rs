@@ -0,0 +1,51 @@
+//! Commitments computed identically by the contract and off-chain apps
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+use crate::types::{EventStats, Ticket};
+
+/// Domain tag prefixed to check-in preimages
+const CHECKIN_DOMAIN: &[u8] = b"lumentix:checkin:v1";
+
+/// Domain tag prefixed to event state digest preimages
+const STATE_DOMAIN: &[u8] = b"lumentix:state:v1";
+
+/// Compute the check-in commitment a ticket's QR code carries:
+/// `sha256("lumentix:checkin:v1" || ticket_id (big-endian) || xdr(owner) || nonce (big-endian))`
+pub fn compute_checkin_hash(env: &Env, ticket_id: u64, owner: &Address, nonce: u64) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, CHECKIN_DOMAIN);
+    preimage.extend_from_array(&ticket_id.to_be_bytes());
+    preimage.append(&owner.clone().to_xdr(env));
+    preimage.extend_from_array(&nonce.to_be_bytes());
+    
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Fold a written ticket record into its event's running ticket digest:
+/// `sha256("lumentix:state:v1" || digest || xdr(ticket))`
+///
+/// An event's ticket digest starts as 32 zero bytes and folds in every
+/// ticket record of the event each time one is written, in write order.
+pub fn fold_ticket_digest(env: &Env, digest: &BytesN<32>, ticket: &Ticket) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, STATE_DOMAIN);
+    preimage.append(&Bytes::from(digest.clone()));
+    preimage.append(&ticket.clone().to_xdr(env));
+    
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Compute an event's state digest from its ticket digest, escrow balance
+/// and statistics:
+/// `sha256("lumentix:state:v1" || ticket_digest || escrow (big-endian i128) || xdr(stats))`
+pub fn compute_state_digest(
+    env: &Env,
+    ticket_digest: &BytesN<32>,
+    escrow: i128,
+    stats: &EventStats,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, STATE_DOMAIN);
+    preimage.append(&Bytes::from(ticket_digest.clone()));
+    preimage.extend_from_array(&escrow.to_be_bytes());
+    preimage.append(&stats.clone().to_xdr(env));
+    
+    env.crypto().sha256(&preimage).into()
+}
@@ -0,0 +1,24 @@
+//! Shared Lumentix contract types
+//!
+//! The `Event`, `Ticket`, config, receipt and error types the contract stores
+//! and returns, in a `no_std` crate that off-chain Rust services (indexer,
+//! backend) can depend on instead of copying the definitions.
+//!
+//! Types holding host objects (`Address`, `String`, `BytesN`) convert from
+//! `ScVal` through `soroban_sdk` with an `Env` (`Env::default()` works
+//! off-chain). Plain types (statuses, reasons, stats, config, quotes and
+//! `LumentixError`) additionally derive serde traits with the `serde` feature.
+//! The `hash` module computes the commitments the contract checks, such as
+//! check-in QR payloads, so apps and gate scanners hash exactly as it does.
+//! It also computes event state digests, so auditors can compare an
+//! off-chain reconstruction with the contract's commitment.
+
+#![no_std]
+
+pub mod error;
+pub mod hash;
+pub mod types;
+
+pub use error::{LumentixError, TicketError};
+pub use hash::{compute_checkin_hash, compute_state_digest, fold_ticket_digest};
+pub use types::*;
@@ -0,0 +1,1011 @@
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
+
+/// Event status enum
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventStatus {
+    Draft,
+    Active,
+    Cancelled,
+    Completed,
+}
+
+/// A 32-byte hash that may be absent
+///
+/// Stands in for `Option<BytesN<32>>` inside contract types, which the SDK
+/// cannot convert to XDR in test builds.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionalHash {
+    None,
+    Some(BytesN<32>),
+}
+
+impl From<Option<BytesN<32>>> for OptionalHash {
+    fn from(hash: Option<BytesN<32>>) -> Self {
+        match hash {
+            Option::Some(hash) => OptionalHash::Some(hash),
+            Option::None => OptionalHash::None,
+        }
+    }
+}
+
+impl From<OptionalHash> for Option<BytesN<32>> {
+    fn from(hash: OptionalHash) -> Self {
+        match hash {
+            OptionalHash::Some(hash) => Option::Some(hash),
+            OptionalHash::None => Option::None,
+        }
+    }
+}
+
+/// Event structure
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub id: u64,
+    pub organizer: Address,
+    pub name: String,
+    /// Short plain-text summary, at most `MAX_SUMMARY_LEN` bytes
+    pub summary: String,
+    /// `sha256` of the full description, which is stored off-chain
+    pub description_hash: OptionalHash,
+    pub location: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub ticket_price: i128,
+    pub max_tickets: u32,
+    /// Tickets ever sold, standby and imported ones included; refunds do
+    /// not lower it
+    pub tickets_sold: u32,
+    /// Number of check-ins each ticket allows (one per event day)
+    pub max_uses: u32,
+    /// Whether checked-out attendees may re-enter until the event ends
+    pub allow_reentry: bool,
+    pub status: EventStatus,
+    pub created_at: u64,
+}
+
+/// Ticket structure
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ticket {
+    pub id: u64,
+    pub event_id: u64,
+    pub owner: Address,
+    /// Ticket tier index; 0 is the event's base tier
+    pub tier: u32,
+    pub purchase_time: u64,
+    /// Number of times the ticket has been checked in
+    pub uses: u32,
+    pub last_used_at: u64,
+    pub status: TicketStatus,
+    /// Whether the holder is currently inside the venue
+    pub inside: bool,
+    /// Platform fee rate applied at purchase, in basis points
+    pub fee_bps: u32,
+    /// Platform fee taken from the payment at purchase
+    pub fee_paid: i128,
+}
+
+/// Lifecycle state of a ticket
+///
+/// There is no listed state: resale goes through buyer offers and swaps,
+/// and a ticket stays `Valid` until an offer on it is accepted.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TicketStatus {
+    /// Sold and admissible; multi-use tickets stay valid between check-ins
+    Valid,
+    /// No check-ins left
+    Used,
+    Refunded,
+    /// Locked in a pending swap or transfer
+    PendingTransfer,
+    /// Never used before its event ended
+    Expired,
+    /// Sold beyond capacity, awaiting confirmation or refund before the event
+    Standby,
+    /// Held by the contract until picked up at will-call
+    WillCall,
+    /// Burned by its owner for redemption outside the contract
+    Redeemed,
+}
+
+/// Token an event is paid in, with the decimals its prices are expressed in
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentAsset {
+    pub token: Address,
+    pub decimals: u32,
+}
+
+/// A payment asset that may be absent, standing in for
+/// `Option<PaymentAsset>` inside contract types
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionalAsset {
+    None,
+    Some(PaymentAsset),
+}
+
+impl From<Option<PaymentAsset>> for OptionalAsset {
+    fn from(asset: Option<PaymentAsset>) -> Self {
+        match asset {
+            Option::Some(asset) => OptionalAsset::Some(asset),
+            Option::None => OptionalAsset::None,
+        }
+    }
+}
+
+impl From<OptionalAsset> for Option<PaymentAsset> {
+    fn from(asset: OptionalAsset) -> Self {
+        match asset {
+            OptionalAsset::Some(asset) => Option::Some(asset),
+            OptionalAsset::None => Option::None,
+        }
+    }
+}
+
+/// How a client displays an event's prices: amounts are in units of
+/// `10^-decimals` of the currency named by `symbol`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurrencyDisplay {
+    pub symbol: String,
+    pub decimals: u32,
+}
+
+/// An event's base ticket price with what a client needs to render it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventPricing {
+    pub ticket_price: i128,
+    /// Token the price is paid in, if the event has a payment asset
+    pub token: Option<Address>,
+    /// Display metadata, if the event has a payment asset or the organizer
+    /// set one
+    pub currency: OptionalCurrency,
+}
+
+/// Display metadata that may be absent, standing in for
+/// `Option<CurrencyDisplay>` inside contract types
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionalCurrency {
+    None,
+    Some(CurrencyDisplay),
+}
+
+impl From<Option<CurrencyDisplay>> for OptionalCurrency {
+    fn from(display: Option<CurrencyDisplay>) -> Self {
+        match display {
+            Option::Some(display) => OptionalCurrency::Some(display),
+            Option::None => OptionalCurrency::None,
+        }
+    }
+}
+
+/// Running per-event statistics, maintained incrementally on every
+/// purchase, check-in and refund so they never require a ticket scan
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventStats {
+    /// Always equal to the event's `tickets_sold`
+    pub tickets_sold: u32,
+    pub tickets_used: u32,
+    pub tickets_refunded: u32,
+    /// Attendees currently inside the venue
+    pub occupancy: u32,
+    /// Tickets expired unused after the event (no-shows)
+    pub tickets_expired: u32,
+    /// Net revenue held for the event (payments minus refunds)
+    pub revenue: i128,
+    /// Platform fees on unrefunded tickets, reserved until the event settles
+    pub refundable_fees: i128,
+}
+
+/// Contract-wide money totals; `escrow + platform_balance + offers_held`
+/// always equals `deposits - withdrawals - refunds`
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reconciliation {
+    pub deposits: i128,
+    pub withdrawals: i128,
+    pub refunds: i128,
+    /// Sum of all event escrow balances
+    pub escrow: i128,
+    pub platform_balance: i128,
+    /// Part of the platform balance held back to cover fee refunds and
+    /// storage rent not consumed yet
+    pub reserved_fees: i128,
+    /// Amounts of open resale offers, held for their buyers
+    pub offers_held: i128,
+}
+
+/// Which side of a platform/organizer split keeps the fractional unit
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingPolicy {
+    /// Platform shares round down and organizer shares round up
+    TowardOrganizer,
+    /// Platform shares round up and organizer shares round down
+    TowardPlatform,
+}
+
+/// Platform-wide limits, settable by the admin
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlatformConfig {
+    /// Maximum number of tickets a single event may offer
+    pub max_capacity_per_event: u32,
+    /// Upper bound for any platform fee, in basis points
+    pub max_fee_bps: u32,
+    /// Platform fee taken from each ticket sale, in basis points
+    pub platform_fee_bps: u32,
+    /// Flat platform fee per ticket, charged on top of the bps fee
+    pub platform_fee_flat: i128,
+    /// Whether refunds also return the platform fee from the platform balance
+    pub refund_platform_fee: bool,
+    /// Minimum ticket price accepted at event creation
+    pub min_ticket_price: i128,
+    /// Maximum number of ticket tiers per event
+    pub max_tiers: u32,
+    /// Maximum number of active events per organizer
+    pub max_events_per_organizer: u32,
+    /// Maximum number of active events per verified organizer
+    pub max_events_per_verified: u32,
+    /// Age after which an unpublished draft may be purged by the admin
+    pub draft_retention_secs: u64,
+    /// Shortest allowed time between an event's start and end
+    pub min_event_duration_secs: u64,
+    /// Longest allowed time between an event's start and end
+    pub max_event_duration_secs: u64,
+    /// How far ahead of creation an event may be scheduled to start
+    pub max_schedule_horizon_secs: u64,
+    /// Length of an event creation rate-limit window, in ledgers
+    pub creation_window_ledgers: u32,
+    /// Maximum number of events created platform-wide per window
+    pub max_creations_per_window: u32,
+    /// Maximum number of events a single organizer may create per window
+    pub max_creations_per_organizer: u32,
+    /// Time after an event's end before its unused tickets can be expired
+    pub ticket_expiry_grace_secs: u64,
+    /// Check-ins an event needs before its escrow can be released, unless
+    /// the admin attests it took place; 0 disables the requirement
+    pub min_completion_check_ins: u32,
+    /// Share of an event's tickets sold that may request late refunds within
+    /// one window before its payouts freeze, in basis points
+    pub refund_spike_bps: u32,
+    /// Length of the refund spike window
+    pub refund_spike_window_secs: u64,
+    /// Platform share of the yield earned on escrow parked in a vault, in
+    /// basis points; the rest goes to the event's escrow
+    pub yield_platform_bps: u32,
+    /// Number of paid promotion slots organizers can occupy
+    pub promotion_slots: u32,
+    /// Price of a promotion slot per started day, paid in the event's asset
+    pub promotion_fee_per_day: i128,
+    /// Minimum notice between scheduling a platform fee change and the time
+    /// it takes effect
+    pub fee_change_notice_secs: u64,
+    /// Storage rent organizers prepay per ticket of capacity per day until
+    /// the event ends, charged when it goes on sale; 0 disables rent
+    pub rent_per_ticket_day: i128,
+    /// Which side keeps the fractional unit when an amount is split between
+    /// the platform and the organizer
+    pub rounding: RoundingPolicy,
+}
+
+impl Default for PlatformConfig {
+    fn default() -> Self {
+        PlatformConfig {
+            max_capacity_per_event: 100_000,
+            max_fee_bps: 1_000,
+            platform_fee_bps: 0,
+            platform_fee_flat: 0,
+            refund_platform_fee: true,
+            min_ticket_price: 1,
+            max_tiers: 10,
+            max_events_per_organizer: 50,
+            max_events_per_verified: 500,
+            draft_retention_secs: 30 * 24 * 60 * 60,
+            min_event_duration_secs: 1,
+            max_event_duration_secs: 365 * 24 * 60 * 60,
+            max_schedule_horizon_secs: 2 * 365 * 24 * 60 * 60,
+            creation_window_ledgers: 720,
+            max_creations_per_window: 100,
+            max_creations_per_organizer: 10,
+            ticket_expiry_grace_secs: 24 * 60 * 60,
+            min_completion_check_ins: 0,
+            refund_spike_bps: 2_500,
+            refund_spike_window_secs: 24 * 60 * 60,
+            yield_platform_bps: 2_000,
+            promotion_slots: 3,
+            promotion_fee_per_day: 100,
+            fee_change_notice_secs: 7 * 24 * 60 * 60,
+            rent_per_ticket_day: 0,
+            rounding: RoundingPolicy::TowardOrganizer,
+        }
+    }
+}
+
+/// Platform fee that a ticket sale would be charged right now
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeQuote {
+    pub ticket_price: i128,
+    pub fee_bps: u32,
+    pub fee_flat: i128,
+    /// Total fee: flat plus bps part, capped at the ticket price
+    pub fee: i128,
+}
+
+/// Discount on an event's tickets for holders of a ticket to a partner event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleDiscount {
+    /// Event a buyer must hold a ticket to
+    pub required_event_id: u64,
+    /// Discount off the ticket price, in basis points
+    pub discount_bps: u32,
+}
+
+/// Storage rent an organizer prepaid for an event, consumed over time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentDeposit {
+    pub amount: i128,
+    /// Part of `amount` moved to the platform's withdrawable balance
+    pub consumed: i128,
+    /// Part of `amount` returned to the organizer on cancellation
+    pub refunded: i128,
+    pub funded_from: u64,
+    pub funded_until: u64,
+}
+
+/// Ticket prices for members of an external membership contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberPricing {
+    /// Contract implementing `tier_of(member) -> Option<u32>`
+    pub contract: Address,
+    /// Ticket price for each membership tier; other tiers pay the regular price
+    pub prices: Map<u32, i128>,
+}
+
+/// Outcome of a simulated purchase, for wallets to show before signing
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseSimulation {
+    /// IDs the tickets would get if bought now
+    pub ticket_ids: Vec<u64>,
+    /// Payment required for all tickets
+    pub total_cost: i128,
+    /// Platform fee included in `total_cost`
+    pub fee: i128,
+}
+
+/// Entity that can be exempted from the platform fee
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeeExemptTarget {
+    Event(u64),
+    Organizer(Address),
+}
+
+/// Platform fee rate applied once an organizer's settled volume reaches a threshold
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeTier {
+    /// Lifetime settled volume at which this tier starts to apply
+    pub min_volume: i128,
+    pub fee_bps: u32,
+}
+
+/// A payout destination for platform fees and its share of each withdrawal
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeDestination {
+    pub recipient: Address,
+    /// Share of each withdrawal, in basis points; shares sum to 10_000
+    pub share_bps: u32,
+}
+
+/// Amount paid to one destination by a platform fee withdrawal
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeePayout {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// Accounting balance that can no longer be reached through normal flows
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecoveryBucket {
+    /// Escrow left behind by an event whose record no longer exists
+    OrphanedEscrow(u64),
+}
+
+/// A stuck-funds recovery proposed by the admin, executable after a timelock
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoveryRequest {
+    pub bucket: RecoveryBucket,
+    pub to: Address,
+    pub amount: i128,
+    pub unlock_at: u64,
+    /// Token the escrow is held in, captured at proposal so the payout does
+    /// not depend on event records that may be gone by then
+    pub token: Option<Address>,
+}
+
+/// Attendee details returned by a successful check-in
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckInResult {
+    pub ticket_id: u64,
+    pub event_id: u64,
+    pub owner: Address,
+    pub tier: u32,
+    pub checked_in_at: u64,
+}
+
+/// Ticket joined with a summary of its event
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketDetails {
+    pub ticket: Ticket,
+    pub event_name: String,
+    pub event_start_time: u64,
+    pub event_status: EventStatus,
+}
+
+/// Compact view of an event returned by list getters, without the
+/// description and location strings
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSummary {
+    pub id: u64,
+    pub name: String,
+    pub start_time: u64,
+    pub status: EventStatus,
+    pub price: i128,
+    /// Tickets still available for sale
+    pub remaining: u32,
+}
+
+/// Reason a ticket was turned away at check-in
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CheckInRejection {
+    NotFound,
+    WrongEvent,
+    AlreadyUsed,
+    AlreadyUsedToday,
+    Refunded,
+    Expired,
+    /// Locked in a pending swap, on standby or held for will-call
+    Locked,
+    Redeemed,
+    /// The scanned secret does not match the ticket's check-in commitment
+    WrongSecret,
+}
+
+/// Reason an organizer gives for cancelling an event
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CancellationReason {
+    Weather,
+    VenueUnavailable,
+    PerformerUnavailable,
+    LowSales,
+    Safety,
+    Other,
+}
+
+/// Notice recorded when an event is cancelled
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventCancellation {
+    pub reason: CancellationReason,
+    pub cancelled_at: u64,
+}
+
+/// Reason recorded with a refund, aggregated per event for reporting
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RefundReason {
+    EventCancelled,
+    EventChanged,
+    CannotAttend,
+    Duplicate,
+    Other,
+    /// Standby ticket that no refunded seat freed up for
+    Oversold,
+}
+
+/// Amounts returned by a refund, by the balance they were debited from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefundReceipt {
+    pub ticket_id: u64,
+    /// Ticket principal debited from the event escrow
+    pub from_escrow: i128,
+    /// Platform fee portion debited from the platform balance
+    pub from_platform: i128,
+    pub refunded_at: u64,
+}
+
+/// A ticket delegated to another address until a deadline, after which
+/// it reverts to its owner
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketLoan {
+    pub borrower: Address,
+    pub lent_at: u64,
+    /// End of the loan; from this time the owner holds the ticket again
+    pub until: u64,
+    /// Check-in commitment set by the borrower, honored only during the loan
+    pub checkin_hash: OptionalHash,
+}
+
+/// Who may pick up a will-call ticket, and the commitment to the pickup
+/// code they must present
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WillCallPickup {
+    /// The owner, or another address named by the owner
+    pub pickup: Address,
+    /// `sha256(code)` of the pickup code
+    pub code_hash: BytesN<32>,
+}
+
+/// How many legacy tickets the admin approved an event to import, and how
+/// many it has imported
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ImportAllowance {
+    pub approved: u32,
+    pub imported: u32,
+}
+
+/// How a ticket entered or left an account
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HistoryAction {
+    Purchased,
+    Refunded,
+    /// Received through an accepted offer or swap
+    TransferredIn,
+    /// Sold through an accepted offer or given up in a swap
+    TransferredOut,
+    /// Burned for redemption outside the contract
+    Redeemed,
+}
+
+/// One entry of an account's ticket history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    /// Position in the account's history, increasing with every entry
+    pub id: u64,
+    pub action: HistoryAction,
+    pub ticket_id: u64,
+    pub event_id: u64,
+    /// Amount paid for a purchase, returned by a refund, or changing hands
+    /// with a transfer (an offer's amount or a swap's top-up)
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Until when a ticket's holder can refund it with `refund_ticket`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RefundDeadline {
+    /// The ticket cannot currently be refunded by its holder
+    NotRefundable,
+    /// The ticket can be refunded at any time
+    NoDeadline,
+}
+
+/// Lifecycle of a late refund request
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LateRefundStatus {
+    Pending,
+    Approved,
+    Denied,
+}
+
+/// A ticket holder's request for a refund outside the normal refund rules,
+/// kept after the organizer's decision as an audit record
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateRefundRequest {
+    pub ticket_id: u64,
+    pub event_id: u64,
+    pub owner: Address,
+    pub reason: RefundReason,
+    pub status: LateRefundStatus,
+    pub requested_at: u64,
+    /// Time of the organizer's decision, 0 while pending
+    pub decided_at: u64,
+}
+
+/// A buyer's attendance history across settled deposit-model events
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttendanceRecord {
+    /// Deposit tickets settled after their event completed
+    pub settled: u32,
+    /// Settled tickets that were never checked in
+    pub no_shows: u32,
+}
+
+/// An attendee's rating of an event, one per checked-in ticket
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Review {
+    pub ticket_id: u64,
+    pub event_id: u64,
+    /// 1 to 5
+    pub rating: u32,
+    pub submitted_at: u64,
+}
+
+/// Aggregate of the ratings received by an event or organizer
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RatingSummary {
+    pub count: u32,
+    /// Sum of all ratings
+    pub total: u32,
+    /// Mean rating multiplied by 100 (e.g. 437 for 4.37), 0 when unrated
+    pub average_x100: u32,
+}
+
+/// A sponsorship slot offered by an event's organizer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponsorship {
+    pub id: u64,
+    pub event_id: u64,
+    pub price: i128,
+    /// Hash of the off-chain document describing the sponsor's benefits
+    pub benefits_hash: BytesN<32>,
+    /// Buyer of the slot, `None` while it is available
+    pub sponsor: Option<Address>,
+    /// Amount the sponsor paid into escrow
+    pub paid: i128,
+    pub refunded: bool,
+    pub created_at: u64,
+}
+
+/// A tranche of an event's capacity released for sale at a given time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapacityWave {
+    pub count: u32,
+    pub release_at: u64,
+}
+
+/// Threshold that unlocks an event's bonus capacity
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BonusUnlock {
+    /// Net revenue held for the event reaches this amount
+    Revenue(i128),
+    /// Tickets sold reach this share of the base capacity, in basis points
+    SellThroughBps(u32),
+}
+
+/// Extra capacity (e.g. standing room) added to an event once it has
+/// earned enough to cover the extra production cost
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BonusCapacity {
+    pub count: u32,
+    pub unlock: BonusUnlock,
+    /// Set once the threshold was reached and `count` added to `max_tickets`
+    pub unlocked: bool,
+}
+
+/// A ticket tier's own sales window, layered over the event's
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SalesWindow {
+    pub opens_at: u64,
+    pub closes_at: u64,
+}
+
+/// An organizer-defined item sold alongside an event's tickets
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddOn {
+    /// Index of the add-on within its event
+    pub id: u32,
+    pub name: String,
+    pub price: i128,
+    pub inventory: u32,
+    pub sold: u32,
+}
+
+/// Add-ons bought with a ticket
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketAddOns {
+    /// Add-on IDs, repeated for quantities above one
+    pub items: Vec<u32>,
+    /// Total paid for the add-ons, refunded with the ticket
+    pub paid: i128,
+}
+
+/// Everything a buyer supplies with a purchase besides the payment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseRequest {
+    /// Hash of the refund/terms policy the buyer accepted
+    pub terms_hash: OptionalHash,
+    /// Asset the buyer is paying with
+    pub payment_asset: OptionalAsset,
+    /// Add-on IDs, repeated for quantities above one
+    pub add_ons: Vec<u32>,
+    /// Hash of the buyer's answer to each of the event's custom fields
+    pub fields: Map<Symbol, BytesN<32>>,
+    /// Buyer reference recorded for payment reconciliation
+    pub memo: OptionalHash,
+}
+
+/// What a buyer paid for a ticket, returned as-is by a full refund
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketPayment {
+    /// Token the ticket was paid in; `None` for accounting-only events
+    pub token: Option<Address>,
+    /// Total paid, including the platform fee and any add-ons
+    pub amount: i128,
+}
+
+/// Pro-rata refund terms of an event that was completed early
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curtailment {
+    /// Share of each ticket's paid principal refunded to its holder, in
+    /// basis points
+    pub refund_bps: u32,
+    /// Holders still owed their refund
+    pub tickets_owed: u32,
+    /// Escrow still reserved for holders who have not been refunded yet
+    pub outstanding: i128,
+    pub curtailed_at: u64,
+}
+
+/// How the price of a resold ticket is divided, in basis points summing to
+/// 10000
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResaleSplit {
+    pub seller_bps: u32,
+    /// Royalty paid to the event organizer
+    pub organizer_bps: u32,
+    pub platform_bps: u32,
+}
+
+/// A setting an organizer's new events are created with
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OrganizerDefault {
+    /// Hash of the terms/refund policy document registered as version 1
+    RefundPolicy(BytesN<32>),
+    /// Refundable share of deposit tickets, in basis points
+    DepositTerms(u32),
+    /// Resale split, including the organizer royalty
+    ResaleSplit(ResaleSplit),
+    /// Check-ins each ticket allows, once per event day, as in `set_max_uses`
+    MaxUses(u32),
+    /// Whether checked-out attendees may re-enter, as in `set_reentry_allowed`
+    AllowReentry(bool),
+    /// Whether tickets may be resold or swapped, as in `set_transferable`
+    Transferable(bool),
+    /// Sales window of a ticket tier, placed relative to each new event's
+    /// start, as in `set_tier_sales_window`
+    TierWindow(u32, TierWindowTemplate),
+}
+
+/// A tier sales window expressed as offsets before an event's start, so one
+/// template fits every event
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TierWindowTemplate {
+    /// Seconds before the start the tier goes on sale
+    pub opens_before_start: u64,
+    /// Seconds before the start the tier's sales close
+    pub closes_before_start: u64,
+}
+
+/// What a resale offer bids on
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OfferTarget {
+    /// One specific ticket
+    Ticket(u64),
+    /// Any ticket of an event
+    Event(u64),
+    /// Any ticket of an event's tier, as `(event_id, tier)`
+    Tier(u64, u32),
+}
+
+/// A buyer's escrow-backed bid for a resale ticket
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    pub id: u64,
+    pub buyer: Address,
+    pub target: OfferTarget,
+    pub event_id: u64,
+    /// Amount held by the contract until the offer is accepted or cancelled
+    pub amount: i128,
+    pub created_at: u64,
+}
+
+/// Escrow of an event parked in a whitelisted yield vault
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldPosition {
+    pub vault: Address,
+    /// Escrow deposited, which must come back in full on redemption
+    pub principal: i128,
+    pub deposited_at: u64,
+}
+
+/// A proposed exchange of two tickets between their holders
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Swap {
+    pub id: u64,
+    pub proposer: Address,
+    /// The proposer's ticket
+    pub ticket_a: u64,
+    /// The ticket the proposer wants in return
+    pub ticket_b: u64,
+    /// Cash the proposer adds on top, held by the contract until the swap
+    /// is accepted or cancelled
+    pub top_up: i128,
+    pub created_at: u64,
+}
+
+/// Ticket levy withheld from an event's sales for a tax authority
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaxTerms {
+    /// Share of each ticket's net price withheld, in basis points
+    pub rate_bps: u32,
+    /// Address the withheld tax is paid to at settlement
+    pub recipient: Address,
+}
+
+/// Outcome of scanning a ticket with `check_in`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckInOutcome {
+    Admitted(CheckInResult),
+    Rejected(CheckInRejection),
+}
+
+/// A rejected check-in attempt kept in the per-event log
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailedCheckIn {
+    pub ticket_id: u64,
+    pub verifier: Address,
+    pub reason: CheckInRejection,
+    pub attempted_at: u64,
+}
+
+/// A scanner device allowed to check tickets in at one event, separately
+/// from the organizer's own key
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GateDevice {
+    pub device: Address,
+    /// Timestamp the registration lapses at
+    pub expires_at: u64,
+}
+
+/// An event placed in the admin-curated featured list
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeaturedEvent {
+    pub event_id: u64,
+    /// Placement, lowest first
+    pub rank: u32,
+    /// Timestamp the placement lapses at
+    pub until: u64,
+}
+
+/// A platform fee change announced ahead of the time it takes effect
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeChange {
+    /// `platform_fee_bps` from `effective_at` onwards
+    pub fee_bps: u32,
+    pub scheduled_at: u64,
+    pub effective_at: u64,
+}
+
+/// A paid promotion slot occupied by an event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Promotion {
+    pub slot: u32,
+    pub event_id: u64,
+    pub organizer: Address,
+    pub starts_at: u64,
+    /// Timestamp the slot frees up at
+    pub until: u64,
+    /// Fee paid into the platform balance
+    pub paid: i128,
+}
+
+/// A registered version of an event's terms/refund policy document
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyVersion {
+    /// 1-based version number, incremented on every registration
+    pub version: u32,
+    /// Hash of the policy document text
+    pub hash: BytesN<32>,
+    pub registered_at: u64,
+}
+
+/// An organizer update posted to an event's ticket holders; the content
+/// lives off-chain
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Announcement {
+    /// 1-based number, in posting order
+    pub number: u32,
+    /// Hash of the announcement text
+    pub content_hash: BytesN<32>,
+    pub posted_at: u64,
+}
+
+/// Per-event outcome of a bulk `update_events_status` call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusUpdateResult {
+    pub event_id: u64,
+    /// `0` when the transition was applied, otherwise the `LumentixError`
+    /// code explaining why the event was skipped
+    pub error_code: u32,
+}